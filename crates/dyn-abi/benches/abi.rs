@@ -2,10 +2,10 @@ use alloy_dyn_abi::{DynSolType, DynSolValue};
 use alloy_primitives::U256;
 use alloy_sol_types::{sol, SolType};
 use criterion::{
-    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
 use hex_literal::hex;
-use std::{hint::black_box, time::Duration};
+use std::time::Duration;
 
 fn ethabi_encode(c: &mut Criterion) {
     let mut g = group(c, "ethabi/encode");
@@ -99,6 +99,24 @@ fn sol_types_encode(c: &mut Criterion) {
         b.iter(|| Input::encode(black_box(&input)));
     });
 
+    g.bench_function("array", |b| {
+        let input = encode_array_input();
+        b.iter(|| {
+            alloy_sol_types::sol_data::Array::<alloy_sol_types::sol_data::Uint<256>>::encode_single(
+                black_box(&input),
+            )
+        });
+    });
+
+    g.bench_function("bytes_array", |b| {
+        let input = encode_bytes_array_input();
+        b.iter(|| {
+            alloy_sol_types::sol_data::Array::<alloy_sol_types::sol_data::Bytes>::encode_single(
+                black_box(&input),
+            )
+        });
+    });
+
     g.finish();
 }
 
@@ -154,6 +172,14 @@ fn encode_struct_input() -> Input {
     }
 }
 
+fn encode_array_input() -> Vec<U256> {
+    (0..4096u64).map(U256::from).collect()
+}
+
+fn encode_bytes_array_input() -> Vec<Vec<u8>> {
+    (0..=255u8).map(|i| vec![i; 100 + i as usize]).collect()
+}
+
 fn encode_struct_input_tokens() -> [ethabi::Token; 8] {
     let input = encode_struct_input();
     [
@@ -50,6 +50,11 @@ const fn int_size(n: usize) -> usize {
     n + (8 - (n % 8))
 }
 
+#[inline]
+const fn fixed_decimals(n: usize) -> usize {
+    n % 81
+}
+
 #[inline]
 #[cfg(feature = "eip712")]
 const fn ident_char(x: u8, first: bool) -> u8 {
@@ -129,6 +134,8 @@ enum Choice {
     Bool,
     Int,
     Uint,
+    Fixed,
+    Ufixed,
     Address,
     FixedBytes,
     Bytes,
@@ -147,6 +154,14 @@ impl<'a> arbitrary::Arbitrary<'a> for DynSolType {
             Choice::Bool => Ok(Self::Bool),
             Choice::Int => u.arbitrary().map(int_size).map(Self::Int),
             Choice::Uint => u.arbitrary().map(int_size).map(Self::Uint),
+            Choice::Fixed => {
+                let (size, decimals) = u.arbitrary()?;
+                Ok(Self::Fixed(int_size(size), fixed_decimals(decimals)))
+            }
+            Choice::Ufixed => {
+                let (size, decimals) = u.arbitrary()?;
+                Ok(Self::Ufixed(int_size(size), fixed_decimals(decimals)))
+            }
             Choice::Address => Ok(Self::Address),
             Choice::FixedBytes => Ok(Self::FixedBytes(u.int_in_range(1..=32)?)),
             Choice::Bytes => Ok(Self::Bytes),
@@ -312,6 +327,8 @@ impl DynSolType {
             Just(Self::Address),
             any::<usize>().prop_map(|x| Self::Int(int_size(x))),
             any::<usize>().prop_map(|x| Self::Uint(int_size(x))),
+            fixed_size_strategy().prop_map(|(sz, d)| Self::Fixed(sz, d)),
+            fixed_size_strategy().prop_map(|(sz, d)| Self::Ufixed(sz, d)),
             (1..=32usize).prop_map(Self::FixedBytes),
             Just(Self::Bytes),
             Just(Self::String),
@@ -357,6 +374,8 @@ impl DynSolValue {
             DynSolType::Address => u.arbitrary().map(Self::Address),
             &DynSolType::Int(sz) => u.arbitrary().map(|x| Self::Int(x, sz)),
             &DynSolType::Uint(sz) => u.arbitrary().map(|x| Self::Uint(x, sz)),
+            &DynSolType::Fixed(sz, d) => u.arbitrary().map(|x| Self::Fixed(x, sz, d)),
+            &DynSolType::Ufixed(sz, d) => u.arbitrary().map(|x| Self::Ufixed(x, sz, d)),
             &DynSolType::FixedBytes(sz) => u.arbitrary().map(|x| Self::FixedBytes(x, sz)),
             DynSolType::Bytes => u.arbitrary().map(Self::Bytes),
             DynSolType::String => u.arbitrary().map(Self::String),
@@ -409,6 +428,12 @@ impl DynSolValue {
             DynSolType::Address => any::<Address>().prop_map(Self::Address).boxed(),
             &DynSolType::Int(sz) => any::<I256>().prop_map(move |x| Self::Int(x, sz)).boxed(),
             &DynSolType::Uint(sz) => any::<U256>().prop_map(move |x| Self::Uint(x, sz)).boxed(),
+            &DynSolType::Fixed(sz, d) => any::<I256>()
+                .prop_map(move |x| Self::Fixed(x, sz, d))
+                .boxed(),
+            &DynSolType::Ufixed(sz, d) => any::<U256>()
+                .prop_map(move |x| Self::Ufixed(x, sz, d))
+                .boxed(),
             &DynSolType::FixedBytes(sz) => any::<B256>()
                 .prop_map(move |x| Self::FixedBytes(x, sz))
                 .boxed(),
@@ -459,6 +484,8 @@ impl DynSolValue {
             any::<Address>().prop_map(Self::Address),
             int_strategy::<I256>().prop_map(|(x, sz)| Self::Int(x, sz)),
             int_strategy::<U256>().prop_map(|(x, sz)| Self::Uint(x, sz)),
+            (any::<I256>(), fixed_size_strategy()).prop_map(|(x, (sz, d))| Self::Fixed(x, sz, d)),
+            (any::<U256>(), fixed_size_strategy()).prop_map(|(x, (sz, d))| Self::Ufixed(x, sz, d)),
             (any::<B256>(), 1..=32usize).prop_map(|(x, sz)| DynSolValue::FixedBytes(x, sz)),
             any::<Vec<u8>>().prop_map(Self::Bytes),
             any::<String>().prop_map(Self::String),
@@ -514,6 +541,11 @@ fn int_strategy<T: Arbitrary>() -> impl Strategy<Value = (ValueOfStrategy<T::Str
     (any::<T>(), any::<usize>().prop_map(int_size))
 }
 
+#[inline]
+fn fixed_size_strategy() -> impl Strategy<Value = (usize, usize)> {
+    (any::<usize>().prop_map(int_size), 0..=80usize)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,398 @@
+use crate::{DynAbiError, DynAbiResult, DynSolType, DynSolValue, Word};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{
+    utils::{parse_units, ParseUnits},
+    Address, Sign, I256, U256,
+};
+
+impl DynSolType {
+    /// Coerces a human-readable string into a [`DynSolValue`] of this type.
+    ///
+    /// The accepted formats are:
+    /// - `address`: hex, with or without the `0x` prefix;
+    /// - `bool`: `true` or `false`;
+    /// - `int` and `uint`: `0x`-prefixed hex, or a decimal number optionally
+    ///   followed by a unit, such as `1.5 ether` or `30gwei`;
+    /// - `fixedMxN` and `ufixedMxN`: a decimal number with at most `N`
+    ///   fractional digits, such as `-1.5`;
+    /// - `bytes` and `bytesN`: hex, with or without the `0x` prefix. `bytesN`
+    ///   must be exactly `N` bytes long;
+    /// - `string`: the input itself, with surrounding double quotes removed;
+    /// - arrays: `[a, b, ...]`;
+    /// - tuples and structs: `(a, b, ...)`.
+    ///
+    /// Array and tuple elements may be nested, and may be quoted with double
+    /// quotes to include commas or brackets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::U256;
+    ///
+    /// let ty: DynSolType = "uint256".parse()?;
+    /// let value = ty.coerce_str("1.5 ether")?;
+    /// assert_eq!(value, DynSolValue::Uint(U256::from(1_500_000_000_000_000_000u64), 256));
+    ///
+    /// let ty: DynSolType = "(bool,uint8[])".parse()?;
+    /// let value = ty.coerce_str("(true, [1, 2, 3])")?;
+    /// assert_eq!(value.sol_type_name().unwrap(), "(bool,uint8[])");
+    ///
+    /// assert!(DynSolType::Uint(8).coerce_str("256").is_err());
+    /// # Ok::<_, alloy_dyn_abi::DynAbiError>(())
+    /// ```
+    pub fn coerce_str(&self, s: &str) -> DynAbiResult<DynSolValue> {
+        let s = s.trim();
+        let value = match self {
+            Self::Address => s.parse::<Address>().ok().map(DynSolValue::Address),
+            Self::Bool => s.parse::<bool>().ok().map(DynSolValue::Bool),
+            Self::Int(n) => int(*n, s).map(|i| DynSolValue::Int(i, *n)),
+            Self::Uint(n) => uint(*n, s).map(|u| DynSolValue::Uint(u, *n)),
+            Self::Fixed(n, d) => fixed(*n, *d, s).map(|i| DynSolValue::Fixed(i, *n, *d)),
+            Self::Ufixed(n, d) => ufixed(*n, *d, s).map(|u| DynSolValue::Ufixed(u, *n, *d)),
+            Self::FixedBytes(n) => fixed_bytes(*n, s).map(|w| DynSolValue::FixedBytes(w, *n)),
+            Self::Bytes => hex::decode(s).ok().map(DynSolValue::Bytes),
+            Self::String => Some(DynSolValue::String(unquote(s).to_string())),
+            Self::Array(inner) => {
+                let elements = split_seq(s, '[', ']').ok_or_else(|| self.invalid_value(s))?;
+                return elements
+                    .into_iter()
+                    .map(|e| inner.coerce_str(e))
+                    .collect::<DynAbiResult<_>>()
+                    .map(DynSolValue::Array)
+            }
+            Self::FixedArray(inner, n) => {
+                let elements = split_seq(s, '[', ']')
+                    .filter(|e| e.len() == *n)
+                    .ok_or_else(|| self.invalid_value(s))?;
+                return elements
+                    .into_iter()
+                    .map(|e| inner.coerce_str(e))
+                    .collect::<DynAbiResult<_>>()
+                    .map(DynSolValue::FixedArray)
+            }
+            Self::Tuple(types) => return self.coerce_tuple(types, s).map(DynSolValue::Tuple),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => {
+                return self
+                    .coerce_tuple(tuple, s)
+                    .map(|tuple| DynSolValue::CustomStruct {
+                        name: name.clone(),
+                        prop_names: prop_names.clone(),
+                        tuple,
+                    })
+            }
+        };
+        value.ok_or_else(|| self.invalid_value(s))
+    }
+
+    fn coerce_tuple(&self, types: &[Self], s: &str) -> DynAbiResult<Vec<DynSolValue>> {
+        let elements = split_seq(s, '(', ')')
+            .filter(|e| e.len() == types.len())
+            .ok_or_else(|| self.invalid_value(s))?;
+        types
+            .iter()
+            .zip(elements)
+            .map(|(ty, e)| ty.coerce_str(e))
+            .collect()
+    }
+
+    #[inline]
+    fn invalid_value(&self, s: &str) -> DynAbiError {
+        DynAbiError::InvalidValue {
+            expected: self.clone(),
+            value: s.to_string(),
+        }
+    }
+}
+
+fn uint(n: usize, s: &str) -> Option<U256> {
+    let u = match s.strip_prefix("0x") {
+        Some(hex) => parse_hex(hex)?,
+        None => U256::try_from(units(s)?).ok()?,
+    };
+    (u.bit_len() <= n).then_some(u)
+}
+
+fn int(n: usize, s: &str) -> Option<I256> {
+    let (sign, abs) = match s.strip_prefix('-') {
+        Some(abs) => (Sign::Negative, abs),
+        None => (Sign::Positive, s),
+    };
+    let i = match abs.strip_prefix("0x") {
+        Some(hex) => I256::checked_from_sign_and_abs(sign, parse_hex(hex)?)?,
+        None => I256::try_from(units(s)?).ok()?,
+    };
+    fits_int(n, i).then_some(i)
+}
+
+fn ufixed(n: usize, decimals: usize, s: &str) -> Option<U256> {
+    match decimal(decimals, s)? {
+        (Sign::Positive, u) => (u.bit_len() <= n).then_some(u),
+        (Sign::Negative, _) => None,
+    }
+}
+
+fn fixed(n: usize, decimals: usize, s: &str) -> Option<I256> {
+    let (sign, abs) = decimal(decimals, s)?;
+    let i = I256::checked_from_sign_and_abs(sign, abs)?;
+    fits_int(n, i).then_some(i)
+}
+
+/// Parses a decimal number, like `-1.5`, into its sign and its absolute value
+/// scaled by `10^decimals`.
+fn decimal(decimals: usize, s: &str) -> Option<(Sign, U256)> {
+    let (sign, abs) = match s.strip_prefix('-') {
+        Some(abs) => (Sign::Negative, abs),
+        None => (Sign::Positive, s),
+    };
+    let (int, frac) = abs.split_once('.').unwrap_or((abs, ""));
+    if (int.is_empty() && frac.is_empty())
+        || frac.len() > decimals
+        || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return None
+    }
+
+    let mut digits = String::with_capacity(int.len() + decimals);
+    digits.push_str(int);
+    digits.push_str(frac);
+    digits.extend(core::iter::repeat('0').take(decimals - frac.len()));
+    U256::from_str_radix(&digits, 10).ok().map(|u| (sign, u))
+}
+
+/// Returns `true` if `i` fits in an `n`-bit signed integer.
+pub(crate) fn fits_int(n: usize, i: I256) -> bool {
+    // the magnitude of `i` if non-negative, or of `-i - 1` if negative, must
+    // fit in `n - 1` bits
+    let raw = if i.is_negative() {
+        !i.into_raw()
+    } else {
+        i.into_raw()
+    };
+    raw.bit_len() < n
+}
+
+/// Parses a decimal amount with an optional unit, defaulting to wei.
+fn units(s: &str) -> Option<ParseUnits> {
+    let (amount, unit) = match s.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => (s[..i].trim_end(), &s[i..]),
+        None => (s, "wei"),
+    };
+    parse_units(amount, unit).ok()
+}
+
+pub(crate) fn parse_hex(hex: &str) -> Option<U256> {
+    // `from_str_radix` also accepts underscores
+    if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None
+    }
+    U256::from_str_radix(hex, 16).ok()
+}
+
+fn fixed_bytes(n: usize, s: &str) -> Option<Word> {
+    let bytes = hex::decode(s).ok()?;
+    if bytes.len() != n || n > 32 {
+        return None
+    }
+    let mut word = Word::ZERO;
+    word[..n].copy_from_slice(&bytes);
+    Some(word)
+}
+
+/// Removes the surrounding double quotes of a string, if any.
+fn unquote(s: &str) -> &str {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+}
+
+/// Splits a delimited sequence such as `[a, (b, c), "d,e"]` into its
+/// top-level elements.
+fn split_seq(s: &str, open: char, close: char) -> Option<Vec<&str>> {
+    let inner = s.strip_prefix(open)?.strip_suffix(close)?;
+    if inner.trim().is_empty() {
+        return Some(Vec::new())
+    }
+
+    let mut elements = Vec::new();
+    let mut depth = 0usize;
+    let mut quoted = false;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            _ if quoted => {}
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                elements.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 || quoted {
+        return None
+    }
+    elements.push(inner[start..].trim());
+    Some(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{borrow::ToOwned, string::String};
+    use alloy_primitives::address;
+
+    #[test]
+    fn coerce_words() {
+        assert_eq!(
+            DynSolType::Address
+                .coerce_str("0x00000000219ab540356cBB839Cbe05303d7705Fa")
+                .unwrap(),
+            DynSolValue::Address(address!("00000000219ab540356cbb839cbe05303d7705fa"))
+        );
+        assert_eq!(
+            DynSolType::Bool.coerce_str(" true ").unwrap(),
+            DynSolValue::Bool(true)
+        );
+        assert!(DynSolType::Bool.coerce_str("1").is_err());
+
+        let mut word = Word::ZERO;
+        word[..2].copy_from_slice(&[0xab, 0xcd]);
+        assert_eq!(
+            DynSolType::FixedBytes(2).coerce_str("0xabcd").unwrap(),
+            DynSolValue::FixedBytes(word, 2)
+        );
+        assert!(DynSolType::FixedBytes(2).coerce_str("0xab").is_err());
+        assert_eq!(
+            DynSolType::Bytes.coerce_str("abcd").unwrap(),
+            DynSolValue::Bytes(vec![0xab, 0xcd])
+        );
+        assert_eq!(
+            DynSolType::String.coerce_str("\"a, b\"").unwrap(),
+            DynSolValue::String("a, b".to_owned())
+        );
+    }
+
+    #[test]
+    fn coerce_ints() {
+        let uint = |n, s| {
+            DynSolType::Uint(n)
+                .coerce_str(s)
+                .map(|v| v.as_uint().unwrap().0)
+        };
+        let int = |n, s| {
+            DynSolType::Int(n)
+                .coerce_str(s)
+                .map(|v| v.as_int().unwrap().0)
+        };
+
+        assert_eq!(uint(256, "42"), Ok(U256::from(42)));
+        assert_eq!(uint(256, "0x2a"), Ok(U256::from(42)));
+        assert_eq!(uint(256, "1.5 gwei"), Ok(U256::from(1_500_000_000u64)));
+        assert_eq!(
+            uint(256, "2ether"),
+            Ok(U256::from(2_000_000_000_000_000_000u64))
+        );
+        assert_eq!(uint(8, "255"), Ok(U256::from(255)));
+        assert!(uint(8, "256").is_err());
+        assert!(uint(8, "0x100").is_err());
+        assert!(uint(256, "-1").is_err());
+        assert!(uint(256, "1.5").is_err());
+        assert!(uint(256, "1 foo").is_err());
+        assert!(uint(256, "0x").is_err());
+
+        assert_eq!(int(256, "-42"), Ok(I256::try_from(-42).unwrap()));
+        assert_eq!(int(256, "-0x2a"), Ok(I256::try_from(-42).unwrap()));
+        assert_eq!(
+            int(256, "-1 gwei"),
+            Ok(I256::try_from(-1_000_000_000).unwrap())
+        );
+        assert_eq!(int(8, "127"), Ok(I256::try_from(127).unwrap()));
+        assert_eq!(int(8, "-128"), Ok(I256::try_from(-128).unwrap()));
+        assert!(int(8, "128").is_err());
+        assert!(int(8, "-129").is_err());
+    }
+
+    #[test]
+    fn coerce_fixed() {
+        let ufixed = |n, d, s| {
+            DynSolType::Ufixed(n, d)
+                .coerce_str(s)
+                .map(|v| v.as_ufixed().unwrap().0)
+        };
+        let fixed = |n, d, s| {
+            DynSolType::Fixed(n, d)
+                .coerce_str(s)
+                .map(|v| v.as_fixed().unwrap().0)
+        };
+
+        assert_eq!(
+            ufixed(128, 18, "1.5"),
+            Ok(U256::from(1_500_000_000_000_000_000u64))
+        );
+        assert_eq!(ufixed(8, 1, "25.5"), Ok(U256::from(255)));
+        assert_eq!(ufixed(8, 2, ".01"), Ok(U256::from(1)));
+        assert_eq!(ufixed(8, 0, "7"), Ok(U256::from(7)));
+        assert!(ufixed(8, 1, "25.6").is_err());
+        assert!(ufixed(8, 1, "0.05").is_err());
+        assert!(ufixed(8, 1, "-1").is_err());
+        assert!(ufixed(8, 1, ".").is_err());
+        assert!(ufixed(8, 1, "1 ether").is_err());
+
+        assert_eq!(fixed(16, 2, "-1.25"), Ok(I256::try_from(-125).unwrap()));
+        assert_eq!(fixed(8, 1, "-12.8"), Ok(I256::try_from(-128).unwrap()));
+        assert!(fixed(8, 1, "12.8").is_err());
+    }
+
+    #[test]
+    fn coerce_sequences() {
+        let ty: DynSolType = "(uint8,string,bool[])[2]".parse().unwrap();
+        let value = ty
+            .coerce_str(r#"[(1, "a, (b", [true, false]), (2, c, [])]"#)
+            .unwrap();
+        assert!(ty.matches(&value));
+        assert_eq!(
+            value,
+            DynSolValue::FixedArray(vec![
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(1), 8),
+                    DynSolValue::String("a, (b".to_owned()),
+                    DynSolValue::Array(vec![true.into(), false.into()]),
+                ]),
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(2), 8),
+                    DynSolValue::String(String::from("c")),
+                    DynSolValue::Array(vec![]),
+                ]),
+            ])
+        );
+
+        for s in [
+            "[(1, a, [])]",
+            "[(1, a), (2, b)]",
+            "[(1, a, [true]]",
+            "(1, a, [])",
+        ] {
+            assert!(ty.coerce_str(s).is_err(), "{s}");
+        }
+
+        let err = ty.coerce_str("[(1, a, []), (2, b, [maybe])]").unwrap_err();
+        assert_eq!(
+            err,
+            DynAbiError::InvalidValue {
+                expected: DynSolType::Bool,
+                value: "maybe".to_owned(),
+            }
+        );
+    }
+}
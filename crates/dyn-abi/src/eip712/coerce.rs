@@ -1,92 +1,205 @@
-use crate::{DynAbiError, DynAbiResult, DynSolType, DynSolValue, Word};
+use crate::{coerce::parse_hex, DynAbiError, DynAbiResult, DynSolType, DynSolValue, Word};
 use alloc::{
-    boxed::Box,
+    format,
     string::{String, ToString},
     vec::Vec,
 };
-use alloy_primitives::{Address, I256, U256};
+use alloy_primitives::{Sign, I256, U256};
+use serde_json::{Map, Value};
 
 impl DynSolType {
     /// Coerce a [`serde_json::Value`] to a [`DynSolValue`] via this type.
-    pub fn coerce(&self, value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
+    ///
+    /// Integers may be given as JSON numbers, or as decimal or hex strings.
+    /// Fixed-point numbers may be given as JSON numbers or decimal strings.
+    /// Hex strings may omit the `0x` prefix if they are not also valid decimal
+    /// numbers, so `"10"` is ten and `"ff"` is 255. Unlike
+    /// [`coerce_str`](Self::coerce_str), units such as `"1 ether"` are not
+    /// accepted. Addresses and bytes are hex strings, arrays and tuples are
+    /// JSON arrays, and structs are JSON objects.
+    ///
+    /// Errors inside arrays, tuples and structs are wrapped in
+    /// [`DynAbiError::InvalidField`], which records the path to the offending
+    /// field, such as `from.wallets[1]`.
+    pub fn coerce(&self, value: &Value) -> DynAbiResult<DynSolValue> {
         match self {
-            DynSolType::Address => address(value),
-            DynSolType::Bool => bool(value),
-            DynSolType::Int(n) => int(*n, value),
-            DynSolType::Uint(n) => uint(*n, value),
+            DynSolType::Address | DynSolType::Bool | DynSolType::Bytes => {
+                self.coerce_json_str(value)
+            }
+            DynSolType::Int(n) => int(*n, value).ok_or_else(|| self.mismatch(value)),
+            DynSolType::Uint(n) => uint(*n, value).ok_or_else(|| self.mismatch(value)),
+            DynSolType::Fixed(..) | DynSolType::Ufixed(..) => match value {
+                Value::Number(num) => self.coerce_str(&num.to_string()),
+                Value::String(s) => self.coerce_str(s),
+                _ => Err(self.mismatch(value)),
+            }
+            .map_err(|_| self.mismatch(value)),
             DynSolType::FixedBytes(n) => fixed_bytes(*n, value),
             DynSolType::String => string(value),
-            DynSolType::Bytes => bytes(value),
-            DynSolType::Array(inner) => array(inner, value),
-            DynSolType::FixedArray(inner, n) => fixed_array(inner, *n, value),
-            DynSolType::Tuple(inner) => tuple(inner, value),
+            DynSolType::Array(inner) => {
+                let arr = value.as_array().ok_or_else(|| self.mismatch(value))?;
+                seq(inner, arr).map(DynSolValue::Array)
+            }
+            DynSolType::FixedArray(inner, n) => {
+                let arr = value
+                    .as_array()
+                    .filter(|arr| arr.len() == *n)
+                    .ok_or_else(|| self.mismatch(value))?;
+                seq(inner, arr).map(DynSolValue::FixedArray)
+            }
+            DynSolType::Tuple(inner) => {
+                let arr = value
+                    .as_array()
+                    .filter(|arr| arr.len() == inner.len())
+                    .ok_or_else(|| self.mismatch(value))?;
+                inner
+                    .iter()
+                    .zip(arr)
+                    .enumerate()
+                    .map(|(i, (t, v))| t.coerce(v).map_err(|e| e.in_field(&format!("[{i}]"))))
+                    .collect::<DynAbiResult<_>>()
+                    .map(DynSolValue::Tuple)
+            }
             DynSolType::CustomStruct {
                 name,
                 prop_names,
                 tuple,
-            } => coerce_custom_struct(name, prop_names, tuple, value),
+            } => {
+                let map = value.as_object().ok_or_else(|| self.mismatch(value))?;
+                coerce_custom_struct(name, prop_names, tuple, map)
+            }
         }
     }
-}
 
-fn address(value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
-    let address = value
-        .as_str()
-        .map(|s| {
-            s.parse::<Address>()
-                .map_err(|_| DynAbiError::type_mismatch(DynSolType::Address, value))
-        })
-        .ok_or_else(|| DynAbiError::type_mismatch(DynSolType::Address, value))??;
+    fn coerce_json_str(&self, value: &Value) -> DynAbiResult<DynSolValue> {
+        match value {
+            Value::Bool(b) if *self == DynSolType::Bool => Ok(DynSolValue::Bool(*b)),
+            Value::String(s) => self.coerce_str(s).map_err(|_| self.mismatch(value)),
+            _ => Err(self.mismatch(value)),
+        }
+    }
 
-    Ok(DynSolValue::Address(address))
+    #[inline]
+    fn mismatch(&self, value: &Value) -> DynAbiError {
+        DynAbiError::type_mismatch(self.clone(), value)
+    }
 }
 
-fn bool(value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
-    if let Some(bool) = value.as_bool() {
-        return Ok(DynSolValue::Bool(bool))
+impl DynSolValue {
+    /// Converts this value to a [`serde_json::Value`], in a format that
+    /// [`DynSolType::coerce`] accepts.
+    ///
+    /// Integers and fixed-point numbers are converted to decimal strings, so
+    /// that they can be read without loss of precision, and addresses to
+    /// checksummed strings. Structs are converted to JSON objects, and all
+    /// other sequences to arrays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::U256;
+    /// use serde_json::json;
+    ///
+    /// let value = DynSolValue::Tuple(vec![
+    ///     DynSolValue::Uint(U256::from(42), 256),
+    ///     DynSolValue::Bytes(vec![0xab, 0xcd]),
+    /// ]);
+    /// let json = value.to_json();
+    /// assert_eq!(json, json!(["42", "0xabcd"]));
+    ///
+    /// let ty: DynSolType = "(uint256,bytes)".parse()?;
+    /// assert_eq!(ty.coerce(&json)?, value);
+    /// # Ok::<_, alloy_dyn_abi::DynAbiError>(())
+    /// ```
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Address(a) => Value::String(a.to_checksum(None)),
+            Self::Bool(b) => Value::Bool(*b),
+            Self::Int(i, _) => Value::String(i.to_string()),
+            Self::Uint(u, _) => Value::String(u.to_string()),
+            Self::Fixed(i, _, d) => Value::String(format_decimal(i.sign(), i.unsigned_abs(), *d)),
+            Self::Ufixed(u, _, d) => Value::String(format_decimal(Sign::Positive, *u, *d)),
+            Self::FixedBytes(word, n) => Value::String(hex::encode_prefixed(&word[..*n])),
+            Self::Bytes(b) => Value::String(hex::encode_prefixed(b)),
+            Self::String(s) => Value::String(s.clone()),
+            Self::Array(values) | Self::FixedArray(values) | Self::Tuple(values) => {
+                Value::Array(values.iter().map(Self::to_json).collect())
+            }
+            Self::CustomStruct {
+                prop_names, tuple, ..
+            } => Value::Object(
+                prop_names
+                    .iter()
+                    .cloned()
+                    .zip(tuple.iter().map(Self::to_json))
+                    .collect(),
+            ),
+        }
     }
+}
 
-    let bool = value
-        .as_str()
-        .map(|s| {
-            s.parse::<bool>()
-                .map_err(|_| DynAbiError::type_mismatch(DynSolType::Address, value))
-        })
-        .ok_or_else(|| DynAbiError::type_mismatch(DynSolType::Address, value))??;
-    Ok(DynSolValue::Bool(bool))
+fn int(n: usize, value: &Value) -> Option<DynSolValue> {
+    let i = match value {
+        Value::Number(num) => match num.as_i64() {
+            Some(i) => I256::try_from(i).ok()?,
+            None => I256::try_from(num.as_u64()?).ok()?,
+        },
+        Value::String(s) => {
+            let (sign, abs) = match s.strip_prefix('-') {
+                Some(abs) => (Sign::Negative, abs),
+                None => (Sign::Positive, s.as_str()),
+            };
+            I256::checked_from_sign_and_abs(sign, parse_uint(abs)?)?
+        }
+        _ => return None,
+    };
+    crate::coerce::fits_int(n, i).then_some(DynSolValue::Int(i, n))
 }
 
-fn int(n: usize, value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
-    if let Some(num) = value.as_i64() {
-        return Ok(DynSolValue::Int(I256::try_from(num).unwrap(), n))
-    }
+fn uint(n: usize, value: &Value) -> Option<DynSolValue> {
+    let u = match value {
+        Value::Number(num) => U256::from(num.as_u64()?),
+        Value::String(s) => parse_uint(s)?,
+        _ => return None,
+    };
+    (u.bit_len() <= n).then_some(DynSolValue::Uint(u, n))
+}
 
-    if let Some(Ok(i)) = value.as_str().map(|s| s.parse()) {
-        return Ok(DynSolValue::Int(i, n))
+/// Formats a fixed-point number, given as its integer representation scaled by
+/// `10^decimals`, as a decimal number.
+fn format_decimal(sign: Sign, abs: U256, decimals: usize) -> String {
+    let digits = abs.to_string();
+    let mut s = String::with_capacity(digits.len() + decimals + 3);
+    if sign.is_negative() && abs != U256::ZERO {
+        s.push('-');
+    }
+    if decimals == 0 {
+        s.push_str(&digits);
+        return s
     }
 
-    Err(DynAbiError::type_mismatch(DynSolType::Int(n), value))
+    // left-pad so that there is at least one integer digit
+    let zeros = (decimals + 1).saturating_sub(digits.len());
+    s.extend(core::iter::repeat('0').take(zeros));
+    s.push_str(&digits);
+    s.insert(s.len() - decimals, '.');
+    s
 }
 
-fn uint(n: usize, value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
-    if let Some(num) = value.as_u64() {
-        return Ok(DynSolValue::Uint(U256::from(num), n))
-    }
-
-    if let Some(s) = value.as_str() {
-        let s = s.strip_prefix("0x").unwrap_or(s);
-        if let Ok(int) = U256::from_str_radix(s, 10) {
-            return Ok(DynSolValue::Uint(int, n))
-        }
-        if let Ok(int) = U256::from_str_radix(s, 16) {
-            return Ok(DynSolValue::Uint(int, n))
+/// Parses an unsigned integer string: `0x`-prefixed hex, decimal, or
+/// unprefixed hex if it is not a valid decimal number.
+fn parse_uint(s: &str) -> Option<U256> {
+    match s.strip_prefix("0x") {
+        Some(hex) => parse_hex(hex),
+        None if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => {
+            U256::from_str_radix(s, 10).ok()
         }
+        None => parse_hex(s),
     }
-
-    Err(DynAbiError::type_mismatch(DynSolType::Uint(n), value))
 }
 
-fn fixed_bytes(n: usize, value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
+fn fixed_bytes(n: usize, value: &Value) -> DynAbiResult<DynSolValue> {
     if let Some(Ok(buf)) = value.as_str().map(hex::decode) {
         let mut word: Word = Default::default();
         let min = n.min(buf.len());
@@ -97,7 +210,7 @@ fn fixed_bytes(n: usize, value: &serde_json::Value) -> DynAbiResult<DynSolValue>
     Err(DynAbiError::type_mismatch(DynSolType::FixedBytes(n), value))
 }
 
-fn string(value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
+fn string(value: &Value) -> DynAbiResult<DynSolValue> {
     let string = value
         .as_str()
         .map(|s| s.to_string())
@@ -105,124 +218,37 @@ fn string(value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
     Ok(DynSolValue::String(string))
 }
 
-fn bytes(value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
-    let bytes = value
-        .as_str()
-        .map(|s| hex::decode(s).map_err(|_| DynAbiError::type_mismatch(DynSolType::Bytes, value)))
-        .ok_or_else(|| DynAbiError::type_mismatch(DynSolType::Bytes, value))??;
-    Ok(DynSolValue::Bytes(bytes))
-}
-
-fn tuple(inner: &[DynSolType], value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
-    if let Some(arr) = value.as_array() {
-        if inner.len() != arr.len() {
-            return Err(DynAbiError::type_mismatch(
-                DynSolType::Tuple(inner.to_vec()),
-                value,
-            ))
-        }
-
-        let tuple = arr
-            .iter()
-            .zip(inner.iter())
-            .map(|(v, t)| t.coerce(v))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        return Ok(DynSolValue::Tuple(tuple))
-    }
-
-    Err(DynAbiError::type_mismatch(
-        DynSolType::Tuple(inner.to_vec()),
-        value,
-    ))
-}
-
-fn array(inner: &DynSolType, value: &serde_json::Value) -> DynAbiResult<DynSolValue> {
-    if let Some(arr) = value.as_array() {
-        let array = arr
-            .iter()
-            .map(|v| inner.coerce(v))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        return Ok(DynSolValue::Array(array))
-    }
-
-    Err(DynAbiError::type_mismatch(
-        DynSolType::Array(Box::new(inner.clone())),
-        value,
-    ))
+fn seq(inner: &DynSolType, values: &[Value]) -> DynAbiResult<Vec<DynSolValue>> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| inner.coerce(v).map_err(|e| e.in_field(&format!("[{i}]"))))
+        .collect()
 }
 
-fn fixed_array(
-    inner: &DynSolType,
-    n: usize,
-    value: &serde_json::Value,
-) -> DynAbiResult<DynSolValue> {
-    if let Some(arr) = value.as_array() {
-        if arr.len() != n {
-            return Err(DynAbiError::type_mismatch(
-                DynSolType::FixedArray(Box::new(inner.clone()), n),
-                value,
-            ))
-        }
-
-        let array = arr
-            .iter()
-            .map(|v| inner.coerce(v))
-            .collect::<Result<Vec<_>, _>>()?;
-
-        return Ok(DynSolValue::FixedArray(array))
-    }
-
-    Err(DynAbiError::type_mismatch(
-        DynSolType::FixedArray(Box::new(inner.clone()), n),
-        value,
-    ))
-}
-
-pub(crate) fn coerce_custom_struct(
+fn coerce_custom_struct(
     name: &str,
     prop_names: &[String],
     inner: &[DynSolType],
-    value: &serde_json::Value,
+    map: &Map<String, Value>,
 ) -> Result<DynSolValue, DynAbiError> {
-    if let Some(map) = value.as_object() {
-        let mut tuple = vec![];
-        for (name, ty) in prop_names.iter().zip(inner.iter()) {
-            if let Some(v) = map.get(name) {
-                tuple.push(ty.coerce(v)?);
-            } else {
-                return Err(DynAbiError::type_mismatch(
-                    DynSolType::CustomStruct {
-                        name: name.to_string(),
-                        prop_names: prop_names.to_vec(),
-                        tuple: inner.to_vec(),
-                    },
-                    value,
-                ))
-            }
-        }
-        return Ok(DynSolValue::CustomStruct {
-            name: name.to_string(),
-            prop_names: prop_names.to_vec(),
-            tuple,
-        })
+    let mut tuple = Vec::with_capacity(inner.len());
+    for (prop_name, ty) in prop_names.iter().zip(inner) {
+        // a missing field is reported as a `null` value
+        let v = map.get(prop_name).unwrap_or(&Value::Null);
+        tuple.push(ty.coerce(v).map_err(|e| e.in_field(prop_name))?);
     }
-
-    Err(DynAbiError::type_mismatch(
-        DynSolType::CustomStruct {
-            name: name.to_string(),
-            prop_names: prop_names.to_vec(),
-            tuple: inner.to_vec(),
-        },
-        value,
-    ))
+    Ok(DynSolValue::CustomStruct {
+        name: name.to_string(),
+        prop_names: prop_names.to_vec(),
+        tuple,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::{borrow::ToOwned, string::ToString};
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
     use serde_json::json;
 
     #[test]
@@ -331,4 +357,151 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn coerces_ints() {
+        let uint = |n, v: Value| DynSolType::Uint(n).coerce(&v);
+        assert_eq!(
+            uint(8, json!(42)).unwrap(),
+            DynSolValue::Uint(U256::from(42), 8)
+        );
+        assert_eq!(
+            uint(8, json!("42")).unwrap(),
+            DynSolValue::Uint(U256::from(42), 8)
+        );
+        assert_eq!(
+            uint(8, json!("0x10")).unwrap(),
+            DynSolValue::Uint(U256::from(16), 8)
+        );
+        assert_eq!(
+            uint(8, json!("ff")).unwrap(),
+            DynSolValue::Uint(U256::from(255), 8)
+        );
+        assert_eq!(
+            uint(8, json!("10")).unwrap(),
+            DynSolValue::Uint(U256::from(10), 8)
+        );
+        assert!(uint(8, json!(256)).is_err());
+        assert!(uint(8, json!("0x100")).is_err());
+        assert!(uint(8, json!(-1)).is_err());
+        assert!(uint(8, json!(4.2)).is_err());
+        for s in ["", "0x", "-1", "1 gwei", "1ether", " 1", "1_0", "0xgg"] {
+            assert!(uint(256, json!(s)).is_err(), "{s}");
+        }
+
+        let int = |n, v: Value| DynSolType::Int(n).coerce(&v);
+        let i = |i: i64| I256::try_from(i).unwrap();
+        assert_eq!(int(8, json!(-128)).unwrap(), DynSolValue::Int(i(-128), 8));
+        assert_eq!(int(8, json!("-1")).unwrap(), DynSolValue::Int(i(-1), 8));
+        assert_eq!(
+            int(256, json!(u64::MAX)).unwrap(),
+            DynSolValue::Int(I256::try_from(u64::MAX).unwrap(), 256)
+        );
+        assert_eq!(
+            int(8, json!("-0x80")).unwrap(),
+            DynSolValue::Int(i(-128), 8)
+        );
+        assert_eq!(
+            int(16, json!("-ff")).unwrap(),
+            DynSolValue::Int(i(-255), 16)
+        );
+        assert!(int(8, json!(128)).is_err());
+        assert!(int(8, json!("0x80")).is_err());
+        assert!(int(256, json!("-1 gwei")).is_err());
+        assert!(int(256, json!("--1")).is_err());
+    }
+
+    #[test]
+    fn roundtrips() {
+        let ty: DynSolType = "(address,bool,int16,uint256,bytes4,bytes,string,uint8[][2])"
+            .parse()
+            .unwrap();
+        let json = json!([
+            "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826",
+            true,
+            "-300",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            "0x01020304",
+            "0xabcd",
+            "hello",
+            [["1", "2"], []]
+        ]);
+        let value = ty.coerce(&json).unwrap();
+        assert!(ty.matches(&value));
+        assert_eq!(value.to_json(), json);
+
+        let ty: DynSolType = "(fixed,ufixed8x3,fixed16x0)".parse().unwrap();
+        let value = ty.coerce(&json!([-1.5, "0.25", 42])).unwrap();
+        assert_eq!(
+            value,
+            DynSolValue::Tuple(vec![
+                DynSolValue::Fixed(
+                    I256::try_from(-1_500_000_000_000_000_000i64).unwrap(),
+                    128,
+                    18
+                ),
+                DynSolValue::Ufixed(U256::from(250), 8, 3),
+                DynSolValue::Fixed(I256::try_from(42).unwrap(), 16, 0),
+            ])
+        );
+        let json = json!(["-1.500000000000000000", "0.250", "42"]);
+        assert_eq!(value.to_json(), json);
+        assert_eq!(ty.coerce(&json).unwrap(), value);
+
+        let person = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_string(), "wallets".to_string()],
+            tuple: vec![
+                DynSolType::String,
+                DynSolType::Array(Box::new(DynSolType::Address)),
+            ],
+        };
+        let json = json!({
+            "name": "Bob",
+            "wallets": ["0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"],
+        });
+        assert_eq!(person.coerce(&json).unwrap().to_json(), json);
+    }
+
+    #[test]
+    fn error_paths() {
+        let person = DynSolType::CustomStruct {
+            name: "Person".to_owned(),
+            prop_names: vec!["name".to_string(), "wallets".to_string()],
+            tuple: vec![
+                DynSolType::String,
+                DynSolType::Array(Box::new(DynSolType::Address)),
+            ],
+        };
+        let mail = DynSolType::CustomStruct {
+            name: "Mail".to_owned(),
+            prop_names: vec!["from".to_string(), "to".to_string()],
+            tuple: vec![person.clone(), DynSolType::Array(Box::new(person))],
+        };
+
+        let path = |json: Value| match mail.coerce(&json).unwrap_err() {
+            DynAbiError::InvalidField { path, error } => (path, *error),
+            e => panic!("unexpected error: {e}"),
+        };
+
+        let (p, e) = path(json!({
+            "from": { "name": "Cow", "wallets": [] },
+            "to": [
+                { "name": "Bob", "wallets": [] },
+                { "name": "Alice", "wallets": ["0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826", "0x1234"] },
+            ],
+        }));
+        assert_eq!(p, "to[1].wallets[1]");
+        assert_eq!(
+            e,
+            DynAbiError::type_mismatch(DynSolType::Address, &json!("0x1234"))
+        );
+
+        let (p, e) = path(json!({ "from": { "wallets": [] }, "to": [] }));
+        assert_eq!(p, "from.name");
+        assert_eq!(
+            e,
+            DynAbiError::type_mismatch(DynSolType::String, &Value::Null)
+        );
+    }
 }
@@ -17,6 +17,14 @@ pub enum DynAbiError {
         /// The actual type.
         actual: serde_json::Value,
     },
+    /// Error in a field of an array, tuple or struct during coercion.
+    #[cfg(feature = "eip712")]
+    InvalidField {
+        /// The path to the field, such as `from.wallets[1]`.
+        path: alloc::string::String,
+        /// The error in the field.
+        error: alloc::boxed::Box<DynAbiError>,
+    },
     /// Unknown type referenced from another type.
     #[cfg(feature = "eip712")]
     MissingType(alloc::string::String),
@@ -27,6 +35,14 @@ pub enum DynAbiError {
     #[cfg(feature = "eip712")]
     InvalidPropertyDefinition(alloc::string::String),
 
+    /// Failed to coerce a string into a value of the expected type.
+    InvalidValue {
+        /// The expected type.
+        expected: crate::DynSolType,
+        /// The input string.
+        value: alloc::string::String,
+    },
+
     /// Hex.
     HexError(hex::FromHexError),
     /// Type Str Error
@@ -61,6 +77,8 @@ impl fmt::Display for DynAbiError {
                 write!(f, "Type mismatch, expected: {expected:?}, actual: {actual}")
             }
             #[cfg(feature = "eip712")]
+            DynAbiError::InvalidField { path, error } => write!(f, "{path}: {error}"),
+            #[cfg(feature = "eip712")]
             DynAbiError::MissingType(name) => write!(f, "Missing type in type resolution: {name}"),
             #[cfg(feature = "eip712")]
             DynAbiError::CircularDependency(dep) => write!(f, "Circular dependency: {dep}"),
@@ -69,6 +87,10 @@ impl fmt::Display for DynAbiError {
                 write!(f, "Invalid property definition: {def}")
             }
 
+            DynAbiError::InvalidValue { expected, value } => {
+                write!(f, "Invalid value for type {expected}: {value:?}")
+            }
+
             DynAbiError::HexError(h) => h.fmt(f),
             DynAbiError::TypeParserError(e) => e.fmt(f),
         }
@@ -95,6 +117,26 @@ impl DynAbiError {
         }
     }
 
+    /// Wraps `self` in an [`InvalidField`](Self::InvalidField) error, or
+    /// prepends `field` to the path of an existing one. `field` is either a
+    /// property name or an index such as `[0]`.
+    #[cfg(feature = "eip712")]
+    pub(crate) fn in_field(self, field: &str) -> DynAbiError {
+        match self {
+            DynAbiError::InvalidField { path, error } => {
+                let sep = if path.starts_with('[') { "" } else { "." };
+                DynAbiError::InvalidField {
+                    path: format!("{field}{sep}{path}"),
+                    error,
+                }
+            }
+            error => DynAbiError::InvalidField {
+                path: field.into(),
+                error: alloc::boxed::Box::new(error),
+            },
+        }
+    }
+
     #[cfg(feature = "eip712")]
     #[inline]
     pub(crate) fn invalid_property_def(def: &str) -> DynAbiError {
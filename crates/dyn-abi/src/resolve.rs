@@ -56,6 +56,8 @@ impl ResolveSolType for RootType<'_> {
             "bytes" => Ok(DynSolType::Bytes),
             "uint" => Ok(DynSolType::Uint(256)),
             "int" => Ok(DynSolType::Int(256)),
+            "ufixed" => Ok(DynSolType::Ufixed(128, 18)),
+            "fixed" => Ok(DynSolType::Fixed(128, 18)),
             name => {
                 if let Some(sz) = name.strip_prefix("bytes") {
                     if let Ok(sz) = sz.parse() {
@@ -66,7 +68,7 @@ impl ResolveSolType for RootType<'_> {
                     return Err(TypeStrError::invalid_size(name).into())
                 }
 
-                // fast path both integer types
+                // fast path both integer and both fixed-point types
                 let (s, is_uint) = if let Some(s) = name.strip_prefix('u') {
                     (s, true)
                 } else {
@@ -84,6 +86,19 @@ impl ResolveSolType for RootType<'_> {
                         }
                     }
                     Err(TypeStrError::invalid_size(name).into())
+                } else if let Some(sz) = s.strip_prefix("fixed") {
+                    if let Some((m, n)) = sz.split_once('x') {
+                        if let (Ok(m), Ok(n)) = (m.parse(), n.parse()) {
+                            if m != 0 && m <= 256 && m % 8 == 0 && n <= 80 {
+                                return if is_uint {
+                                    Ok(DynSolType::Ufixed(m, n))
+                                } else {
+                                    Ok(DynSolType::Fixed(m, n))
+                                }
+                            }
+                        }
+                    }
+                    Err(TypeStrError::invalid_size(name).into())
                 } else {
                     Err(TypeStrError::invalid_type_string(name).into())
                 }
@@ -303,6 +318,12 @@ mod tests {
         assert_eq!(parse("string").unwrap(), DynSolType::String);
         assert_eq!(parse("bytes").unwrap(), DynSolType::Bytes);
         assert_eq!(parse("bytes32").unwrap(), DynSolType::FixedBytes(32));
+        assert_eq!(parse("fixed").unwrap(), DynSolType::Fixed(128, 18));
+        assert_eq!(parse("ufixed").unwrap(), DynSolType::Ufixed(128, 18));
+        assert_eq!(parse("fixed64x10").unwrap(), DynSolType::Fixed(64, 10));
+        assert_eq!(parse("ufixed256x80").unwrap(), DynSolType::Ufixed(256, 80));
+        assert!(parse("fixed64x81").is_err());
+        assert!(parse("ufixed7x1").is_err());
     }
 
     #[test]
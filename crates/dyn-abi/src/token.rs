@@ -153,7 +153,7 @@ impl<'a> DynToken<'a> {
             }
             Self::DynSeq { contents, template } => {
                 let mut child = dec.take_indirection()?;
-                let size = child.take_u32()? as usize;
+                let size = child.take_seq_len()?;
                 // This appears to be an unclarity in the solidity spec. The
                 // spec specifies that offsets are relative to the beginning of
                 // `enc(X)`. But known-good test vectors have it relative to the
@@ -91,6 +91,12 @@ pub enum DynSolType {
     Int(usize),
     /// Unsigned Integer.
     Uint(usize),
+    /// Signed fixed-point number, with its size in bits and number of
+    /// decimals.
+    Fixed(usize, usize),
+    /// Unsigned fixed-point number, with its size in bits and number of
+    /// decimals.
+    Ufixed(usize, usize),
     /// Fixed-size bytes, up to 32.
     FixedBytes(usize),
 
@@ -225,6 +231,12 @@ impl DynSolType {
             Self::Bytes => matches!(value, DynSolValue::Bytes(_)),
             Self::Int(size) => matches!(value, DynSolValue::Int(_, s) if s == size),
             Self::Uint(size) => matches!(value, DynSolValue::Uint(_, s) if s == size),
+            Self::Fixed(size, decimals) => {
+                matches!(value, DynSolValue::Fixed(_, s, d) if s == size && d == decimals)
+            }
+            Self::Ufixed(size, decimals) => {
+                matches!(value, DynSolValue::Ufixed(_, s, d) if s == size && d == decimals)
+            }
             Self::Bool => matches!(value, DynSolValue::Bool(_)),
             Self::Array(t) => {
                 matches!(value, DynSolValue::Array(v) if v.iter().all(|v| t.matches(v)))
@@ -236,7 +248,9 @@ impl DynSolType {
                 DynSolValue::FixedArray(v) if v.len() == *size && v.iter().all(|v| t.matches(v))
             ),
             Self::Tuple(types) => match value {
-                as_tuple!(DynSolValue tuple) => types.iter().zip(tuple).all(|(t, v)| t.matches(v)),
+                as_tuple!(DynSolValue tuple) => {
+                    types.len() == tuple.len() && types.iter().zip(tuple).all(|(t, v)| t.matches(v))
+                }
                 _ => false,
             },
             #[cfg(feature = "eip712")]
@@ -257,7 +271,7 @@ impl DynSolType {
                         && tuple.len() == t.len()
                         && tuple.iter().zip(t).all(|(a, b)| a.matches(b))
                 } else if let DynSolValue::Tuple(v) = value {
-                    v.iter().zip(tuple).all(|(v, t)| t.matches(v))
+                    v.len() == tuple.len() && v.iter().zip(tuple).all(|(v, t)| t.matches(v))
                 } else {
                     false
                 }
@@ -289,6 +303,16 @@ impl DynSolType {
                 sol_data::Uint::<256>::detokenize(word.into()),
                 *size,
             )),
+            (Self::Fixed(size, decimals), DynToken::Word(word)) => Ok(DynSolValue::Fixed(
+                sol_data::Int::<256>::detokenize(word.into()),
+                *size,
+                *decimals,
+            )),
+            (Self::Ufixed(size, decimals), DynToken::Word(word)) => Ok(DynSolValue::Ufixed(
+                sol_data::Uint::<256>::detokenize(word.into()),
+                *size,
+                *decimals,
+            )),
 
             (Self::String, DynToken::PackedSeq(buf)) => Ok(DynSolValue::String(
                 sol_data::String::detokenize(buf.into()),
@@ -393,6 +417,17 @@ impl DynSolType {
                 out.push_str(prefix);
                 out.push_str(itoa::Buffer::new().format(*size));
             }
+            Self::Fixed(size, decimals) | Self::Ufixed(size, decimals) => {
+                let prefix = match self {
+                    Self::Fixed(..) => "fixed",
+                    Self::Ufixed(..) => "ufixed",
+                    _ => unreachable!(),
+                };
+                out.push_str(prefix);
+                out.push_str(itoa::Buffer::new().format(*size));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*decimals));
+            }
 
             Self::Tuple(inner) => {
                 out.push('(');
@@ -450,9 +485,13 @@ impl DynSolType {
     /// Instantiate an empty dyn token, to be decoded into.
     pub(crate) fn empty_dyn_token(&self) -> DynToken<'_> {
         match self {
-            Self::Address | Self::Bool | Self::FixedBytes(_) | Self::Int(_) | Self::Uint(_) => {
-                DynToken::Word(Word::ZERO)
-            }
+            Self::Address
+            | Self::Bool
+            | Self::FixedBytes(_)
+            | Self::Int(_)
+            | Self::Uint(_)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => DynToken::Word(Word::ZERO),
 
             Self::Bytes | Self::String => DynToken::PackedSeq(&[]),
 
@@ -524,7 +563,7 @@ impl DynSolType {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{hex, Address};
+    use alloy_primitives::{hex, Address, I256, U256};
 
     #[test]
     fn dynamically_encodes() {
@@ -554,6 +593,78 @@ mod tests {
         assert_eq!(enc.finish(), vec![word1, word2]);
     }
 
+    #[test]
+    fn static_parity() {
+        use alloy_sol_types::sol_data::{Address as SolAddress, Array, FixedArray, Uint};
+
+        type T = Array<FixedArray<(Uint<256>, SolAddress), 3>>;
+
+        let ty: DynSolType = "(uint256,address)[3][]".parse().unwrap();
+        assert_eq!(ty.sol_type_name(), T::sol_type_name());
+
+        let rust = vec![
+            [
+                (U256::from(1), Address::repeat_byte(0x11)),
+                (U256::from(2), Address::repeat_byte(0x22)),
+                (U256::MAX, Address::ZERO),
+            ];
+            2
+        ];
+        let encoded = T::encode_single(&rust);
+        let value = ty.decode_single(&encoded).unwrap();
+        assert!(ty.matches(&value));
+        assert_eq!(value.encode_single(), encoded);
+        assert_eq!(
+            T::decode_single(&value.encode_single(), true).unwrap(),
+            rust
+        );
+    }
+
+    #[test]
+    fn fixed_parity() {
+        use alloy_sol_types::{
+            sol_data::{Fixed, Ufixed},
+            FixedPoint,
+        };
+
+        type T = (Fixed<64, 10>, Ufixed<8, 1>);
+
+        let ty: DynSolType = "(fixed64x10,ufixed8x1)".parse().unwrap();
+        assert_eq!(ty.sol_type_name(), T::sol_type_name());
+
+        let rust = (FixedPoint(-15_000_000_000i64), FixedPoint(255u8));
+        let encoded = T::encode_single(&rust);
+        let value = ty.decode_single(&encoded).unwrap();
+        assert!(ty.matches(&value));
+        assert_eq!(value.as_type().unwrap(), ty);
+        assert_eq!(
+            value.as_tuple().unwrap()[0].as_fixed(),
+            Some((I256::try_from(-15_000_000_000i64).unwrap(), 64, 10))
+        );
+        assert_eq!(value.encode_single(), encoded);
+        assert_eq!(value.encode_packed(), T::encode_packed(&rust));
+    }
+
+    #[test]
+    fn value_matches() {
+        let ty: DynSolType = "(uint8,address[])".parse().unwrap();
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(1), 8),
+            DynSolValue::Array(vec![Address::ZERO.into()]),
+        ]);
+        assert!(value.matches(&ty));
+        assert_eq!(ty.decode_params(&value.encode_params()).unwrap(), value);
+
+        assert!(!DynSolValue::Uint(U256::from(1), 8).matches(&ty));
+        assert!(!DynSolValue::Uint(U256::from(1), 16).matches(&DynSolType::Uint(8)));
+        // missing and extra tuple elements
+        let short = DynSolValue::Tuple(vec![DynSolValue::Uint(U256::from(1), 8)]);
+        assert!(!short.matches(&ty));
+        let mut long = value.as_tuple().unwrap().to_vec();
+        long.push(true.into());
+        assert!(!DynSolValue::Tuple(long).matches(&ty));
+    }
+
     // also tests the type name parser
     macro_rules! encoder_tests {
         ($($name:ident($ty:literal, $encoded:literal)),* $(,)?) => {$(
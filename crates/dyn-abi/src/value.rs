@@ -45,6 +45,12 @@ pub enum DynSolValue {
     Int(I256, usize),
     /// An unsigned integer.
     Uint(U256, usize),
+    /// A signed fixed-point number, as its scaled integer, the size in bits
+    /// and the number of decimals.
+    Fixed(I256, usize, usize),
+    /// An unsigned fixed-point number, as its scaled integer, the size in bits
+    /// and the number of decimals.
+    Ufixed(U256, usize, usize),
     /// A fixed-length byte string.
     FixedBytes(Word, usize),
 
@@ -177,6 +183,8 @@ impl DynSolValue {
             Self::FixedBytes(_, size) => DynSolType::FixedBytes(*size),
             Self::Int(_, size) => DynSolType::Int(*size),
             Self::Uint(_, size) => DynSolType::Uint(*size),
+            Self::Fixed(_, size, decimals) => DynSolType::Fixed(*size, *decimals),
+            Self::Ufixed(_, size, decimals) => DynSolType::Ufixed(*size, *decimals),
             Self::String(_) => DynSolType::String,
             Self::Tuple(inner) => {
                 return inner
@@ -246,6 +254,17 @@ impl DynSolValue {
                 out.push_str(prefix);
                 out.push_str(itoa::Buffer::new().format(*size));
             }
+            Self::Fixed(_, size, decimals) | Self::Ufixed(_, size, decimals) => {
+                let prefix = match self {
+                    Self::Fixed(..) => "fixed",
+                    Self::Ufixed(..) => "ufixed",
+                    _ => unreachable!(),
+                };
+                out.push_str(prefix);
+                out.push_str(itoa::Buffer::new().format(*size));
+                out.push('x');
+                out.push_str(itoa::Buffer::new().format(*decimals));
+            }
 
             Self::Tuple(inner) => {
                 out.push('(');
@@ -288,6 +307,13 @@ impl DynSolValue {
         true
     }
 
+    /// Check that this value matches the given type. This is the same as
+    /// [`DynSolType::matches`].
+    #[inline]
+    pub fn matches(&self, ty: &DynSolType) -> bool {
+        ty.matches(self)
+    }
+
     /// The Solidity type name. This returns the solidity type corresponding to
     /// this value, if it is known. A type will not be known if the value
     /// contains an empty sequence, e.g. `T[0]`.
@@ -318,6 +344,8 @@ impl DynSolValue {
                 | Self::FixedBytes(..)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
         )
     }
 
@@ -330,6 +358,8 @@ impl DynSolValue {
             Self::FixedBytes(w, _) => Some(w),
             Self::Int(i, _) => Some(i.into()),
             Self::Uint(u, _) => Some(u.into()),
+            Self::Fixed(i, ..) => Some(i.into()),
+            Self::Ufixed(u, ..) => Some(u.into()),
             _ => None,
         }
     }
@@ -388,6 +418,24 @@ impl DynSolValue {
         }
     }
 
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_fixed(&self) -> Option<(I256, usize, usize)> {
+        match self {
+            Self::Fixed(i, size, decimals) => Some((*i, *size, *decimals)),
+            _ => None,
+        }
+    }
+
+    /// Fallible cast to the contents of a variant.
+    #[inline]
+    pub const fn as_ufixed(&self) -> Option<(U256, usize, usize)> {
+        match self {
+            Self::Ufixed(u, size, decimals) => Some((*u, *size, *decimals)),
+            _ => None,
+        }
+    }
+
     /// Fallible cast to the contents of a variant.
     #[inline]
     pub fn as_str(&self) -> Option<&str> {
@@ -493,6 +541,8 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..) => false,
             Self::Bytes(_) | Self::String(_) | Self::Array(_) => true,
             as_fixed_seq!(tuple) => tuple.iter().any(Self::is_dynamic),
@@ -530,7 +580,9 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => 0,
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => 0,
 
             // `self.as_packed_seq()`
             // 1 for the length, then the body padded to the next word.
@@ -573,7 +625,9 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => enc.append_word(unsafe { self.as_word().unwrap_unchecked() }),
 
             Self::String(_) | Self::Bytes(_) | Self::Array(_) => enc.append_indirection(),
 
@@ -595,7 +649,9 @@ impl DynSolValue {
             | Self::Bool(_)
             | Self::FixedBytes(..)
             | Self::Int(..)
-            | Self::Uint(..) => {}
+            | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..) => {}
 
             Self::String(string) => enc.append_packed_seq(string.as_bytes()),
             Self::Bytes(bytes) => enc.append_packed_seq(bytes),
@@ -634,6 +690,12 @@ impl DynSolValue {
             Self::Uint(num, size) => {
                 buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size)..])
             }
+            Self::Fixed(num, size, _) => {
+                buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size / 8)..])
+            }
+            Self::Ufixed(num, size, _) => {
+                buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size / 8)..])
+            }
             as_fixed_seq!(inner) | Self::Array(inner) => {
                 inner.iter().for_each(|v| v.encode_packed_to(buf))
             }
@@ -658,6 +720,8 @@ impl DynSolValue {
             Self::FixedBytes(buf, _) => (*buf).into(),
             Self::Int(int, _) => int.to_be_bytes::<32>().into(),
             Self::Uint(uint, _) => uint.to_be_bytes::<32>().into(),
+            Self::Fixed(int, ..) => int.to_be_bytes::<32>().into(),
+            Self::Ufixed(uint, ..) => uint.to_be_bytes::<32>().into(),
             Self::String(s) => DynToken::PackedSeq(s.as_bytes()),
             Self::Array(t) => DynToken::from_dyn_seq(t),
             as_fixed_seq!(t) => DynToken::from_fixed_seq(t),
@@ -29,7 +29,13 @@ int_aliases! {
     I160<160, 3>,
     I192<192, 3>,
     I256<256, 4>,
+    I320<320, 5>,
+    I384<384, 6>,
+    I448<448, 7>,
     I512<512, 8>,
+    I1024<1024, 16>,
+    I2048<2048, 32>,
+    I4096<4096, 64>,
 }
 
 macro_rules! fixed_bytes_aliases {
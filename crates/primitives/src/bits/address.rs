@@ -168,6 +168,12 @@ impl Address {
     /// let address = Address::parse_checksummed(checksummed, None).unwrap();
     /// let expected = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
     /// assert_eq!(address, expected);
+    ///
+    /// // EIP-1191 checksums depend on the chain ID, e.g. RSK mainnet (30)
+    /// let checksummed = "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD";
+    /// let address = Address::parse_checksummed(checksummed, Some(30)).unwrap();
+    /// assert_eq!(address.to_checksum(Some(30)), checksummed);
+    /// assert!(Address::parse_checksummed(checksummed, None).is_err());
     /// ```
     pub fn parse_checksummed<S: AsRef<str>>(
         s: S,
@@ -312,30 +318,47 @@ impl Address {
     /// let expected = address!("e33c6e89e69d085897f98e92b06ebd541d1daa99");
     /// assert_eq!(sender.create(1), expected);
     /// ```
-    #[cfg(feature = "rlp")]
     #[inline]
     #[must_use]
     pub fn create(&self, nonce: u64) -> Self {
-        use alloy_rlp::{Encodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE};
+        // RLP string and list header codes, for payloads shorter than 56 bytes
+        const EMPTY_STRING_CODE: u8 = 0x80;
+        const EMPTY_LIST_CODE: u8 = 0xC0;
 
         // max u64 encoded length is `1 + u64::BYTES`
         const MAX_LEN: usize = 1 + (1 + 20) + 9;
 
-        let len = 22 + nonce.length();
-        debug_assert!(len <= MAX_LEN);
-
         let mut out = [0u8; MAX_LEN];
 
-        // list header
-        // minus 1 to account for the list header itself
-        out[0] = EMPTY_LIST_CODE + len as u8 - 1;
-
         // address header + address
         out[1] = EMPTY_STRING_CODE + 20;
         out[2..22].copy_from_slice(self.as_slice());
 
-        // nonce
-        nonce.encode(&mut &mut out[22..]);
+        // nonce: single bytes below `0x80` are their own encoding, otherwise
+        // the big-endian bytes without leading zeros are prefixed by a header
+        let len = match nonce {
+            0 => {
+                out[22] = EMPTY_STRING_CODE;
+                23
+            }
+            1..=0x7f => {
+                out[22] = nonce as u8;
+                23
+            }
+            _ => {
+                let bytes = nonce.to_be_bytes();
+                let start = nonce.leading_zeros() as usize / 8;
+                let nonce_len = bytes.len() - start;
+                out[22] = EMPTY_STRING_CODE + nonce_len as u8;
+                out[23..23 + nonce_len].copy_from_slice(&bytes[start..]);
+                23 + nonce_len
+            }
+        };
+        debug_assert!(len <= MAX_LEN);
+
+        // list header
+        // minus 1 to account for the list header itself
+        out[0] = EMPTY_LIST_CODE + len as u8 - 1;
 
         let hash = keccak256(&out[..len]);
         Self::from_word(hash)
@@ -403,6 +426,50 @@ impl Address {
         self._create2(salt.borrow(), init_code_hash.borrow())
     }
 
+    /// Computes the address of a raw, uncompressed secp256k1 public key,
+    /// without its `0x04` SEC1 prefix:
+    ///
+    /// `keccak256(public_key)[12:]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, hex, Address};
+    /// let public_key = hex!(
+    ///     "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+    ///     "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+    /// );
+    /// let expected = address!("7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+    /// assert_eq!(Address::from_raw_public_key(&public_key), expected);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_raw_public_key(public_key: &[u8; 64]) -> Self {
+        Self::from_word(keccak256(public_key))
+    }
+
+    /// Computes the address of a secp256k1 public key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// # use alloy_primitives::k256::ecdsa::SigningKey;
+    /// let mut secret = [0; 32];
+    /// secret[31] = 1;
+    /// let signing_key = SigningKey::from_slice(&secret).unwrap();
+    /// let expected = address!("7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+    /// assert_eq!(Address::from_public_key(signing_key.verifying_key()), expected);
+    /// ```
+    #[cfg(feature = "k256")]
+    #[inline]
+    #[must_use]
+    pub fn from_public_key(public_key: &k256::ecdsa::VerifyingKey) -> Self {
+        let point = public_key.to_encoded_point(false);
+        // skip the `0x04` uncompressed point prefix
+        Self::from_raw_public_key(point.as_bytes()[1..].try_into().unwrap())
+    }
+
     // non-generic inner function
     fn _create2(&self, salt: &[u8; 32], init_code_hash: &[u8; 32]) -> Self {
         // note: creating a temporary buffer and copying everything over performs
@@ -526,7 +593,6 @@ mod tests {
 
     // https://ethereum.stackexchange.com/questions/760/how-is-the-address-of-an-ethereum-contract-computed
     #[test]
-    #[cfg(feature = "rlp")]
     fn create() {
         let from = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"
             .parse::<Address>()
@@ -2,7 +2,7 @@
 //!
 //! Adapted from <https://github.com/paritytech/parity-common/blob/2fb72eea96b6de4a085144ce239feb49da0cd39e/ethbloom/src/lib.rs>
 
-use crate::{keccak256, wrap_fixed_bytes, FixedBytes};
+use crate::{keccak256, wrap_fixed_bytes, Address, FixedBytes, B256};
 use core::borrow::Borrow;
 
 /// Number of bits to set per input in Ethereum bloom filter.
@@ -115,6 +115,23 @@ impl Bloom {
         *self |= *other;
     }
 
+    /// Accrues a log into the bloom filter: its emitting address and all of
+    /// its topics.
+    pub fn accrue_raw_log(&mut self, address: Address, topics: &[B256]) {
+        self.m3_2048(address.as_slice());
+        for topic in topics {
+            self.m3_2048(topic.as_slice());
+        }
+    }
+
+    /// Returns whether the bloom filter contains the given log (allowing for
+    /// false positives).
+    pub fn contains_raw_log(&self, address: Address, topics: &[B256]) -> bool {
+        let mut bloom = Self::default();
+        bloom.accrue_raw_log(address, topics);
+        self.contains(bloom)
+    }
+
     /// See Section 4.3.1 "Transaction Receipt" of the Ethereum Yellow Paper.
     pub fn m3_2048(&mut self, x: &[u8]) {
         let hash = keccak256(x);
@@ -177,4 +194,22 @@ mod tests {
 
         assert_eq!(my_bloom, bloom);
     }
+
+    #[test]
+    fn logs() {
+        let address = Address::from(hex!("ef2d6d194084c2de36e0dabfce45d046b37d1106"));
+        let topic = B256::from(hex!(
+            "02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc"
+        ));
+
+        let mut bloom = Bloom::default();
+        assert!(!bloom.contains_raw_log(address, &[topic]));
+
+        bloom.accrue_raw_log(address, &[topic]);
+        assert!(bloom.contains_raw_log(address, &[topic]));
+        assert!(bloom.contains_raw_log(address, &[]));
+        assert!(bloom.contains_input(BloomInput::Raw(address.as_slice())));
+        assert!(bloom.contains_input(BloomInput::Raw(topic.as_slice())));
+        assert!(!bloom.contains_raw_log(Address::ZERO, &[topic]));
+    }
 }
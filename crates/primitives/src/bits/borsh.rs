@@ -0,0 +1,39 @@
+use super::FixedBytes;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+/// Fixed bytes are Borsh-encoded like `[u8; N]`: the raw bytes, without a
+/// length prefix.
+impl<const N: usize> BorshSerialize for FixedBytes<N> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedBytes<N> {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = [0u8; N];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, B256};
+
+    #[test]
+    fn borsh() {
+        let hash = B256::repeat_byte(0x42);
+        let encoded = borsh::to_vec(&hash).unwrap();
+        assert_eq!(encoded, [0x42; 32]);
+        assert_eq!(borsh::from_slice::<B256>(&encoded).unwrap(), hash);
+        assert!(borsh::from_slice::<B256>(&encoded[1..]).is_err());
+
+        let address = Address::repeat_byte(0x42);
+        let encoded = borsh::to_vec(&address).unwrap();
+        assert_eq!(encoded, [0x42; 20]);
+        assert_eq!(borsh::from_slice::<Address>(&encoded).unwrap(), address);
+    }
+}
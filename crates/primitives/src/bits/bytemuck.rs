@@ -0,0 +1,39 @@
+use super::FixedBytes;
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+// SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`, which is `Pod`
+unsafe impl<const N: usize> Zeroable for FixedBytes<N> {}
+unsafe impl<const N: usize> Pod for FixedBytes<N> {}
+unsafe impl<const N: usize> TransparentWrapper<[u8; N]> for FixedBytes<N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, B256};
+
+    #[test]
+    fn cast_slices() {
+        let hashes = [B256::repeat_byte(1), B256::repeat_byte(2)];
+        let bytes: &[u8] = bytemuck::cast_slice(&hashes);
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(bytes[..32], [1; 32]);
+        assert_eq!(bytes[32..], [2; 32]);
+        assert_eq!(bytemuck::cast_slice::<u8, B256>(bytes), hashes);
+
+        let addresses: &[Address] = bytemuck::cast_slice(&bytes[..60]);
+        assert_eq!(
+            addresses,
+            [
+                Address::repeat_byte(1),
+                Address::from_slice(&bytes[20..40]),
+                Address::repeat_byte(2)
+            ]
+        );
+        assert!(bytemuck::try_cast_slice::<u8, Address>(&bytes[..59]).is_err());
+
+        assert_eq!(<Address as bytemuck::Zeroable>::zeroed(), Address::ZERO);
+        let array: &[u8; 32] = bytemuck::TransparentWrapper::peel_ref(&hashes[0]);
+        assert_eq!(array, &[1; 32]);
+        let address: &Address = bytemuck::TransparentWrapper::wrap_ref(&addresses[0].0);
+        assert_eq!(address, &addresses[0]);
+    }
+}
@@ -0,0 +1,37 @@
+use super::FixedBytes;
+
+/// Formats the bytes as a hex byte slice. The bytes are sent in a single write,
+/// and formatted by the host.
+impl<const N: usize> defmt::Format for FixedBytes<N> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=[u8]:x}", &self.0[..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bytes, FixedBytes, B256};
+
+    // Logging with `defmt` requires a global logger, so this is only compiled
+    // to check that the types can be used as `defmt::write!` arguments.
+    #[allow(dead_code)]
+    fn write(f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "{} {} {} {}",
+            FixedBytes([1, 2, 3]),
+            Address::ZERO,
+            B256::ZERO,
+            Bytes::from_static(&[1, 2, 3]),
+        );
+    }
+
+    #[test]
+    fn format() {
+        fn assert_format<T: defmt::Format + ?Sized>() {}
+        assert_format::<FixedBytes<3>>();
+        assert_format::<Address>();
+        assert_format::<B256>();
+        assert_format::<Bytes>();
+    }
+}
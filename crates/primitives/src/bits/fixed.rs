@@ -1,4 +1,5 @@
 use crate::aliases;
+use alloc::string::String;
 use core::{fmt, ops, str};
 use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
 
@@ -277,13 +278,75 @@ impl<const N: usize> ops::BitXorAssign for FixedBytes<N> {
     }
 }
 
+impl<const N: usize> ops::Not for FixedBytes<N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.bit_not()
+    }
+}
+
+/// Shifts the bytes towards the first byte, as a big-endian integer.
+impl<const N: usize> ops::Shl<usize> for FixedBytes<N> {
+    type Output = Self;
+
+    fn shl(mut self, rhs: usize) -> Self::Output {
+        self <<= rhs;
+        self
+    }
+}
+
+impl<const N: usize> ops::ShlAssign<usize> for FixedBytes<N> {
+    fn shl_assign(&mut self, rhs: usize) {
+        let (bytes, bits) = (rhs / 8, rhs % 8);
+        if bytes >= N {
+            *self = Self::ZERO;
+            return
+        }
+        self.0.copy_within(bytes.., 0);
+        self.0[N - bytes..].fill(0);
+        if bits > 0 {
+            for i in 0..N {
+                let next = if i + 1 < N { self.0[i + 1] } else { 0 };
+                self.0[i] = (self.0[i] << bits) | (next >> (8 - bits));
+            }
+        }
+    }
+}
+
+/// Shifts the bytes towards the last byte, as a big-endian integer.
+impl<const N: usize> ops::Shr<usize> for FixedBytes<N> {
+    type Output = Self;
+
+    fn shr(mut self, rhs: usize) -> Self::Output {
+        self >>= rhs;
+        self
+    }
+}
+
+impl<const N: usize> ops::ShrAssign<usize> for FixedBytes<N> {
+    fn shr_assign(&mut self, rhs: usize) {
+        let (bytes, bits) = (rhs / 8, rhs % 8);
+        if bytes >= N {
+            *self = Self::ZERO;
+            return
+        }
+        self.0.copy_within(..N - bytes, bytes);
+        self.0[..bytes].fill(0);
+        if bits > 0 {
+            for i in (0..N).rev() {
+                let prev = if i > 0 { self.0[i - 1] } else { 0 };
+                self.0[i] = (self.0[i] >> bits) | (prev << (8 - bits));
+            }
+        }
+    }
+}
+
 impl<const N: usize> core::str::FromStr for FixedBytes<N> {
     type Err = hex::FromHexError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut buf = [0u8; N];
-        hex::decode_to_slice(s, &mut buf)?;
-        Ok(Self(buf))
+        hex::decode_to_array(s).map(Self)
     }
 }
 
@@ -321,6 +384,24 @@ impl<const N: usize> FixedBytes<N> {
         Ok(Self(unsafe { crate::impl_core::array_assume_init(bytes) }))
     }
 
+    /// Instantiates a new fixed hash with random content from the given
+    /// random number generator.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn random_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = Self::ZERO;
+        bytes.randomize_with(rng);
+        bytes
+    }
+
+    /// Fills this fixed hash with random content from the given random number
+    /// generator.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn randomize_with<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        rng.fill_bytes(&mut self.0);
+    }
+
     /// Concatenate two `FixedBytes`.
     ///
     /// Due to constraints in the language, the user must specify the value of
@@ -347,6 +428,62 @@ impl<const N: usize> FixedBytes<N> {
         FixedBytes(result)
     }
 
+    /// Returns the `LEN` bytes starting at `START`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `START + LEN` is greater than `N`. This results
+    /// in a compilation error when evaluated in a const context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// const WORD: FixedBytes<4> = fixed_bytes!("01020304");
+    /// const MIDDLE: FixedBytes<2> = WORD.slice_const::<1, 2>();
+    /// assert_eq!(MIDDLE, fixed_bytes!("0203"));
+    /// ```
+    pub const fn slice_const<const START: usize, const LEN: usize>(&self) -> FixedBytes<LEN> {
+        assert!(START + LEN <= N, "slice out of bounds");
+
+        let mut result = [0u8; LEN];
+        let mut i = 0;
+        while i < LEN {
+            result[i] = self.0[START + i];
+            i += 1;
+        }
+        FixedBytes(result)
+    }
+
+    /// Splits the bytes into two at index `M`, the reverse of
+    /// [`concat_const`](Self::concat_const).
+    ///
+    /// Due to constraints in the language, the user must specify the value of
+    /// both output sizes.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `M + Z` is not equal to `N`. This results in a
+    /// compilation error when evaluated in a const context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// const WORD: FixedBytes<4> = fixed_bytes!("01020304");
+    /// const PARTS: (FixedBytes<1>, FixedBytes<3>) = WORD.split_const();
+    /// assert_eq!(PARTS, (fixed_bytes!("01"), fixed_bytes!("020304")));
+    /// ```
+    pub const fn split_const<const M: usize, const Z: usize>(
+        &self,
+    ) -> (FixedBytes<M>, FixedBytes<Z>) {
+        assert!(
+            M + Z == N,
+            "Output sizes `M` and `Z` must add up to the input size `N`"
+        );
+        (self.slice_const::<0, M>(), self.slice_const::<M, Z>())
+    }
+
     /// Returns a new fixed hash where all bits are set to the given byte.
     #[inline]
     pub const fn repeat_byte(byte: u8) -> Self {
@@ -452,6 +589,86 @@ impl<const N: usize> FixedBytes<N> {
         ret
     }
 
+    /// Computes the bitwise NOT of a `FixedBytes`.
+    pub const fn bit_not(self) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+        while i < N {
+            ret.0[i] = !self.0[i];
+            i += 1;
+        }
+        ret
+    }
+
+    /// Returns the number of leading zero bits, interpreting the bytes as a
+    /// big-endian integer.
+    pub const fn leading_zeros(&self) -> usize {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return i * 8 + self.0[i].leading_zeros() as usize
+            }
+            i += 1;
+        }
+        N * 8
+    }
+
+    /// Returns the value of the bit at the given index, interpreting the bytes
+    /// as a big-endian integer: index `0` is the least significant bit of the
+    /// last byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N * 8`.
+    #[inline]
+    #[track_caller]
+    pub const fn bit(&self, index: usize) -> bool {
+        assert!(index < N * 8, "bit index out of bounds");
+        self.0[N - 1 - index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Sets the value of the bit at the given index, interpreting the bytes as
+    /// a big-endian integer: index `0` is the least significant bit of the
+    /// last byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N * 8`.
+    #[inline]
+    #[track_caller]
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        assert!(index < N * 8, "bit index out of bounds");
+        let byte = &mut self.0[N - 1 - index / 8];
+        let mask = 1 << (index % 8);
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+    /// Appends the `0x`-prefixed lowercase hex encoding of `self` to `out`.
+    ///
+    /// Unlike [`to_string`](alloc::string::ToString::to_string), this can
+    /// reuse the allocation of an existing string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// let mut out = String::new();
+    /// for bytes in [fixed_bytes!("0102"), fixed_bytes!("abcd")] {
+    ///     out.clear();
+    ///     bytes.encode_to(&mut out);
+    ///     assert_eq!(out, bytes.to_string());
+    /// }
+    /// # assert_eq!(out, "0xabcd");
+    /// ```
+    #[inline]
+    pub fn encode_to(&self, out: &mut String) {
+        out.push_str(hex::Buffer::<N, true>::new().format(self));
+    }
+
     fn fmt_hex<const UPPER: bool>(&self, f: &mut fmt::Formatter<'_>, prefix: bool) -> fmt::Result {
         let mut buf = hex::Buffer::<N, true>::new();
         let s = if UPPER {
@@ -484,6 +701,20 @@ mod tests {
         const ACTUAL: FixedBytes<4> = A.concat_const(B);
 
         assert_eq!(ACTUAL, EXPECTED);
+
+        const SLICE: FixedBytes<2> = ACTUAL.slice_const::<1, 2>();
+        assert_eq!(SLICE, fixed_bytes!("2345"));
+        const EMPTY: FixedBytes<0> = ACTUAL.slice_const::<4, 0>();
+        assert_eq!(EMPTY, FixedBytes::<0>::ZERO);
+
+        const SPLIT: (FixedBytes<2>, FixedBytes<2>) = ACTUAL.split_const();
+        assert_eq!(SPLIT, (A, B));
+    }
+
+    #[test]
+    #[should_panic = "slice out of bounds"]
+    fn slice_const_out_of_bounds() {
+        let _ = fixed_bytes!("0123").slice_const::<1, 2>();
     }
 
     #[test]
@@ -519,4 +750,104 @@ mod tests {
             "{:#X}", "0123456789abcdef" => "0x0123456789ABCDEF";
         }
     }
+
+    #[test]
+    fn bit_ops() {
+        let a = fixed_bytes!("f0f0");
+        assert_eq!(!a, fixed_bytes!("0f0f"));
+        assert_eq!(a & fixed_bytes!("ff00"), fixed_bytes!("f000"));
+        assert_eq!(a | fixed_bytes!("0f00"), fixed_bytes!("fff0"));
+        assert_eq!(a ^ fixed_bytes!("ffff"), fixed_bytes!("0f0f"));
+
+        let address = crate::Address::ZERO;
+        assert_eq!(!!address, address);
+    }
+
+    #[test]
+    fn shifts() {
+        use crate::U256;
+
+        let value = FixedBytes::<32>::from(U256::from(0x1234_5678_u64).to_be_bytes::<32>());
+        for shift in [0, 1, 4, 8, 13, 64, 200, 255, 256, 300] {
+            let expected = U256::from_be_bytes((value << shift).0);
+            assert_eq!(expected, U256::from_be_bytes(value.0) << shift, "{shift}");
+            let expected = U256::from_be_bytes((value >> shift).0);
+            assert_eq!(expected, U256::from_be_bytes(value.0) >> shift, "{shift}");
+        }
+
+        let mut b = crate::B256::with_last_byte(1);
+        b <<= 255;
+        assert_eq!(b[0], 0x80);
+        b >>= 255;
+        assert_eq!(b, crate::B256::with_last_byte(1));
+    }
+
+    #[test]
+    fn bits() {
+        let mut b = FixedBytes::<4>::ZERO;
+        assert_eq!(b.leading_zeros(), 32);
+        b.set_bit(0, true);
+        assert_eq!(b, fixed_bytes!("00000001"));
+        assert_eq!(b.leading_zeros(), 31);
+        b.set_bit(31, true);
+        assert_eq!(b, fixed_bytes!("80000001"));
+        assert_eq!(b.leading_zeros(), 0);
+        assert!(b.bit(31) && b.bit(0) && !b.bit(1));
+        b.set_bit(31, false);
+        assert_eq!(b.leading_zeros(), 31);
+    }
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_with() {
+        use rand::rngs::mock::StepRng;
+
+        let mut rng = StepRng::new(1, 1);
+        let b = FixedBytes::<16>::random_with(&mut rng);
+        assert_eq!(b, fixed_bytes!("01000000000000000200000000000000"));
+
+        let mut address = crate::Address::random_with(&mut rng);
+        assert_ne!(address, crate::Address::ZERO);
+        address.randomize_with(&mut StepRng::new(0, 0));
+        assert_eq!(address, crate::Address::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary() {
+        use crate::{Address, Bloom, Bytes, Function, I256, U256};
+        use proptest::{
+            strategy::{Strategy, ValueTree},
+            test_runner::TestRunner,
+        };
+
+        fn check<T>()
+        where
+            T: for<'a> arbitrary::Arbitrary<'a> + proptest::arbitrary::Arbitrary,
+        {
+            let data = [0x42; 512];
+            let mut u = arbitrary::Unstructured::new(&data);
+            <T as arbitrary::Arbitrary<'_>>::arbitrary(&mut u).unwrap();
+
+            let mut runner = TestRunner::deterministic();
+            proptest::arbitrary::any::<T>()
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+        }
+
+        check::<FixedBytes<0>>();
+        check::<FixedBytes<32>>();
+        check::<Address>();
+        check::<Bloom>();
+        check::<Function>();
+        check::<Bytes>();
+        check::<U256>();
+        check::<I256>();
+
+        let mut u = arbitrary::Unstructured::new(&[0x42; 20]);
+        assert_eq!(
+            <Address as arbitrary::Arbitrary<'_>>::arbitrary(&mut u).unwrap(),
+            Address::repeat_byte(0x42)
+        );
+    }
 }
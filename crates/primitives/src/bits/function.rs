@@ -0,0 +1,108 @@
+use crate::{Address, FixedBytes, Selector};
+
+wrap_fixed_bytes!(
+    /// An Ethereum ABI function pointer, 24 bytes in length.
+    ///
+    /// An address (20 bytes), followed by a function selector (4 bytes).
+    /// Encoded identically to `bytes24`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{address, Function};
+    ///
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// let function = Function::from_address_and_selector(address, [0xa9, 0x05, 0x9c, 0xbb]);
+    /// assert_eq!(function.as_address_and_selector(), (address, [0xa9, 0x05, 0x9c, 0xbb]));
+    /// ```
+    pub struct Function<24>;
+);
+
+impl From<(Address, Selector)> for Function {
+    #[inline]
+    fn from((address, selector): (Address, Selector)) -> Self {
+        Self::from_address_and_selector(address, selector)
+    }
+}
+
+impl From<Function> for (Address, Selector) {
+    #[inline]
+    fn from(value: Function) -> Self {
+        value.as_address_and_selector()
+    }
+}
+
+impl Function {
+    /// Creates an Ethereum function from an EVM word's lower 24 bytes
+    /// (`word[..24]`).
+    ///
+    /// Note that this is different from [`Address::from_word`], which uses the
+    /// upper 20 bytes.
+    #[inline]
+    #[must_use]
+    pub fn from_word(word: FixedBytes<32>) -> Self {
+        Self(FixedBytes(word[..24].try_into().unwrap()))
+    }
+
+    /// Right-pads the function to 32 bytes (EVM word size).
+    ///
+    /// Note that this is different from [`Address::into_word`], which
+    /// left-pads the address.
+    #[inline]
+    #[must_use]
+    pub fn into_word(&self) -> FixedBytes<32> {
+        let mut word = [0; 32];
+        word[..24].copy_from_slice(self.as_slice());
+        FixedBytes(word)
+    }
+
+    /// Creates an Ethereum function from an address and selector.
+    #[inline]
+    pub const fn from_address_and_selector(address: Address, selector: Selector) -> Self {
+        Self(address.0.concat_const(FixedBytes(selector)))
+    }
+
+    /// Returns the address and selector of the function.
+    #[inline]
+    pub const fn as_address_and_selector(&self) -> (Address, Selector) {
+        let (address, selector) = self.0.split_const::<20, 4>();
+        (Address(address), selector.0)
+    }
+
+    /// Returns the address of the function.
+    #[inline]
+    pub const fn address(&self) -> Address {
+        Address(self.0.slice_const::<0, 20>())
+    }
+
+    /// Returns the selector of the function.
+    #[inline]
+    pub const fn selector(&self) -> Selector {
+        self.0.slice_const::<20, 4>().0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn halves() {
+        const ADDRESS: Address = Address::repeat_byte(0x11);
+        const FUNCTION: Function = Function::from_address_and_selector(ADDRESS, [1, 2, 3, 4]);
+        const SELECTOR: Selector = FUNCTION.selector();
+
+        assert_eq!(FUNCTION.address(), ADDRESS);
+        assert_eq!(SELECTOR, [1, 2, 3, 4]);
+        assert_eq!(<(Address, Selector)>::from(FUNCTION), (ADDRESS, SELECTOR));
+        assert_eq!(Function::from((ADDRESS, SELECTOR)), FUNCTION);
+
+        assert_eq!(
+            FUNCTION.to_string(),
+            "0x111111111111111111111111111111111111111101020304"
+        );
+        assert_eq!(FUNCTION.into_word()[..24], FUNCTION[..]);
+        assert_eq!(Function::from_word(FUNCTION.into_word()), FUNCTION);
+    }
+}
@@ -0,0 +1,392 @@
+//! Compile-time parsing of the hex string literals passed to the
+//! [`address!`], [`fixed_bytes!`] and [`bytes!`] macros, of the integer
+//! literals passed to the [`uint!`] macro, and hashing of the string literals
+//! passed to the [`keccak256!`] macro.
+//!
+//! All the functions in this module panic on invalid input, which results in a
+//! compilation error when they are evaluated in a const context.
+
+/// Returns the number of bytes encoded by a hex string literal, ignoring
+/// whitespace and an optional `0x` prefix.
+pub const fn hex_len(s: &str) -> usize {
+    let s = s.as_bytes();
+    let mut digits = 0;
+    let mut i = prefix_len(s);
+    while i < s.len() {
+        if !s[i].is_ascii_whitespace() {
+            digits += 1;
+        }
+        i += 1;
+    }
+    if digits % 2 != 0 {
+        panic!("hex string literal has an odd number of digits");
+    }
+    digits / 2
+}
+
+/// Decodes a hex string literal into a byte array, ignoring whitespace and an
+/// optional `0x` prefix.
+pub const fn decode_hex<const N: usize>(s: &str) -> [u8; N] {
+    let s = s.as_bytes();
+    let mut out = [0u8; N];
+    let mut nibbles = 0;
+    let mut i = prefix_len(s);
+    while i < s.len() {
+        let c = s[i];
+        i += 1;
+        if c.is_ascii_whitespace() {
+            continue
+        }
+        let nibble = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("invalid character in hex string literal"),
+        };
+        if nibbles / 2 >= N {
+            panic!("hex string literal is too long");
+        }
+        if nibbles % 2 == 0 {
+            out[nibbles / 2] = nibble << 4;
+        } else {
+            out[nibbles / 2] |= nibble;
+        }
+        nibbles += 1;
+    }
+    if nibbles != N * 2 {
+        panic!("hex string literal is too short");
+    }
+    out
+}
+
+/// Decodes an address string literal, verifying its [EIP-55] checksum if it
+/// contains both uppercase and lowercase letters.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+pub const fn parse_address(s: &str) -> [u8; 20] {
+    let bytes = decode_hex::<20>(s);
+
+    // collect the digits, as they may be interspersed with whitespace
+    let s = s.as_bytes();
+    let mut digits = [0u8; 40];
+    let mut lower = [0u8; 40];
+    let (mut has_lower, mut has_upper) = (false, false);
+    let mut n = 0;
+    let mut i = prefix_len(s);
+    while i < s.len() {
+        let c = s[i];
+        i += 1;
+        if c.is_ascii_whitespace() {
+            continue
+        }
+        has_lower |= c.is_ascii_lowercase();
+        has_upper |= c.is_ascii_uppercase();
+        digits[n] = c;
+        lower[n] = c.to_ascii_lowercase();
+        n += 1;
+    }
+
+    // all-lowercase and all-uppercase addresses are not checksummed
+    if has_lower && has_upper {
+        let hash = const_keccak256(&lower);
+        let mut i = 0;
+        while i < 40 {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0xf
+            };
+            if digits[i].is_ascii_alphabetic() && digits[i].is_ascii_uppercase() != (nibble >= 8) {
+                panic!("invalid address checksum");
+            }
+            i += 1;
+        }
+    }
+    bytes
+}
+
+/// Returns the number of bits of a [`uint!`] integer literal, from its
+/// `U<bits>` suffix.
+pub const fn uint_bits(s: &str) -> usize {
+    let s = s.as_bytes();
+    let mut i = s.len();
+    let mut bits = 0;
+    let mut scale = 1;
+    while i > 0 && s[i - 1].is_ascii_digit() && s.len() - i < 5 {
+        bits += (s[i - 1] - b'0') as usize * scale;
+        scale *= 10;
+        i -= 1;
+    }
+    if i == s.len() || i < 2 || s[i - 1] != b'U' {
+        panic!("integer literal must have a `U<bits>` suffix, like `1_U256`");
+    }
+    bits
+}
+
+/// Parses a [`uint!`] integer literal into little-endian limbs, ignoring
+/// underscores and the `U<bits>` suffix.
+///
+/// The literal may be prefixed with `0x`, `0o` or `0b`.
+pub const fn uint_limbs<const LIMBS: usize>(s: &str) -> [u64; LIMBS] {
+    let s = s.as_bytes();
+    let mut end = s.len();
+    while end > 0 && s[end - 1] != b'U' {
+        end -= 1;
+    }
+    // skip the `U`
+    end -= 1;
+
+    let (base, mut i) = match s {
+        [b'0', b'x', ..] => (16, 2),
+        [b'0', b'o', ..] => (8, 2),
+        [b'0', b'b', ..] => (2, 2),
+        _ => (10, 0),
+    };
+    let mut limbs = [0u64; LIMBS];
+    let mut digits = 0;
+    while i < end {
+        let c = s[i];
+        i += 1;
+        if c == b'_' {
+            continue
+        }
+        let digit = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => base,
+        };
+        if digit >= base {
+            panic!("invalid digit in integer literal");
+        }
+
+        // limbs = limbs * base + digit
+        let mut carry = digit as u128;
+        let mut j = 0;
+        while j < LIMBS {
+            let limb = limbs[j] as u128 * base as u128 + carry;
+            limbs[j] = limb as u64;
+            carry = limb >> 64;
+            j += 1;
+        }
+        if carry != 0 {
+            panic!("integer literal is too large for its type");
+        }
+        digits += 1;
+    }
+    if digits == 0 {
+        panic!("integer literal has no digits");
+    }
+    limbs
+}
+
+/// Returns the length of the `0x` prefix of a hex string literal, if any.
+const fn prefix_len(s: &[u8]) -> usize {
+    if s.len() >= 2 && s[0] == b'0' && s[1] == b'x' {
+        2
+    } else {
+        0
+    }
+}
+
+/// The Keccak-f\[1600\] round constants.
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the rho step, in the order of the pi step.
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// The lane permutation of the pi step.
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// The Keccak-f\[1600\] permutation.
+const fn keccak_f(mut a: [u64; 25]) -> [u64; 25] {
+    let mut round = 0;
+    while round < 24 {
+        // theta
+        let mut c = [0u64; 5];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+            x += 1;
+        }
+        let mut x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            let mut y = 0;
+            while y < 25 {
+                a[x + y] ^= d;
+                y += 5;
+            }
+            x += 1;
+        }
+
+        // rho and pi
+        let mut last = a[1];
+        let mut i = 0;
+        while i < 24 {
+            let tmp = a[PI[i]];
+            a[PI[i]] = last.rotate_left(RHO[i]);
+            last = tmp;
+            i += 1;
+        }
+
+        // chi
+        let mut y = 0;
+        while y < 25 {
+            let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+            let mut x = 0;
+            while x < 5 {
+                a[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+
+        // iota
+        a[0] ^= RC[round];
+        round += 1;
+    }
+    a
+}
+
+/// A const implementation of [`keccak256`](crate::keccak256()), used to verify
+/// checksums and to hash string literals at compile time.
+pub const fn const_keccak256(input: &[u8]) -> [u8; 32] {
+    const RATE: usize = 136;
+
+    let mut state = [0u64; 25];
+    let mut offset = 0;
+    loop {
+        let remaining = input.len() - offset;
+        let len = if remaining < RATE { remaining } else { RATE };
+
+        let mut block = [0u8; RATE];
+        let mut i = 0;
+        while i < len {
+            block[i] = input[offset + i];
+            i += 1;
+        }
+        // the last block is padded, even if it is empty
+        if len < RATE {
+            block[len] ^= 0x01;
+            block[RATE - 1] ^= 0x80;
+        }
+
+        let mut lane = 0;
+        while lane < RATE / 8 {
+            let i = lane * 8;
+            state[lane] ^= u64::from_le_bytes([
+                block[i],
+                block[i + 1],
+                block[i + 2],
+                block[i + 3],
+                block[i + 4],
+                block[i + 5],
+                block[i + 6],
+                block[i + 7],
+            ]);
+            lane += 1;
+        }
+        state = keccak_f(state);
+
+        if len < RATE {
+            break
+        }
+        offset += RATE;
+    }
+
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = state[i / 8].to_le_bytes()[i % 8];
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_keccak() {
+        for len in [0, 1, 40, 135, 136, 137, 272, 300] {
+            let input = (0..len).map(|i| i as u8).collect::<alloc::vec::Vec<_>>();
+            assert_eq!(const_keccak256(&input), crate::keccak256(&input).0, "{len}");
+        }
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(hex_len("0x0102 03"), 3);
+        assert_eq!(decode_hex::<3>("0x0102 03"), [1, 2, 3]);
+        assert_eq!(decode_hex::<2>("aBcD"), [0xab, 0xcd]);
+    }
+
+    #[test]
+    fn address_checksum() {
+        let checksummed = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        let expected = decode_hex::<20>(checksummed);
+        assert_eq!(parse_address(checksummed), expected);
+        assert_eq!(parse_address(&checksummed.to_lowercase()), expected);
+        assert_eq!(parse_address(&checksummed[2..].to_uppercase()), expected);
+    }
+
+    #[test]
+    fn uint_literals() {
+        assert_eq!(uint_bits("1_U256"), 256);
+        assert_eq!(uint_bits("0xffU8"), 8);
+        assert_eq!(uint_limbs::<1>("0xff_U8"), [0xff]);
+        assert_eq!(uint_limbs::<1>("0b1010_U4"), [10]);
+        assert_eq!(uint_limbs::<1>("0o17_U64"), [15]);
+        assert_eq!(uint_limbs::<2>("18_446_744_073_709_551_616_U128"), [0, 1]);
+        assert_eq!(uint_limbs::<0>("0_U0"), [0u64; 0]);
+    }
+
+    #[test]
+    #[should_panic = "integer literal is too large for its type"]
+    fn uint_literal_overflow() {
+        uint_limbs::<1>("18446744073709551616_U64");
+    }
+
+    #[test]
+    #[should_panic = "integer literal must have a `U<bits>` suffix"]
+    fn uint_literal_no_suffix() {
+        uint_bits("1234");
+    }
+
+    #[test]
+    #[should_panic = "invalid address checksum"]
+    fn address_bad_checksum() {
+        parse_address("0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    }
+}
@@ -59,6 +59,11 @@ macro_rules! wrap_fixed_bytes {
             $crate::private::derive_more::Into,
             $crate::private::derive_more::IntoIterator,
             $crate::private::derive_more::LowerHex,
+            $crate::private::derive_more::Not,
+            $crate::private::derive_more::Shl,
+            $crate::private::derive_more::ShlAssign,
+            $crate::private::derive_more::Shr,
+            $crate::private::derive_more::ShrAssign,
             $crate::private::derive_more::UpperHex,
             $(
                 $extra_derives,
@@ -168,8 +173,15 @@ macro_rules! wrap_fixed_bytes {
 
         $crate::impl_fixed_bytes_traits!($name, $n);
         $crate::impl_getrandom!($name);
+        $crate::impl_rand!($name);
+        $crate::impl_borsh!($name);
+        $crate::impl_bytemuck!($name, $n);
+        $crate::impl_defmt!($name);
+        $crate::impl_postgres!($name, $n);
         $crate::impl_rlp!($name, $n);
+        $crate::impl_schemars!($name, $n);
         $crate::impl_serde!($name);
+        $crate::impl_sqlx!($name, $n);
         $crate::impl_arbitrary!($name, $n);
 
         impl $name {
@@ -373,6 +385,162 @@ macro_rules! impl_getrandom {
     ($t:ty) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "rand")]
+macro_rules! impl_rand {
+    ($t:ty) => {
+        impl $t {
+            /// Instantiates a new fixed hash with random content from the given
+            /// random number generator.
+            #[inline]
+            pub fn random_with<R: $crate::private::rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                Self($crate::FixedBytes::random_with(rng))
+            }
+
+            /// Fills this fixed hash with random content from the given random
+            /// number generator.
+            #[inline]
+            pub fn randomize_with<R: $crate::private::rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+                self.0.randomize_with(rng);
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "rand"))]
+macro_rules! impl_rand {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "borsh")]
+macro_rules! impl_borsh {
+    ($t:ty) => {
+        impl $crate::private::borsh::BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: $crate::private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::private::borsh::io::Result<()> {
+                $crate::private::borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::private::borsh::BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: $crate::private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::private::borsh::io::Result<Self> {
+                $crate::private::borsh::BorshDeserialize::deserialize_reader(reader).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "borsh"))]
+macro_rules! impl_borsh {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_bytemuck {
+    ($t:ty, $n:literal) => {
+        // SAFETY: `$t` is `repr(transparent)` for `FixedBytes<$n>`, which is
+        // `Pod`
+        unsafe impl $crate::private::bytemuck::Zeroable for $t {}
+        unsafe impl $crate::private::bytemuck::Pod for $t {}
+        unsafe impl $crate::private::bytemuck::TransparentWrapper<$crate::FixedBytes<$n>> for $t {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "bytemuck"))]
+macro_rules! impl_bytemuck {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "defmt")]
+macro_rules! impl_defmt {
+    ($t:ty) => {
+        impl $crate::private::defmt::Format for $t {
+            #[inline]
+            fn format(&self, f: $crate::private::defmt::Formatter<'_>) {
+                $crate::private::defmt::Format::format(&self.0, f)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "defmt"))]
+macro_rules! impl_defmt {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "postgres")]
+macro_rules! impl_postgres {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::postgres_types::ToSql for $t {
+            #[inline]
+            fn to_sql(
+                &self,
+                ty: &$crate::private::postgres_types::Type,
+                out: &mut $crate::private::bytes::BytesMut,
+            ) -> ::core::result::Result<
+                $crate::private::postgres_types::IsNull,
+                $crate::private::BoxedError,
+            > {
+                $crate::private::postgres_types::ToSql::to_sql(&self.0, ty, out)
+            }
+
+            #[inline]
+            fn accepts(ty: &$crate::private::postgres_types::Type) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::postgres_types::ToSql>::accepts(ty)
+            }
+
+            $crate::private::postgres_types::to_sql_checked!();
+        }
+
+        impl<'a> $crate::private::postgres_types::FromSql<'a> for $t {
+            #[inline]
+            fn from_sql(
+                ty: &$crate::private::postgres_types::Type,
+                raw: &'a [u8],
+            ) -> ::core::result::Result<Self, $crate::private::BoxedError> {
+                $crate::private::postgres_types::FromSql::from_sql(ty, raw).map(Self)
+            }
+
+            #[inline]
+            fn accepts(ty: &$crate::private::postgres_types::Type) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::postgres_types::FromSql<'a>>::accepts(
+                    ty,
+                )
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "postgres"))]
+macro_rules! impl_postgres {
+    ($t:ty, $n:literal) => {};
+}
+
 #[doc(hidden)]
 #[macro_export]
 #[cfg(feature = "rlp")]
@@ -410,6 +578,34 @@ macro_rules! impl_rlp {
     ($t:ty, $n:literal) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "schemars")]
+macro_rules! impl_schemars {
+    ($t:ident, $n:literal) => {
+        impl $crate::private::schemars::JsonSchema for $t {
+            #[inline]
+            fn schema_name() -> ::std::string::String {
+                ::std::string::String::from(stringify!($t))
+            }
+
+            #[inline]
+            fn json_schema(
+                gen: &mut $crate::private::schemars::gen::SchemaGenerator,
+            ) -> $crate::private::schemars::schema::Schema {
+                <$crate::FixedBytes<$n> as $crate::private::schemars::JsonSchema>::json_schema(gen)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "schemars"))]
+macro_rules! impl_schemars {
+    ($t:ident, $n:literal) => {};
+}
+
 #[doc(hidden)]
 #[macro_export]
 #[cfg(feature = "serde")]
@@ -440,6 +636,66 @@ macro_rules! impl_serde {
     ($t:ty) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "sqlx")]
+macro_rules! impl_sqlx {
+    ($t:ty, $n:literal) => {
+        impl<DB: $crate::private::sqlx_core::database::Database>
+            $crate::private::sqlx_core::types::Type<DB> for $t
+        where
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::types::Type<DB>,
+        {
+            #[inline]
+            fn type_info() -> DB::TypeInfo {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::types::Type<DB>>::type_info()
+            }
+
+            #[inline]
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::types::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'a, DB: $crate::private::sqlx_core::database::Database>
+            $crate::private::sqlx_core::encode::Encode<'a, DB> for $t
+        where
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::encode::Encode<'a, DB>,
+        {
+            #[inline]
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::private::sqlx_core::database::HasArguments<'a>>::ArgumentBuffer,
+            ) -> $crate::private::sqlx_core::encode::IsNull {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::encode::Encode<'a, DB>>::encode_by_ref(
+                    &self.0, buf,
+                )
+            }
+        }
+
+        impl<'a, DB: $crate::private::sqlx_core::database::Database>
+            $crate::private::sqlx_core::decode::Decode<'a, DB> for $t
+        where
+            $crate::FixedBytes<$n>: $crate::private::sqlx_core::decode::Decode<'a, DB>,
+        {
+            #[inline]
+            fn decode(
+                value: <DB as $crate::private::sqlx_core::database::HasValueRef<'a>>::ValueRef,
+            ) -> ::core::result::Result<Self, $crate::private::BoxedError> {
+                <$crate::FixedBytes<$n> as $crate::private::sqlx_core::decode::Decode<'a, DB>>::decode(value)
+                    .map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "sqlx"))]
+macro_rules! impl_sqlx {
+    ($t:ty, $n:literal) => {};
+}
+
 #[doc(hidden)]
 #[macro_export]
 #[cfg(feature = "arbitrary")]
@@ -500,22 +756,53 @@ macro_rules! fixed_bytes_macros {
             "into a new [`", stringify!($ty), "`][crate::", stringify!($ty), "].\n",
         )]
         ///
-        /// Note that the strings cannot be prefixed with `0x`.
-        ///
-        /// See [`hex_literal::hex!`] for more information.
+        /// The data is decoded at compile time. It may be prefixed with `0x`,
+        /// and whitespace is ignored.
         $(#[$attr])*
         #[macro_export]
         macro_rules! $name {
-            ($d ($d s:literal)*) => {
-                $crate::$ty::new($crate::hex!($d ($d s)*))
-            };
+            ($d ($d s:literal)*) => {{
+                const LEN: usize = $crate::private::hex_len(::core::concat!($d ($d s),*));
+                const BYTES: [u8; LEN] = $crate::private::decode_hex(::core::concat!($d ($d s),*));
+                $crate::$ty::new(BYTES)
+            }};
         }
     )*};
 }
 
-fixed_bytes_macros! { $
-    macro address(Address);
+/// Converts a sequence of string literals containing hex-encoded data into a
+/// new [`Address`][crate::Address].
+///
+/// The data is decoded at compile time. It may be prefixed with `0x`, and
+/// whitespace is ignored. If it contains both uppercase and lowercase letters,
+/// its [EIP-55] checksum is verified.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, Address};
+///
+/// const VITALIK: Address = address!("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+/// assert_eq!(VITALIK, address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"));
+/// ```
+///
+/// Invalid checksums fail to compile:
+///
+/// ```compile_fail
+/// # use alloy_primitives::address;
+/// let _ = address!("0xD8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+/// ```
+#[macro_export]
+macro_rules! address {
+    ($($s:literal)*) => {{
+        const BYTES: [u8; 20] = $crate::private::parse_address(::core::concat!($($s),*));
+        $crate::Address::new(BYTES)
+    }};
+}
 
+fixed_bytes_macros! { $
     macro b64(B64);
 
     macro b128(B128);
@@ -532,13 +819,141 @@ fixed_bytes_macros! { $
 /// Converts a sequence of string literals containing hex-encoded data into a
 /// new [`Bytes`][crate::Bytes].
 ///
-/// Note that the strings cannot be prefixed with `0x`.
-///
-/// See [`hex_literal::hex!`] for more information.
+/// The data is decoded at compile time. It may be prefixed with `0x`, and
+/// whitespace is ignored.
 #[macro_export]
 macro_rules! bytes {
-    ($($s:literal)*) => {
-        $crate::Bytes::from_static(&$crate::hex!($($s)*))
+    ($($s:literal)*) => {{
+        const LEN: usize = $crate::private::hex_len(::core::concat!($($s),*));
+        const BYTES: [u8; LEN] = $crate::private::decode_hex(::core::concat!($($s),*));
+        $crate::Bytes::from_static(&BYTES)
+    }};
+}
+
+/// Computes the [Keccak-256] hash of a sequence of string literals at compile
+/// time, returning a [`B256`][crate::B256].
+///
+/// This allows function selectors, event topics and role hashes to be declared
+/// as `const` items.
+///
+/// [Keccak-256]: https://en.wikipedia.org/wiki/SHA-3
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, keccak256, B256};
+///
+/// const TRANSFER: B256 = keccak256!("Transfer(address,address,uint256)");
+/// assert_eq!(
+///     TRANSFER,
+///     b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+/// );
+/// assert_eq!(TRANSFER, keccak256("Transfer(address,address,uint256)"));
+///
+/// const SELECTOR: [u8; 4] = keccak256!("transfer(address,uint256)").slice_const::<0, 4>().0;
+/// assert_eq!(SELECTOR, [0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
+#[macro_export]
+macro_rules! keccak256 {
+    ($($s:literal)*) => {{
+        const HASH: [u8; 32] = $crate::private::const_keccak256(::core::concat!($($s),*).as_bytes());
+        $crate::FixedBytes::<32>::new(HASH)
+    }};
+}
+
+/// Converts integer literals with a `U<bits>` suffix into new
+/// [`Uint`][crate::Uint]s at compile time.
+///
+/// The literal may be prefixed with `0x`, `0o` or `0b`, and underscores are
+/// ignored. Literals that do not fit in the given number of bits fail to
+/// compile.
+///
+/// The input may also be an arbitrary expression or sequence of tokens, in
+/// which case every literal is converted, including the ones nested in
+/// parentheses, brackets and braces.
+///
+/// Unlike [`ruint::uint!`][crate::ruint::uint], which this macro replaces,
+/// all the literals in the input must have a `U<bits>` suffix; literals such
+/// as `2` or `"abc"` fail to compile. The original macro is still available as
+/// [`ruint::uint!`][crate::ruint::uint], but it expands to `::ruint2` paths,
+/// which requires depending on `ruint2` directly.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{uint, U256, U8};
+///
+/// const MAX: U256 =
+///     uint!(115792089237316195423570985008687907853269984665640564039457584007913129639935_U256);
+/// assert_eq!(MAX, U256::MAX);
+/// assert_eq!(uint!(0xff_U8), U8::MAX);
+/// assert_eq!(uint!(1_000_000_U256), U256::from(1_000_000));
+///
+/// let sum = uint!((1_U256 + 2_U256) * 3_U256);
+/// assert_eq!(sum, U256::from(9));
+/// assert_eq!(uint!([1_U8, 2_U8]), [U8::from(1), U8::from(2)]);
+/// ```
+///
+/// ```compile_fail
+/// # use alloy_primitives::uint;
+/// let _ = uint!(256_U8);
+/// ```
+///
+/// ```compile_fail
+/// # use alloy_primitives::uint;
+/// let _ = uint!(1_U256 << 2);
+/// ```
+#[macro_export]
+macro_rules! uint {
+    (@lit $lit:literal) => {{
+        const LITERAL: &str = ::core::stringify!($lit);
+        const BITS: usize = $crate::private::uint_bits(LITERAL);
+        const LIMBS: usize = $crate::ruint::nlimbs(BITS);
+        const VALUE: $crate::Uint<BITS, LIMBS> =
+            $crate::Uint::from_limbs($crate::private::uint_limbs(LITERAL));
+        VALUE
+    }};
+
+    // Converts the tokens one at a time, moving them to `done`. Groups push
+    // the surrounding state on `stack`, and are rebuilt once their contents
+    // are converted.
+    (@munch [$($done:tt)*] []) => {
+        $($done)*
+    };
+    (@munch [$($done:tt)*] [[paren [$($pdone:tt)*] [$($prest:tt)*]] $($stack:tt)*]) => {
+        $crate::uint!(@munch [$($pdone)* ($($done)*)] [$($stack)*] $($prest)*)
+    };
+    (@munch [$($done:tt)*] [[bracket [$($pdone:tt)*] [$($prest:tt)*]] $($stack:tt)*]) => {
+        $crate::uint!(@munch [$($pdone)* [$($done)*]] [$($stack)*] $($prest)*)
+    };
+    (@munch [$($done:tt)*] [[brace [$($pdone:tt)*] [$($prest:tt)*]] $($stack:tt)*]) => {
+        $crate::uint!(@munch [$($pdone)* {$($done)*}] [$($stack)*] $($prest)*)
+    };
+    (@munch [$($done:tt)*] [$($stack:tt)*] ($($inner:tt)*) $($rest:tt)*) => {
+        $crate::uint!(@munch [] [[paren [$($done)*] [$($rest)*]] $($stack)*] $($inner)*)
+    };
+    (@munch [$($done:tt)*] [$($stack:tt)*] [$($inner:tt)*] $($rest:tt)*) => {
+        $crate::uint!(@munch [] [[bracket [$($done)*] [$($rest)*]] $($stack)*] $($inner)*)
+    };
+    (@munch [$($done:tt)*] [$($stack:tt)*] {$($inner:tt)*} $($rest:tt)*) => {
+        $crate::uint!(@munch [] [[brace [$($done)*] [$($rest)*]] $($stack)*] $($inner)*)
+    };
+    // `-` would otherwise be parsed as part of a negative literal
+    (@munch [$($done:tt)*] [$($stack:tt)*] - $($rest:tt)*) => {
+        $crate::uint!(@munch [$($done)* -] [$($stack)*] $($rest)*)
+    };
+    (@munch [$($done:tt)*] [$($stack:tt)*] $ident:ident $($rest:tt)*) => {
+        $crate::uint!(@munch [$($done)* $ident] [$($stack)*] $($rest)*)
+    };
+    (@munch [$($done:tt)*] [$($stack:tt)*] $lit:literal $($rest:tt)*) => {
+        $crate::uint!(@munch [$($done)* $crate::uint!(@lit $lit)] [$($stack)*] $($rest)*)
+    };
+    (@munch [$($done:tt)*] [$($stack:tt)*] $other:tt $($rest:tt)*) => {
+        $crate::uint!(@munch [$($done)* $other] [$($stack)*] $($rest)*)
+    };
+
+    ($($tokens:tt)*) => {
+        $crate::uint!(@munch [] [] $($tokens)*)
     };
 }
 
@@ -557,4 +972,52 @@ mod tests {
         static B: Bytes = bytes!("112233");
         assert_eq!(B[..], [0x11, 0x22, 0x33]);
     }
+
+    #[test]
+    fn prefixed_literals() {
+        const A: Address = address!("0x0102030405060708090a0b0c0d0e0f1011121314");
+        assert_eq!(A, address!("0102030405060708090a0b0c0d0e0f1011121314"));
+
+        const B: crate::B256 =
+            b256!("0x0000000000000000000000000000000000000000000000000000000000000001");
+        assert_eq!(B, crate::B256::with_last_byte(1));
+
+        const C: crate::FixedBytes<3> = fixed_bytes!("0x11" "22" "33");
+        assert_eq!(C, [0x11, 0x22, 0x33]);
+
+        static D: Bytes = bytes!("0x1122 33");
+        assert_eq!(D[..], [0x11, 0x22, 0x33]);
+
+        let empty = bytes!("");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn keccak256_macro() {
+        const EMPTY: crate::B256 = keccak256!("");
+        assert_eq!(EMPTY, crate::keccak256(""));
+
+        const ROLE: crate::B256 = keccak256!("MINTER" "_ROLE");
+        assert_eq!(ROLE, crate::keccak256("MINTER_ROLE"));
+    }
+
+    #[test]
+    fn uint_macro() {
+        use crate::{U256, U8};
+
+        const A: U256 = uint!(0x10_U256);
+        assert_eq!(A, U256::from(16));
+
+        let b = uint!(-(1_U256 + 2_U256) * 3_U256);
+        assert_eq!(b, U256::from(9).wrapping_neg());
+
+        let c = uint!([1_U8, 2_U8])[1];
+        assert_eq!(c, U8::from(2));
+
+        let d = uint!({
+            let x = 5_U8;
+            x.pow(2_U8.to())
+        });
+        assert_eq!(d, U8::from(25));
+    }
 }
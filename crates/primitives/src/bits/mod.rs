@@ -10,8 +10,32 @@ pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_S
 mod fixed;
 pub use fixed::FixedBytes;
 
+mod function;
+pub use function::Function;
+
+mod literal;
+pub use literal::{const_keccak256, decode_hex, hex_len, parse_address, uint_bits, uint_limbs};
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "schemars")]
+mod schemars;
+
 #[cfg(feature = "serde")]
 mod serde;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
@@ -0,0 +1,57 @@
+use super::FixedBytes;
+use crate::private::BoxedError;
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+/// Fixed bytes are stored as `BYTEA`.
+impl<const N: usize> ToSql for FixedBytes<N> {
+    #[inline]
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+        self.0.as_slice().to_sql(ty, out)
+    }
+
+    #[inline]
+    fn accepts(ty: &Type) -> bool {
+        <&[u8] as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+/// Fixed bytes are loaded from `BYTEA` values of exactly `N` bytes.
+impl<'a, const N: usize> FromSql<'a> for FixedBytes<N> {
+    #[inline]
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+        let bytes = <&[u8] as FromSql<'a>>::from_sql(ty, raw)?;
+        Ok(Self(bytes.try_into()?))
+    }
+
+    #[inline]
+    fn accepts(ty: &Type) -> bool {
+        <&[u8] as FromSql<'a>>::accepts(ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, B256, U256};
+
+    #[test]
+    fn postgres() {
+        let address = Address::repeat_byte(0x42);
+        let mut buf = BytesMut::new();
+        address.to_sql(&Type::BYTEA, &mut buf).unwrap();
+        assert_eq!(buf[..], [0x42; 20]);
+        assert_eq!(Address::from_sql(&Type::BYTEA, &buf).unwrap(), address);
+        assert!(B256::from_sql(&Type::BYTEA, &buf).is_err());
+        assert!(<Address as ToSql>::accepts(&Type::BYTEA));
+        assert!(!<Address as ToSql>::accepts(&Type::TEXT));
+        assert!(address.to_sql_checked(&Type::TEXT, &mut buf).is_err());
+
+        let value = U256::from(1234);
+        let mut buf = BytesMut::new();
+        value.to_sql(&Type::NUMERIC, &mut buf).unwrap();
+        assert_eq!(U256::from_sql(&Type::NUMERIC, &buf).unwrap(), value);
+    }
+}
@@ -0,0 +1,51 @@
+use super::FixedBytes;
+use alloc::{boxed::Box, format, string::String};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+
+/// Fixed bytes are described as `0x`-prefixed hex strings of exactly `2 * N`
+/// digits, as they are serialized.
+impl<const N: usize> JsonSchema for FixedBytes<N> {
+    #[inline]
+    fn schema_name() -> String {
+        format!("FixedBytes{N}")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let len = 2 + 2 * N as u32;
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                max_length: Some(len),
+                min_length: Some(len),
+                pattern: Some(format!("^0x[0-9a-fA-F]{{{}}}$", 2 * N)),
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, B256};
+    use serde_json::json;
+
+    #[test]
+    fn schema() {
+        let schema = schemars::schema_for!(Address);
+        let schema = serde_json::to_value(schema).unwrap();
+        assert_eq!(schema["title"], "Address");
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["pattern"], "^0x[0-9a-fA-F]{40}$");
+        assert_eq!(schema["minLength"], 42);
+        assert_eq!(schema["maxLength"], 42);
+
+        let schema = serde_json::to_value(schemars::schema_for!(B256)).unwrap();
+        assert_eq!(schema["title"], "FixedBytes32");
+        assert_eq!(schema["pattern"], json!("^0x[0-9a-fA-F]{64}$"));
+    }
+}
@@ -1,22 +1,67 @@
 use super::FixedBytes;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
+/// Serializes a `0x`-prefixed hex string in human-readable formats, and the
+/// raw bytes in binary formats.
 impl<const N: usize> Serialize for FixedBytes<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut buf = hex::Buffer::<N, true>::new();
-        serializer.serialize_str(buf.format(&self.0))
+        if serializer.is_human_readable() {
+            let mut buf = hex::Buffer::<N, true>::new();
+            serializer.serialize_str(buf.format(&self.0))
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
     }
 }
 
 impl<'de, const N: usize> Deserialize<'de> for FixedBytes<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        hex::deserialize::<'de, D, [u8; N]>(deserializer).map(Self)
+        struct FixedVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for FixedVisitor<N> {
+            type Value = FixedBytes<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{N} bytes")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                <[u8; N]>::try_from(v)
+                    .map(FixedBytes)
+                    .map_err(|_| de::Error::invalid_length(v.len(), &self))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut bytes = [0u8; N];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(N + 1, &self))
+                }
+                Ok(FixedBytes(bytes))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            hex::deserialize::<'de, D, [u8; N]>(deserializer).map(Self)
+        } else {
+            deserializer.deserialize_bytes(FixedVisitor)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Address, U256};
+    use alloc::vec::Vec;
 
     #[test]
     fn serde() {
@@ -25,4 +70,44 @@ mod tests {
         assert_eq!(ser, "\"0x000000000123456789abcdef\"");
         assert_eq!(serde_json::from_str::<FixedBytes<12>>(&ser).unwrap(), bytes);
     }
+
+    #[test]
+    fn serde_binary() {
+        let bytes = FixedBytes([0, 0, 0, 0, 1, 35, 69, 103, 137, 171, 205, 239]);
+        let mut ser = Vec::new();
+        ciborium::into_writer(&bytes, &mut ser).unwrap();
+        // byte string of length 12, followed by the raw bytes
+        assert_eq!(ser[0], 0x4c);
+        assert_eq!(ser[1..], bytes[..]);
+        assert_eq!(
+            ciborium::from_reader::<FixedBytes<12>, _>(&ser[..]).unwrap(),
+            bytes
+        );
+        assert!(ciborium::from_reader::<FixedBytes<11>, _>(&ser[..]).is_err());
+        assert!(ciborium::from_reader::<FixedBytes<13>, _>(&ser[..]).is_err());
+
+        // sequences of bytes are accepted as well
+        let mut ser = Vec::new();
+        ciborium::into_writer(&bytes.0, &mut ser).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<FixedBytes<12>, _>(&ser[..]).unwrap(),
+            bytes
+        );
+        assert!(ciborium::from_reader::<FixedBytes<11>, _>(&ser[..]).is_err());
+
+        let address = Address::repeat_byte(0x42);
+        let mut ser = Vec::new();
+        ciborium::into_writer(&address, &mut ser).unwrap();
+        assert_eq!(ser.len(), 1 + 20);
+        assert_eq!(
+            ciborium::from_reader::<Address, _>(&ser[..]).unwrap(),
+            address
+        );
+
+        let value = U256::from(0x1234);
+        let mut ser = Vec::new();
+        ciborium::into_writer(&value, &mut ser).unwrap();
+        assert_eq!(ser.len(), 2 + 32);
+        assert_eq!(ciborium::from_reader::<U256, _>(&ser[..]).unwrap(), value);
+    }
 }
@@ -0,0 +1,55 @@
+//! `sqlx` support for fixed bytes.
+//!
+//! Unsigned integers, like `U256`, are not handled here: their `Type`,
+//! `Encode` and `Decode` implementations come entirely from `ruint`'s own
+//! `sqlx` feature, which the `sqlx` feature of this crate enables.
+//!
+//! Round-trip tests against a database live in the
+//! `alloy-primitives-sqlx-tests` crate, so that this crate does not depend on a
+//! database driver or runtime.
+
+use super::FixedBytes;
+use crate::private::BoxedError;
+use sqlx_core::{
+    database::{Database, HasArguments, HasValueRef},
+    decode::Decode,
+    encode::{Encode, IsNull},
+    types::Type,
+};
+
+/// Fixed bytes are stored as the database's binary type, like `Vec<u8>`.
+impl<const N: usize, DB: Database> Type<DB> for FixedBytes<N>
+where
+    Vec<u8>: Type<DB>,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as Type<DB>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'a, const N: usize, DB: Database> Encode<'a, DB> for FixedBytes<N>
+where
+    Vec<u8>: Encode<'a, DB>,
+{
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'a>>::ArgumentBuffer) -> IsNull {
+        self.0.to_vec().encode_by_ref(buf)
+    }
+}
+
+impl<'a, const N: usize, DB: Database> Decode<'a, DB> for FixedBytes<N>
+where
+    Vec<u8>: Decode<'a, DB>,
+{
+    #[inline]
+    fn decode(value: <DB as HasValueRef<'a>>::ValueRef) -> Result<Self, BoxedError> {
+        let bytes = Vec::<u8>::decode(value)?;
+        Ok(Self(bytes.as_slice().try_into()?))
+    }
+}
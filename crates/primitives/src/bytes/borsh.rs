@@ -0,0 +1,33 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+/// Bytes are Borsh-encoded like `Vec<u8>`: a little-endian `u32` length
+/// followed by the bytes.
+impl BorshSerialize for Bytes {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self[..].serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Bytes {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Vec::<u8>::deserialize_reader(reader).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borsh() {
+        let bytes = Bytes::from_static(&[1, 35, 69, 103]);
+        let encoded = borsh::to_vec(&bytes).unwrap();
+        assert_eq!(encoded, [4, 0, 0, 0, 1, 35, 69, 103]);
+        assert_eq!(borsh::from_slice::<Bytes>(&encoded).unwrap(), bytes);
+        assert!(borsh::from_slice::<Bytes>(&encoded[..7]).is_err());
+    }
+}
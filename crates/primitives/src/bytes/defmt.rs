@@ -0,0 +1,9 @@
+use super::Bytes;
+
+/// Formats the bytes as a hex byte slice. The bytes are sent in a single write,
+/// and formatted by the host.
+impl defmt::Format for Bytes {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=[u8]:x}", &self[..]);
+    }
+}
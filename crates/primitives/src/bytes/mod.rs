@@ -5,9 +5,18 @@ use core::{
     ops::{Deref, DerefMut},
 };
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
+#[cfg(feature = "schemars")]
+mod schemars;
+
 #[cfg(feature = "serde")]
 mod serde;
 
@@ -19,20 +28,26 @@ pub struct Bytes(pub bytes::Bytes);
 impl fmt::Debug for Bytes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Bytes(")?;
-        f.write_str(&self.hex_encode())?;
+        self.write_hex::<_, false>(f)?;
         f.write_str(")")
     }
 }
 
 impl fmt::Display for Bytes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.hex_encode())
+        self.write_hex::<_, false>(f)
     }
 }
 
 impl fmt::LowerHex for Bytes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.hex_encode())
+        self.write_hex::<_, false>(f)
+    }
+}
+
+impl fmt::UpperHex for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_hex::<_, true>(f)
     }
 }
 
@@ -114,6 +129,20 @@ impl From<Vec<u8>> for Bytes {
     }
 }
 
+impl From<Bytes> for bytes::Bytes {
+    #[inline]
+    fn from(src: Bytes) -> Self {
+        src.0
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    #[inline]
+    fn from(src: Bytes) -> Self {
+        src.0.into()
+    }
+}
+
 impl<const N: usize> From<[u8; N]> for Bytes {
     #[inline]
     fn from(src: [u8; N]) -> Self {
@@ -228,9 +257,44 @@ impl Bytes {
         Self(bytes::Bytes::copy_from_slice(src))
     }
 
+    /// Appends the `0x`-prefixed lowercase hex encoding of `self` to `out`.
+    ///
+    /// Unlike [`to_string`](alloc::string::ToString::to_string), this can
+    /// reuse the allocation of an existing string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let mut out = String::new();
+    /// Bytes::from_static(b"hello").encode_to(&mut out);
+    /// assert_eq!(out, "0x68656c6c6f");
+    /// ```
     #[inline]
-    fn hex_encode(&self) -> String {
-        hex::encode_prefixed(self.0.as_ref())
+    pub fn encode_to(&self, out: &mut String) {
+        out.reserve(2 + self.len() * 2);
+        // Writing to a `String` is infallible.
+        let _ = self.write_hex::<_, false>(out);
+    }
+
+    /// Writes the `0x`-prefixed hex encoding of `self` in chunks, without
+    /// allocating.
+    fn write_hex<W: fmt::Write, const UPPER: bool>(&self, w: &mut W) -> fmt::Result {
+        const CHUNK: usize = 64;
+        let mut buf = [0u8; CHUNK * 2];
+        w.write_str("0x")?;
+        for chunk in self.chunks(CHUNK) {
+            let buf = &mut buf[..chunk.len() * 2];
+            if UPPER {
+                hex::encode_to_slice_upper(chunk, buf).unwrap();
+            } else {
+                hex::encode_to_slice(chunk, buf).unwrap();
+            }
+            // SAFETY: hex is always valid UTF-8
+            w.write_str(unsafe { core::str::from_utf8_unchecked(buf) })?;
+        }
+        Ok(())
     }
 }
 
@@ -294,6 +358,27 @@ mod tests {
         let expected = "0x0123456789abcdef";
         assert_eq!(format!("{b:x}"), expected);
         assert_eq!(format!("{b}"), expected);
+        assert_eq!(format!("{b:X}"), "0x0123456789ABCDEF");
+
+        // spans several chunks
+        let b = Bytes::from((0..=255).collect::<Vec<u8>>());
+        let expected = hex::encode_prefixed(&b);
+        assert_eq!(format!("{b}"), expected);
+        assert_eq!(format!("{b:?}"), format!("Bytes({expected})"));
+        assert_eq!(format!("{b:X}"), format!("0x{}", hex::encode_upper(&b)));
+
+        let mut out = String::from("stale");
+        out.clear();
+        b.encode_to(&mut out);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn conversions() {
+        let v = vec![1, 2, 3];
+        let b = Bytes::from(v.clone());
+        assert_eq!(Vec::<u8>::from(b.clone()), v);
+        assert_eq!(bytes::Bytes::from(b), bytes::Bytes::from(v));
     }
 
     #[test]
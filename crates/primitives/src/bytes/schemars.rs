@@ -0,0 +1,41 @@
+use super::Bytes;
+use alloc::{boxed::Box, string::String};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+
+/// Bytes are described as `0x`-prefixed hex strings with an even number of
+/// digits, as they are serialized.
+impl JsonSchema for Bytes {
+    #[inline]
+    fn schema_name() -> String {
+        String::from("Bytes")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(String::from("^0x([0-9a-fA-F]{2})*$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(Bytes)).unwrap();
+        assert_eq!(schema["title"], "Bytes");
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["pattern"], "^0x([0-9a-fA-F]{2})*$");
+    }
+}
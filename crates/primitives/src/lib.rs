@@ -19,20 +19,27 @@ extern crate alloc;
 
 // Used in Serde tests.
 #[cfg(test)]
-use serde_json as _;
+use {ciborium as _, serde_json as _};
+
+// Unused when another Keccak-256 implementation takes precedence.
+#[cfg(all(
+    feature = "asm-keccak",
+    any(feature = "native-keccak", feature = "tiny-keccak")
+))]
+use keccak_asm as _;
 
 pub mod aliases;
 #[doc(no_inline)]
 pub use aliases::{
     BlockHash, BlockNumber, ChainId, Selector, StorageKey, StorageValue, TxHash, TxIndex, TxNumber,
-    B128, B160, B256, B512, B64, I128, I16, I160, I256, I32, I64, I8, U128, U16, U160, U256, U32,
-    U512, U64, U8,
+    B128, B160, B256, B512, B64, I128, I16, I160, I256, I32, I512, I64, I8, U128, U16, U160, U256,
+    U32, U512, U64, U8,
 };
 
 mod bits;
 pub use bits::{
-    Address, AddressError, Bloom, BloomInput, FixedBytes, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS,
-    BLOOM_SIZE_BYTES,
+    Address, AddressError, Bloom, BloomInput, FixedBytes, Function, BLOOM_BITS_PER_ITEM,
+    BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
 };
 
 mod bytes;
@@ -41,18 +48,29 @@ pub use self::bytes::Bytes;
 #[cfg(feature = "getrandom")]
 mod impl_core;
 
+mod log;
+pub use log::{LogData, MAX_TOPICS};
+
+pub mod map;
+
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+
 mod signed;
-pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
+pub use signed::{BigIntConversionError, ParseSignedError, Rounding, Sign, Signed};
 
-mod utils;
-pub use utils::keccak256;
+pub mod utils;
+pub use utils::{eip191_hash_message, keccak256};
 
 #[doc(no_inline)]
 pub use ::hex;
 #[doc(no_inline)]
 pub use hex_literal::{self, hex};
+#[cfg(feature = "k256")]
+#[doc(no_inline)]
+pub use k256;
 #[doc(no_inline)]
-pub use ruint::{self, uint, Uint};
+pub use ruint::{self, Uint};
 #[doc(no_inline)]
 pub use tiny_keccak::{self, Hasher, Keccak};
 
@@ -63,17 +81,45 @@ pub use ::hex::serde as serde_hex;
 // Not public API.
 #[doc(hidden)]
 pub mod private {
+    pub use crate::bits::{
+        const_keccak256, decode_hex, hex_len, parse_address, uint_bits, uint_limbs,
+    };
     pub use derive_more;
 
     #[cfg(feature = "getrandom")]
     pub use getrandom;
 
+    #[cfg(feature = "rand")]
+    pub use rand;
+
+    #[cfg(feature = "postgres")]
+    pub use {bytes, postgres_types};
+
+    /// The error type of the `postgres` and `sqlx` conversions.
+    #[cfg(any(feature = "postgres", feature = "sqlx"))]
+    pub type BoxedError = std::boxed::Box<dyn std::error::Error + Sync + Send + 'static>;
+
     #[cfg(feature = "rlp")]
     pub use alloy_rlp;
 
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+
     #[cfg(feature = "serde")]
     pub use serde;
 
+    #[cfg(feature = "sqlx")]
+    pub use sqlx_core;
+
     #[cfg(feature = "arbitrary")]
     pub use {arbitrary, derive_arbitrary, proptest, proptest_derive};
 }
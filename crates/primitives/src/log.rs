@@ -0,0 +1,129 @@
+use crate::{Bytes, B256};
+use alloc::vec::Vec;
+
+/// The maximum number of topics of a log, from `LOG0` to `LOG4`.
+pub const MAX_TOPICS: usize = 4;
+
+/// The topics and data of an Ethereum event log.
+///
+/// A log has at most [`MAX_TOPICS`] topics, which is enforced by the
+/// constructors.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawLogData"))]
+pub struct LogData {
+    /// The indexed topic list.
+    topics: Vec<B256>,
+    /// The plain data.
+    pub data: Bytes,
+}
+
+impl LogData {
+    /// Creates a new log, without length-checking the topics. This allows
+    /// creating invalid logs, which can be checked with
+    /// [`is_valid`](Self::is_valid).
+    #[inline]
+    pub const fn new_unchecked(topics: Vec<B256>, data: Bytes) -> Self {
+        Self { topics, data }
+    }
+
+    /// Creates a new log, returning `None` if there are more than
+    /// [`MAX_TOPICS`] topics.
+    #[inline]
+    pub fn new(topics: Vec<B256>, data: Bytes) -> Option<Self> {
+        let this = Self::new_unchecked(topics, data);
+        this.is_valid().then_some(this)
+    }
+
+    /// Returns `true` if the log has at most [`MAX_TOPICS`] topics.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.topics.len() <= MAX_TOPICS
+    }
+
+    /// Returns the topics of the log.
+    #[inline]
+    pub fn topics(&self) -> &[B256] {
+        &self.topics
+    }
+
+    /// Returns a mutable reference to the topics of the log.
+    #[inline]
+    pub fn topics_mut(&mut self) -> &mut [B256] {
+        &mut self.topics
+    }
+
+    /// Sets the topics of the log, returning `false` and leaving the log
+    /// unchanged if there are more than [`MAX_TOPICS`] topics.
+    #[inline]
+    pub fn set_topics(&mut self, topics: Vec<B256>) -> bool {
+        let valid = topics.len() <= MAX_TOPICS;
+        if valid {
+            self.topics = topics;
+        }
+        valid
+    }
+
+    /// Consumes the log, returning its topics and data.
+    #[inline]
+    pub fn split(self) -> (Vec<B256>, Bytes) {
+        (self.topics, self.data)
+    }
+}
+
+/// The unchecked representation of [`LogData`], used to validate the number of
+/// topics when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawLogData {
+    topics: Vec<B256>,
+    data: Bytes,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawLogData> for LogData {
+    type Error = &'static str;
+
+    fn try_from(raw: RawLogData) -> Result<Self, Self::Error> {
+        Self::new(raw.topics, raw.data).ok_or("too many topics")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topics_len() {
+        let topics = vec![B256::ZERO; MAX_TOPICS];
+        let mut log = LogData::new(topics.clone(), Bytes::new()).unwrap();
+        assert_eq!(log.topics(), &topics[..]);
+
+        assert!(LogData::new(vec![B256::ZERO; MAX_TOPICS + 1], Bytes::new()).is_none());
+        assert!(!LogData::new_unchecked(vec![B256::ZERO; MAX_TOPICS + 1], Bytes::new()).is_valid());
+
+        assert!(!log.set_topics(vec![B256::ZERO; MAX_TOPICS + 1]));
+        assert_eq!(log.topics().len(), MAX_TOPICS);
+        assert!(log.set_topics(vec![]));
+        assert!(log.topics().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let log = LogData::new(vec![B256::with_last_byte(1)], Bytes::from_static(&[1, 2])).unwrap();
+        let json = serde_json::to_string(&log).unwrap();
+        assert_eq!(
+            json,
+            r#"{"topics":["0x0000000000000000000000000000000000000000000000000000000000000001"],"data":"0x0102"}"#
+        );
+        assert_eq!(serde_json::from_str::<LogData>(&json).unwrap(), log);
+
+        let too_many = format!(
+            r#"{{"topics":[{}],"data":"0x"}}"#,
+            [r#""0x0000000000000000000000000000000000000000000000000000000000000000""#; 5]
+                .join(",")
+        );
+        assert!(serde_json::from_str::<LogData>(&too_many).is_err());
+    }
+}
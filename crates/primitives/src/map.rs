@@ -0,0 +1,181 @@
+//! Hash maps and sets keyed by fixed-size byte arrays.
+//!
+//! Hashes and addresses are already uniformly distributed, so hashing them
+//! again with a general-purpose hasher such as SipHash only costs time.
+//! [`FbHasher`] instead folds the key bytes into the hash with a few
+//! multiplications.
+//!
+//! Because the hash is derived directly from the key, these maps are not
+//! resistant to HashDoS: only use them with keys that are outputs of a
+//! cryptographic hash function, or otherwise not chosen by an adversary.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use alloy_primitives::{b256, map::B256HashMap};
+//!
+//! let mut balances = B256HashMap::default();
+//! balances.insert(
+//!     b256!("0000000000000000000000000000000000000000000000000000000000000001"),
+//!     42,
+//! );
+//! assert_eq!(balances.len(), 1);
+//! # }
+//! ```
+
+#[cfg(feature = "std")]
+use crate::{Address, FixedBytes};
+use core::hash::{BuildHasherDefault, Hasher};
+
+/// The multiplier of the hash function, from `FxHasher`.
+const K: u64 = 0xf135_7aea_2e62_a9c5;
+
+/// A [`BuildHasher`](core::hash::BuildHasher) for [`FbHasher`].
+pub type FbBuildHasher = BuildHasherDefault<FbHasher>;
+
+/// A hasher for keys that hash as a single
+/// [fixed-size byte array](crate::FixedBytes), such as
+/// [addresses](crate::Address) and [hashes](crate::B256).
+///
+/// The key bytes are mixed into the hash 8 bytes at a time, like `FxHasher`,
+/// and the length prefix written by the `Hash` implementation of arrays is
+/// ignored. [`finish`](Hasher::finish) then spreads the entropy of every byte
+/// over the whole hash, so that keys which only differ in a few bytes, such
+/// as storage slots, still use all the buckets of the map.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FbHasher {
+    hash: u64,
+}
+
+impl FbHasher {
+    #[inline]
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(K);
+    }
+}
+
+impl Hasher for FbHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        // folded multiply: the high half of the product depends on all the
+        // bits of the hash, and is mixed back into the low half
+        let full = u128::from(self.hash) * u128::from(K);
+        ((full as u64) ^ ((full >> 64) as u64)).rotate_left(26)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_ne_bytes(chunk.try_into().unwrap()));
+        }
+        let rem = chunks.remainder();
+        if !rem.is_empty() {
+            let mut buf = [0u8; 8];
+            buf[..rem.len()].copy_from_slice(rem);
+            self.add(u64::from_ne_bytes(buf));
+        }
+    }
+
+    #[inline]
+    fn write_usize(&mut self, _len: usize) {
+        // Length prefix of the array, which is the same for all keys.
+    }
+}
+
+/// A [`HashMap`](std::collections::HashMap) keyed by [`FixedBytes<N>`].
+#[cfg(feature = "std")]
+pub type FbHashMap<const N: usize, V> = std::collections::HashMap<FixedBytes<N>, V, FbBuildHasher>;
+
+/// A [`HashSet`](std::collections::HashSet) of [`FixedBytes<N>`].
+#[cfg(feature = "std")]
+pub type FbHashSet<const N: usize> = std::collections::HashSet<FixedBytes<N>, FbBuildHasher>;
+
+/// A [`HashMap`](std::collections::HashMap) keyed by [`B256`](crate::B256).
+#[cfg(feature = "std")]
+pub type B256HashMap<V> = FbHashMap<32, V>;
+
+/// A [`HashSet`](std::collections::HashSet) of [`B256`](crate::B256).
+#[cfg(feature = "std")]
+pub type B256HashSet = FbHashSet<32>;
+
+/// A [`HashMap`](std::collections::HashMap) keyed by [`Address`].
+#[cfg(feature = "std")]
+pub type AddressHashMap<V> = std::collections::HashMap<Address, V, FbBuildHasher>;
+
+/// A [`HashSet`](std::collections::HashSet) of [`Address`].
+#[cfg(feature = "std")]
+pub type AddressHashSet = std::collections::HashSet<Address, FbBuildHasher>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, B256, U256};
+    use core::hash::{BuildHasher, Hash};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = FbBuildHasher::default().build_hasher();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn mix() {
+        // every byte of the key changes the hash
+        let zero = hash(&B256::ZERO);
+        for i in 0..32 {
+            let mut b = B256::ZERO;
+            b[i] = 1;
+            assert_ne!(hash(&b), zero, "{i}");
+        }
+
+        // the position of the words matters, unlike with XOR folding
+        let mut a = B256::ZERO;
+        a[0] = 1;
+        let mut b = B256::ZERO;
+        b[8] = 1;
+        assert_ne!(hash(&a), hash(&b));
+
+        assert_eq!(hash(&Address::ZERO), hash(&Address::ZERO));
+        assert_ne!(hash(&Address::ZERO), hash(&Address::with_last_byte(1)));
+    }
+
+    #[test]
+    fn sequential_slots() {
+        // storage slots only differ in their last bytes, which must still
+        // reach both the low bits, used as the bucket index, and the high
+        // bits, used as tags by `hashbrown`
+        let hashes = (0..1024u64).map(|i| hash(&B256::from(U256::from(i))));
+        let mut low = [false; 1024];
+        let mut high = [false; 128];
+        for h in hashes {
+            low[(h & 1023) as usize] = true;
+            high[(h >> 57) as usize] = true;
+        }
+        assert!(low.iter().filter(|&&b| b).count() > 512);
+        assert!(high.iter().filter(|&&b| b).count() > 100);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn maps() {
+        let mut map = AddressHashMap::default();
+        let mut set = B256HashSet::default();
+        for i in 0..=255u8 {
+            map.insert(Address::with_last_byte(i), i);
+            set.insert(B256::with_last_byte(i));
+        }
+        assert_eq!(map.len(), 256);
+        assert_eq!(set.len(), 256);
+        assert_eq!(map[&Address::with_last_byte(42)], 42);
+        assert!(set.contains(&B256::with_last_byte(42)));
+
+        let mut slots = B256HashMap::default();
+        for i in 0..10_000u64 {
+            slots.insert(B256::from(U256::from(i)), i);
+        }
+        assert_eq!(slots.len(), 10_000);
+        assert!((0..10_000u64).all(|i| slots[&B256::from(U256::from(i))] == i));
+    }
+}
@@ -0,0 +1,731 @@
+//! Serde helpers for integers, for use with `#[serde(with = "...")]`.
+//!
+//! The helpers are generic over [`Integer`], which is implemented for the
+//! primitive integers, [`Uint`] and [`Signed`]. Negative integers are
+//! serialized with a leading `-`, such as `"-0x2a"` or `"-42"`.
+//!
+//! Each module also has an `opt` submodule for `Option`al fields, which
+//! are serialized as `null` when `None`.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{serde_helpers, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Block {
+//!     #[serde(with = "serde_helpers::quantity")]
+//!     number: u64,
+//!     #[serde(with = "serde_helpers::decimal")]
+//!     difficulty: U256,
+//!     #[serde(with = "serde_helpers::flexible")]
+//!     gas_limit: u64,
+//!     #[serde(with = "serde_helpers::quantity::opt")]
+//!     base_fee: Option<u64>,
+//! }
+//!
+//! let block: Block =
+//!     serde_json::from_str(r#"{"number":"0x2a","difficulty":"1000","gas_limit":30000000,"base_fee":null}"#)
+//!         .unwrap();
+//! assert_eq!(block.number, 42);
+//! assert_eq!(block.base_fee, None);
+//! assert_eq!(
+//!     serde_json::to_string(&block).unwrap(),
+//!     r#"{"number":"0x2a","difficulty":"1000","gas_limit":"0x1c9c380","base_fee":null}"#
+//! );
+//! ```
+
+use crate::{Sign, Signed, Uint};
+use alloc::{format, string::String};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserializer, Serialize, Serializer,
+};
+
+/// An integer type supported by the serde helpers in this module.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Integer: Sized + fmt::Display + private::Sealed {
+    #[doc(hidden)]
+    fn from_u128(value: u128) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn from_i128(value: i128) -> Option<Self>;
+
+    /// Parses a non-empty string of ASCII digits in the given radix, negated
+    /// if `negative` is `true`.
+    #[doc(hidden)]
+    fn from_digits(negative: bool, digits: &str, radix: u32) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn is_negative(&self) -> bool;
+
+    /// Formats the absolute value of `self` as lowercase hex, without prefix
+    /// or leading zeros.
+    #[doc(hidden)]
+    fn to_hex(&self) -> String;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),+) => {$(
+        impl private::Sealed for $t {}
+
+        impl Integer for $t {
+            #[inline]
+            fn from_u128(value: u128) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            #[inline]
+            fn from_i128(value: i128) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            #[inline]
+            fn from_digits(negative: bool, digits: &str, radix: u32) -> Option<Self> {
+                if negative {
+                    return None
+                }
+                <$t>::from_str_radix(digits, radix).ok()
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                false
+            }
+
+            #[inline]
+            fn to_hex(&self) -> String {
+                format!("{self:x}")
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),+) => {$(
+        impl private::Sealed for $t {}
+
+        impl Integer for $t {
+            #[inline]
+            fn from_u128(value: u128) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            #[inline]
+            fn from_i128(value: i128) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            #[inline]
+            fn from_digits(negative: bool, digits: &str, radix: u32) -> Option<Self> {
+                let abs = u128::from_str_radix(digits, radix).ok()?;
+                if !negative {
+                    return Self::from_u128(abs)
+                }
+                // `i128::MIN` is the only value whose magnitude doesn't fit in
+                // an `i128`, and `wrapping_neg` maps it to itself
+                if abs > i128::MIN.unsigned_abs() {
+                    return None
+                }
+                Self::from_i128((abs as i128).wrapping_neg())
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                <$t>::is_negative(*self)
+            }
+
+            #[inline]
+            fn to_hex(&self) -> String {
+                format!("{:x}", self.unsigned_abs())
+            }
+        }
+    )+};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+impl<const BITS: usize, const LIMBS: usize> private::Sealed for Uint<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> Integer for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        value.try_into().ok()
+    }
+
+    #[inline]
+    fn from_i128(value: i128) -> Option<Self> {
+        value.try_into().ok()
+    }
+
+    #[inline]
+    fn from_digits(negative: bool, digits: &str, radix: u32) -> Option<Self> {
+        if negative {
+            return None
+        }
+        Self::from_str_radix(digits, radix as u64).ok()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn to_hex(&self) -> String {
+        uint_to_hex(self)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> private::Sealed for Signed<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> Integer for Signed<BITS, LIMBS> {
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        Self::checked_from_sign_and_abs(Sign::Positive, value.try_into().ok()?)
+    }
+
+    #[inline]
+    fn from_i128(value: i128) -> Option<Self> {
+        value.try_into().ok()
+    }
+
+    #[inline]
+    fn from_digits(negative: bool, digits: &str, radix: u32) -> Option<Self> {
+        let abs = Uint::from_str_radix(digits, radix as u64).ok()?;
+        let sign = if negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        Self::checked_from_sign_and_abs(sign, abs)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Self::is_negative(*self)
+    }
+
+    #[inline]
+    fn to_hex(&self) -> String {
+        uint_to_hex(&self.unsigned_abs())
+    }
+}
+
+fn uint_to_hex<const BITS: usize, const LIMBS: usize>(value: &Uint<BITS, LIMBS>) -> String {
+    // `LowerHex` pads to the full width of the type
+    let hex = format!("{value:x}");
+    match hex.trim_start_matches('0') {
+        "" => String::from("0"),
+        trimmed => String::from(trimmed),
+    }
+}
+
+/// JSON-RPC "quantity" encoding: a `0x`-prefixed hex string without leading
+/// zeros, such as `"0x0"`, `"0x2a"` or `"-0x2a"`.
+///
+/// Deserialization is strict and rejects numbers, unprefixed strings and
+/// leading zeros.
+pub mod quantity {
+    use super::*;
+
+    /// Serializes an integer as a quantity.
+    pub fn serialize<T: Integer, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_with(Format::Quantity, value, serializer)
+    }
+
+    /// Deserializes an integer from a quantity.
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserialize_with(Format::Quantity, deserializer)
+    }
+
+    /// Quantity encoding for `Option`al integers.
+    pub mod opt {
+        use super::super::*;
+
+        /// Serializes an optional integer as a quantity or `null`.
+        pub fn serialize<T: Integer, S: Serializer>(
+            value: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_option(Format::Quantity, value, serializer)
+        }
+
+        /// Deserializes an optional integer from a quantity or `null`.
+        pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            deserialize_option(Format::Quantity, deserializer)
+        }
+    }
+}
+
+/// Decimal string encoding, such as `"42"` or `"-42"`.
+///
+/// Deserialization rejects numbers and any non-digit characters other than a
+/// leading `-`.
+pub mod decimal {
+    use super::*;
+
+    /// Serializes an integer as a decimal string.
+    pub fn serialize<T: Integer, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_with(Format::Decimal, value, serializer)
+    }
+
+    /// Deserializes an integer from a decimal string.
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserialize_with(Format::Decimal, deserializer)
+    }
+
+    /// Decimal string encoding for `Option`al integers.
+    pub mod opt {
+        use super::super::*;
+
+        /// Serializes an optional integer as a decimal string or `null`.
+        pub fn serialize<T: Integer, S: Serializer>(
+            value: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_option(Format::Decimal, value, serializer)
+        }
+
+        /// Deserializes an optional integer from a decimal string or `null`.
+        pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            deserialize_option(Format::Decimal, deserializer)
+        }
+    }
+}
+
+/// Lenient encoding that serializes as a
+/// [quantity], and deserializes from numbers,
+/// `0x`-prefixed hex strings, or decimal strings.
+pub mod flexible {
+    use super::*;
+
+    /// Serializes an integer as a quantity.
+    pub fn serialize<T: Integer, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_with(Format::Flexible, value, serializer)
+    }
+
+    /// Deserializes an integer from a number, a hex string or a decimal
+    /// string.
+    pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserialize_with(Format::Flexible, deserializer)
+    }
+
+    /// Lenient encoding for `Option`al integers.
+    pub mod opt {
+        use super::super::*;
+
+        /// Serializes an optional integer as a quantity or `null`.
+        pub fn serialize<T: Integer, S: Serializer>(
+            value: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_option(Format::Flexible, value, serializer)
+        }
+
+        /// Deserializes an optional integer from a number, a hex string, a
+        /// decimal string or `null`.
+        pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            deserialize_option(Format::Flexible, deserializer)
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Quantity,
+    Decimal,
+    Flexible,
+}
+
+fn serialize_with<T: Integer, S: Serializer>(
+    format: Format,
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let sign = if value.is_negative() { "-" } else { "" };
+    match format {
+        Format::Decimal => serializer.collect_str(value),
+        Format::Quantity | Format::Flexible => {
+            serializer.collect_str(&format_args!("{sign}0x{}", value.to_hex()))
+        }
+    }
+}
+
+fn deserialize_with<'de, T: Integer, D: Deserializer<'de>>(
+    format: Format,
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let visitor = IntegerVisitor::new(format);
+    match format {
+        Format::Quantity | Format::Decimal => deserializer.deserialize_str(visitor),
+        Format::Flexible => deserializer.deserialize_any(visitor),
+    }
+}
+
+fn serialize_option<T: Integer, S: Serializer>(
+    format: Format,
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_some(&Formatted(format, value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_option<'de, T: Integer, D: Deserializer<'de>>(
+    format: Format,
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    deserializer.deserialize_option(OptionVisitor(IntegerVisitor::new(format)))
+}
+
+/// Serializes an integer in the given format.
+struct Formatted<'a, T>(Format, &'a T);
+
+impl<T: Integer> Serialize for Formatted<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_with(self.0, self.1, serializer)
+    }
+}
+
+struct IntegerVisitor<T> {
+    format: Format,
+    _marker: PhantomData<T>,
+}
+
+impl<T> IntegerVisitor<T> {
+    const fn new(format: Format) -> Self {
+        Self {
+            format,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Integer> Visitor<'_> for IntegerVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.format {
+            Format::Quantity => "a 0x-prefixed hex string without leading zeros",
+            Format::Decimal => "a decimal string",
+            Format::Flexible => "an integer, or a hex or decimal string",
+        })
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        match self.format {
+            Format::Flexible => T::from_u128(v.into())
+                .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self)),
+            _ => Err(E::invalid_type(Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        match self.format {
+            Format::Flexible => {
+                T::from_u128(v).ok_or_else(|| E::invalid_value(Unexpected::Other("u128"), &self))
+            }
+            _ => Err(E::invalid_type(Unexpected::Other("u128"), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        match self.format {
+            Format::Flexible => {
+                T::from_i128(v.into()).ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+            _ => Err(E::invalid_type(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        match self.format {
+            Format::Flexible => {
+                T::from_i128(v).ok_or_else(|| E::invalid_value(Unexpected::Other("i128"), &self))
+            }
+            _ => Err(E::invalid_type(Unexpected::Other("i128"), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        let (negative, abs) = match v.strip_prefix('-') {
+            Some(abs) => (true, abs),
+            None => (false, v),
+        };
+        let parsed = match (self.format, abs.strip_prefix("0x")) {
+            // `-0x0` is not canonical
+            (Format::Quantity, Some("0")) if !negative => parse_digits(false, "0", 16),
+            (Format::Quantity, Some(hex)) if !hex.starts_with('0') => {
+                parse_digits(negative, hex, 16)
+            }
+            (Format::Flexible, Some(hex)) => parse_digits(negative, hex, 16),
+            (Format::Decimal | Format::Flexible, None) => parse_digits(negative, abs, 10),
+            _ => None,
+        };
+        parsed.ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+/// Deserializes `null` as `None`, and anything else with the inner visitor.
+struct OptionVisitor<T>(IntegerVisitor<T>);
+
+impl<'de, T: Integer> Visitor<'de> for OptionVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expecting(f)?;
+        f.write_str(", or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Option<T>, D::Error> {
+        deserialize_with(self.0.format, deserializer).map(Some)
+    }
+}
+
+fn parse_digits<T: Integer>(negative: bool, digits: &str, radix: u32) -> Option<T> {
+    // `from_str_radix` also accepts signs and, for `Uint`, underscores
+    let valid = !digits.is_empty() && digits.bytes().all(|b| (b as char).is_digit(radix));
+    valid
+        .then(|| T::from_digits(negative, digits, radix))
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I256, U256};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Quantity<T: Integer>(#[serde(with = "quantity")] T);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Decimal<T: Integer>(#[serde(with = "decimal")] T);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Flexible<T: Integer>(#[serde(with = "flexible")] T);
+
+    #[test]
+    fn quantity() {
+        for (value, s) in [
+            (0u64, "\"0x0\""),
+            (1, "\"0x1\""),
+            (0x2a, "\"0x2a\""),
+            (u64::MAX, "\"0xffffffffffffffff\""),
+        ] {
+            assert_eq!(serde_json::to_string(&Quantity(value)).unwrap(), s);
+            assert_eq!(
+                serde_json::from_str::<Quantity<u64>>(s).unwrap(),
+                Quantity(value)
+            );
+            let value = U256::from(value);
+            assert_eq!(serde_json::to_string(&Quantity(value)).unwrap(), s);
+            assert_eq!(
+                serde_json::from_str::<Quantity<U256>>(s).unwrap(),
+                Quantity(value)
+            );
+        }
+
+        for s in [
+            "42",
+            "\"42\"",
+            "\"0x\"",
+            "\"0x01\"",
+            "\"0x00\"",
+            "\"0x+1\"",
+            "\"0x1_0\"",
+            "\"0x100\"",
+        ] {
+            assert!(serde_json::from_str::<Quantity<u8>>(s).is_err(), "{s}");
+        }
+        assert!(serde_json::from_str::<Quantity<U256>>("\"0x1_0\"").is_err());
+    }
+
+    #[test]
+    fn decimal() {
+        for (value, s) in [
+            (0u128, "\"0\""),
+            (42, "\"42\""),
+            (u128::MAX, "\"340282366920938463463374607431768211455\""),
+        ] {
+            assert_eq!(serde_json::to_string(&Decimal(value)).unwrap(), s);
+            assert_eq!(
+                serde_json::from_str::<Decimal<u128>>(s).unwrap(),
+                Decimal(value)
+            );
+            let value = U256::from(value);
+            assert_eq!(serde_json::to_string(&Decimal(value)).unwrap(), s);
+            assert_eq!(
+                serde_json::from_str::<Decimal<U256>>(s).unwrap(),
+                Decimal(value)
+            );
+        }
+
+        for s in ["42", "\"\"", "\"+42\"", "\"-1\"", "\"0x2a\"", "\"256\""] {
+            assert!(serde_json::from_str::<Decimal<u8>>(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn flexible() {
+        for s in ["42", "\"42\"", "\"0x2a\"", "\"0x002a\""] {
+            assert_eq!(
+                serde_json::from_str::<Flexible<u8>>(s).unwrap(),
+                Flexible(42),
+                "{s}"
+            );
+            assert_eq!(
+                serde_json::from_str::<Flexible<U256>>(s).unwrap(),
+                Flexible(U256::from(42)),
+                "{s}"
+            );
+        }
+        assert_eq!(serde_json::to_string(&Flexible(42u8)).unwrap(), "\"0x2a\"");
+
+        for s in ["-1", "256", "1.0", "\"\"", "\"0x\"", "\"-1\"", "null"] {
+            assert!(serde_json::from_str::<Flexible<u8>>(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn signed() {
+        for (value, quantity, decimal) in [
+            (0i64, "\"0x0\"", "\"0\""),
+            (42, "\"0x2a\"", "\"42\""),
+            (-42, "\"-0x2a\"", "\"-42\""),
+            (
+                i64::MIN,
+                "\"-0x8000000000000000\"",
+                "\"-9223372036854775808\"",
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&Quantity(value)).unwrap(), quantity);
+            assert_eq!(
+                serde_json::from_str::<Quantity<i64>>(quantity).unwrap(),
+                Quantity(value)
+            );
+            assert_eq!(serde_json::to_string(&Decimal(value)).unwrap(), decimal);
+            assert_eq!(
+                serde_json::from_str::<Decimal<i64>>(decimal).unwrap(),
+                Decimal(value)
+            );
+
+            let value = I256::try_from(value).unwrap();
+            assert_eq!(serde_json::to_string(&Quantity(value)).unwrap(), quantity);
+            assert_eq!(
+                serde_json::from_str::<Quantity<I256>>(quantity).unwrap(),
+                Quantity(value)
+            );
+            assert_eq!(serde_json::to_string(&Decimal(value)).unwrap(), decimal);
+            assert_eq!(
+                serde_json::from_str::<Decimal<I256>>(decimal).unwrap(),
+                Decimal(value)
+            );
+        }
+
+        for s in ["-42", "\"-42\"", "\"-0x2a\""] {
+            assert_eq!(
+                serde_json::from_str::<Flexible<i8>>(s).unwrap(),
+                Flexible(-42),
+                "{s}"
+            );
+        }
+        assert_eq!(
+            serde_json::from_str::<Flexible<i8>>("\"-0x80\"").unwrap(),
+            Flexible(i8::MIN)
+        );
+
+        for s in ["\"-0x0\"", "\"--0x1\"", "\"0x-1\"", "\"-0x81\"", "\"0x80\""] {
+            assert!(serde_json::from_str::<Quantity<i8>>(s).is_err(), "{s}");
+        }
+        for s in ["128", "-129", "\"-129\"", "\"- 1\""] {
+            assert!(serde_json::from_str::<Flexible<i8>>(s).is_err(), "{s}");
+        }
+        assert!(serde_json::from_str::<Quantity<u8>>("\"-0x1\"").is_err());
+    }
+
+    #[test]
+    fn option() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Optional {
+            #[serde(with = "quantity::opt")]
+            quantity: Option<u64>,
+            #[serde(with = "decimal::opt")]
+            decimal: Option<I256>,
+            #[serde(with = "flexible::opt", default)]
+            flexible: Option<U256>,
+        }
+
+        let value = Optional {
+            quantity: Some(42),
+            decimal: Some(I256::try_from(-1).unwrap()),
+            flexible: None,
+        };
+        let s = r#"{"quantity":"0x2a","decimal":"-1","flexible":null}"#;
+        assert_eq!(serde_json::to_string(&value).unwrap(), s);
+        assert_eq!(serde_json::from_str::<Optional>(s).unwrap(), value);
+
+        let value =
+            serde_json::from_str::<Optional>(r#"{"quantity":null,"decimal":null,"flexible":42}"#)
+                .unwrap();
+        assert_eq!(
+            value,
+            Optional {
+                quantity: None,
+                decimal: None,
+                flexible: Some(U256::from(42)),
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<Optional>(r#"{"quantity":null,"decimal":null}"#)
+                .unwrap()
+                .flexible,
+            None
+        );
+
+        assert!(serde_json::from_str::<Optional>(
+            r#"{"quantity":42,"decimal":null,"flexible":null}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use super::Signed;
+use alloc::vec;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+use ruint::Uint;
+
+/// Signed integers are Borsh-encoded like the primitive integers: the
+/// little-endian two's complement bytes, using the minimal number of bytes
+/// for `BITS`.
+impl<const BITS: usize, const LIMBS: usize> BorshSerialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0.as_le_bytes())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshDeserialize for Signed<BITS, LIMBS> {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = vec![0u8; Uint::<BITS, LIMBS>::BYTES];
+        reader.read_exact(&mut bytes)?;
+        Uint::try_from_le_slice(&bytes)
+            .map(Self)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "value out of range"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{aliases::I8, I256};
+
+    #[test]
+    fn borsh() {
+        let value = I256::try_from(-2).unwrap();
+        let encoded = borsh::to_vec(&value).unwrap();
+        let mut expected = [0xff; 32];
+        expected[0] = 0xfe;
+        assert_eq!(encoded, expected);
+        assert_eq!(borsh::from_slice::<I256>(&encoded).unwrap(), value);
+        assert!(borsh::from_slice::<I256>(&encoded[1..]).is_err());
+
+        for v in i8::MIN..=i8::MAX {
+            let value = I8::try_from(v).unwrap();
+            assert_eq!(borsh::to_vec(&value).unwrap(), borsh::to_vec(&v).unwrap());
+            assert_eq!(borsh::from_slice::<I8>(&[v as u8]).unwrap(), value);
+        }
+        assert!(borsh::from_slice::<crate::aliases::I1>(&[2]).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use super::{utils::twos_complement, BigIntConversionError, ParseSignedError, Sign, Signed};
+use crate::aliases::*;
 use alloc::string::String;
 use core::str::FromStr;
 use ruint::Uint;
@@ -55,12 +56,23 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<String> for Signed<BITS, LIM
     }
 }
 
+/// Parses a decimal string, optionally prefixed with a sign, or a hex string.
+///
+/// Hex strings are parsed as the absolute value, like [`Signed::from_hex_str`].
+/// Hex strings with a `0x` prefix and without a sign are also accepted in
+/// two's complement form, as produced by the [`LowerHex`](core::fmt::LowerHex)
+/// implementation with the `#` flag, so `0xff` parses as `-1` for an 8-bit
+/// integer.
 impl<const BITS: usize, const LIMBS: usize> FromStr for Signed<BITS, LIMBS> {
     type Err = ParseSignedError;
 
-    #[inline(always)]
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        Signed::from_dec_str(value).or_else(|_| Signed::from_hex_str(value))
+        Signed::from_dec_str(value).or_else(|_| match Signed::from_hex_str(value) {
+            Err(ParseSignedError::IntegerOverflow) if value.starts_with("0x") => {
+                Ok(Signed::from_raw(Uint::from_str_radix(&value[2..], 16)?))
+            }
+            result => result,
+        })
     }
 }
 
@@ -91,14 +103,9 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<i128> for Signed<BITS, LIMBS
             return Self::try_from(u)
         }
 
-        // This is a bit messy :(
-        let tc = (!u).wrapping_add(1);
-        let stc = Uint::<128, 2>::saturating_from(tc);
-        let (num, overflow) = Uint::<BITS, LIMBS>::overflowing_from_limbs_slice(stc.as_limbs());
-        if overflow {
-            return Err(BigIntConversionError)
-        }
-        Ok(Signed(twos_complement(num)))
+        let abs = Uint::<BITS, LIMBS>::try_from(value.unsigned_abs())
+            .map_err(|_| BigIntConversionError)?;
+        Self::checked_from_sign_and_abs(Sign::Negative, abs).ok_or(BigIntConversionError)
     }
 }
 
@@ -110,13 +117,6 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<Signed<BITS, LIMBS>> for u12
             return Err(BigIntConversionError)
         }
 
-        let saturated = Uint::<BITS, LIMBS>::saturating_from(u128::MAX);
-
-        // if the value is greater than the saturated value, return an error
-        if value > Signed(saturated) {
-            return Err(BigIntConversionError)
-        }
-
         value
             .into_raw()
             .try_into()
@@ -169,15 +169,13 @@ macro_rules! impl_conversions {
 
                 #[inline(always)]
                 fn try_from(value: $i) -> Result<Self, Self::Error> {
-                    let uint: $u = value as $u;
-
-                    if value.is_positive() {
-                        return Self::try_from(uint);
+                    if value >= 0 {
+                        return Self::try_from(value as $u);
                     }
 
-                    let abs = (!uint).wrapping_add(1);
-                    let tc = twos_complement(Uint::<BITS, LIMBS>::from(abs));
-                    Ok(Self(tc))
+                    let abs = Uint::<BITS, LIMBS>::try_from(value.unsigned_abs())
+                        .map_err(|_| BigIntConversionError)?;
+                    Self::checked_from_sign_and_abs(Sign::Negative, abs).ok_or(BigIntConversionError)
                 }
             }
 
@@ -232,3 +230,33 @@ impl_conversions! {
     u64  [low_u64  -> low_u64,   as_u64],   i64  [low_u64  -> low_i64,   as_i64];
     usize[low_u64  -> low_usize, as_usize], isize[low_u64  -> low_isize, as_isize];
 }
+
+// lossless widening and checked narrowing between the sized aliases
+macro_rules! impl_resize {
+    ($narrow:ident $(, $wide:ident)*) => {
+        $(
+            impl From<$narrow> for $wide {
+                #[inline]
+                fn from(value: $narrow) -> Self {
+                    value.wrapping_resize()
+                }
+            }
+
+            impl TryFrom<$wide> for $narrow {
+                type Error = BigIntConversionError;
+
+                #[inline]
+                fn try_from(value: $wide) -> Result<Self, Self::Error> {
+                    value.checked_resize().ok_or(BigIntConversionError)
+                }
+            }
+        )*
+
+        impl_resize!($($wide),*);
+    };
+    () => {};
+}
+
+impl_resize!(
+    I8, I16, I32, I64, I128, I160, I192, I256, I320, I384, I448, I512, I1024, I2048, I4096
+);
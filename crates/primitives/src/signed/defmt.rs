@@ -0,0 +1,25 @@
+use super::Signed;
+
+/// Formats values that fit in an `i128` as decimal numbers, and larger values
+/// as their sign and `0x`-prefixed absolute value in hex.
+impl<const BITS: usize, const LIMBS: usize> defmt::Format for Signed<BITS, LIMBS> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        if let Ok(value) = i128::try_from(*self) {
+            defmt::write!(f, "{=i128}", value);
+            return
+        }
+
+        let (sign, abs) = self.into_sign_and_abs();
+        if sign.is_negative() {
+            defmt::write!(f, "-");
+        }
+        defmt::write!(f, "0x");
+        let mut limbs = abs.as_limbs().iter().rev().skip_while(|&&limb| limb == 0);
+        if let Some(limb) = limbs.next() {
+            defmt::write!(f, "{=u64:x}", limb);
+        }
+        for limb in limbs {
+            defmt::write!(f, "{=u64:016x}", limb);
+        }
+    }
+}
@@ -195,6 +195,40 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         self.0
     }
 
+    /// Converts `self` to a signed integer of a different size, sign-extending
+    /// when widening and discarding the high bits when narrowing.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_resize<const TO_BITS: usize, const TO_LIMBS: usize>(
+        self,
+    ) -> Signed<TO_BITS, TO_LIMBS> {
+        let negative = self.is_negative();
+        let mut limbs = [if negative { u64::MAX } else { 0 }; TO_LIMBS];
+        for (to, from) in limbs.iter_mut().zip(self.0.as_limbs()) {
+            *to = *from;
+        }
+        // sign-extend the partially used top limb of `self`
+        if negative && BITS % 64 != 0 && TO_LIMBS >= LIMBS {
+            limbs[LIMBS - 1] |= u64::MAX << (BITS % 64);
+        }
+        // clear the unused bits of the top limb of the result
+        if TO_BITS % 64 != 0 {
+            limbs[TO_LIMBS - 1] &= u64::MAX >> (64 - TO_BITS % 64);
+        }
+        Signed(Uint::from_limbs(limbs))
+    }
+
+    /// Converts `self` to a signed integer of a different size, returning
+    /// `None` if the value does not fit.
+    #[inline]
+    #[must_use]
+    pub fn checked_resize<const TO_BITS: usize, const TO_LIMBS: usize>(
+        self,
+    ) -> Option<Signed<TO_BITS, TO_LIMBS>> {
+        let resized = self.wrapping_resize::<TO_BITS, TO_LIMBS>();
+        (resized.wrapping_resize::<BITS, LIMBS>() == self).then_some(resized)
+    }
+
     /// Returns the sign of self.
     #[inline(always)]
     pub const fn sign(self) -> Sign {
@@ -418,6 +452,69 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         (sign, abs)
     }
 
+    /// Converts `self` to the nearest `f64`.
+    ///
+    /// This conversion is lossy: `f64` only has 53 bits of precision, so
+    /// integers larger than `2^53` in magnitude are rounded. Values that do not
+    /// fit in an `f64` at all, which requires more than 1024 bits, become
+    /// infinite.
+    ///
+    /// Unsigned integers can be converted with [`f64::from`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::I256;
+    /// assert_eq!(I256::try_from(-42).unwrap().to_f64_lossy(), -42.0);
+    /// assert_eq!(I256::MIN.to_f64_lossy(), -(2f64.powi(255)));
+    /// ```
+    #[inline]
+    pub fn to_f64_lossy(self) -> f64 {
+        let (sign, abs) = self.into_sign_and_abs();
+        let abs = f64::from(abs);
+        match sign {
+            Sign::Positive => abs,
+            Sign::Negative => -abs,
+        }
+    }
+
+    /// Creates a `Signed` from an `f64`, rounding to the nearest integer.
+    ///
+    /// This conversion is lossy: values that are out of range saturate to
+    /// [`MIN`](Self::MIN) or [`MAX`](Self::MAX), including infinities, and
+    /// `NaN` becomes zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::I256;
+    /// assert_eq!(I256::from_f64_lossy(-41.7), I256::try_from(-42).unwrap());
+    /// assert_eq!(I256::from_f64_lossy(1e100), I256::MAX);
+    /// assert_eq!(I256::from_f64_lossy(f64::NEG_INFINITY), I256::MIN);
+    /// assert_eq!(I256::from_f64_lossy(f64::NAN), I256::ZERO);
+    /// ```
+    pub fn from_f64_lossy(value: f64) -> Self {
+        if value.is_nan() {
+            return Self::ZERO
+        }
+
+        let (sign, abs) = if value < 0.0 {
+            (Sign::Negative, -value)
+        } else {
+            (Sign::Positive, value)
+        };
+        let saturated = match sign {
+            Sign::Positive => Self::MAX,
+            Sign::Negative => Self::MIN,
+        };
+        match Uint::<BITS, LIMBS>::try_from(abs) {
+            // `checked_from_sign_and_abs` rejects negative zero
+            Ok(abs) if abs == Uint::ZERO => Self::ZERO,
+            Ok(abs) => Self::checked_from_sign_and_abs(sign, abs).unwrap_or(saturated),
+            Err(_) => saturated,
+        }
+    }
+
     /// Converts `self` to a big-endian byte array of size exactly
     /// [`Self::BYTES`].
     ///
@@ -501,7 +598,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{aliases::*, BigIntConversionError, ParseSignedError};
+    use crate::{aliases::*, BigIntConversionError, ParseSignedError, Rounding};
     use alloc::string::ToString;
     use core::ops::Neg;
     use ruint::{
@@ -722,7 +819,9 @@ mod tests {
         assert_eq!("0x0".parse::<I1>(), Ok(I1::ZERO));
         assert_eq!("+0x0".parse::<I1>(), Ok(I1::ZERO));
         assert_eq!("-0x1".parse::<I1>(), Ok(I1::MINUS_ONE));
-        assert_eq!("0x1".parse::<I1>(), Err(ParseSignedError::IntegerOverflow));
+        assert_eq!("0x1".parse::<I1>(), Ok(I1::MINUS_ONE));
+        assert_eq!("+0x1".parse::<I1>(), Err(ParseSignedError::IntegerOverflow));
+        assert_eq!("0x2".parse::<I1>(), Err(ParseSignedError::IntegerOverflow));
 
         assert_eq!("0".parse::<I0>(), Ok(I0::default()));
         assert_eq!("+0".parse::<I0>(), Ok(I0::default()));
@@ -730,6 +829,25 @@ mod tests {
         assert_eq!("+0".parse::<I1>(), Ok(I1::ZERO));
         assert_eq!("-1".parse::<I1>(), Ok(I1::MINUS_ONE));
         assert_eq!("1".parse::<I1>(), Err(ParseSignedError::IntegerOverflow));
+
+        // two's complement hex
+        assert_eq!("0xff".parse::<I8>(), Ok(I8::MINUS_ONE));
+        assert_eq!("0x80".parse::<I8>(), Ok(I8::MIN));
+        assert_eq!("0x7f".parse::<I8>(), Ok(I8::MAX));
+        assert_eq!("-0x80".parse::<I8>(), Ok(I8::MIN));
+        assert_eq!(
+            "-0xff".parse::<I8>(),
+            Err(ParseSignedError::IntegerOverflow)
+        );
+        assert_eq!(
+            "0x100".parse::<I8>(),
+            Err(ParseSignedError::IntegerOverflow)
+        );
+
+        for value in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            assert_eq!(format!("{value:#x}").parse::<I256>(), Ok(value));
+            assert_eq!(value.to_string().parse::<I256>(), Ok(value));
+        }
     }
 
     #[test]
@@ -1728,4 +1846,320 @@ mod tests {
         run_test!(I192, U192);
         run_test!(I256, U256);
     }
+
+    #[test]
+    fn primitive_conversions() {
+        for v in i16::MIN..=i16::MAX {
+            let expected = i8::try_from(v).ok();
+            let i8_value = |x: I8| i8::try_from(x).unwrap();
+            assert_eq!(I8::try_from(v).ok().map(i8_value), expected, "{v}");
+            assert_eq!(I8::try_from(v as i32).ok().map(i8_value), expected, "{v}");
+            assert_eq!(I8::try_from(v as i64).ok().map(i8_value), expected, "{v}");
+            assert_eq!(I8::try_from(v as isize).ok().map(i8_value), expected, "{v}");
+            assert_eq!(I8::try_from(v as i128).ok().map(i8_value), expected, "{v}");
+            if let Ok(u) = u16::try_from(v) {
+                assert_eq!(I8::try_from(u).ok().map(i8_value), expected, "{v}");
+                assert_eq!(I8::try_from(u as u128).ok().map(i8_value), expected, "{v}");
+            }
+
+            let value = I16::try_from(v).unwrap();
+            assert_eq!(i16::try_from(value), Ok(v));
+            assert_eq!(i128::try_from(value), Ok(v as i128));
+            assert_eq!(u8::try_from(value).ok(), u8::try_from(v).ok(), "{v}");
+            assert_eq!(u128::try_from(value).ok(), u128::try_from(v).ok(), "{v}");
+        }
+
+        for v in [i128::MIN, i128::MIN + 1, -1, 0, i128::MAX] {
+            let value = I256::try_from(v).unwrap();
+            assert_eq!(i128::try_from(value), Ok(v));
+            assert_eq!(I128::try_from(v).map(i128::try_from), Ok(Ok(v)));
+        }
+        assert!(I128::try_from(u128::MAX).is_err());
+        assert_eq!(
+            u128::try_from(I256::try_from(u128::MAX).unwrap()),
+            Ok(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn resize() {
+        for v in i16::MIN..=i16::MAX {
+            let value = I16::try_from(v).unwrap();
+            assert_eq!(
+                value.wrapping_resize(),
+                I8::try_from(v as i8).unwrap(),
+                "{v}"
+            );
+            assert_eq!(
+                I8::try_from(value).ok(),
+                i8::try_from(v).ok().map(|x| I8::try_from(x).unwrap())
+            );
+            assert_eq!(I256::from(value), I256::try_from(v).unwrap(), "{v}");
+            assert_eq!(I4096::from(value).checked_resize(), Some(value), "{v}");
+            assert_eq!(
+                value.wrapping_resize::<12, 1>().wrapping_resize(),
+                I16::try_from((v << 4) >> 4).unwrap()
+            );
+        }
+
+        assert_eq!(I512::from(I256::MIN), -I512::from(I256::MAX) - I512::ONE);
+        assert_eq!(I256::try_from(I512::from(I256::MIN)), Ok(I256::MIN));
+        assert!(I256::try_from(I512::from(I256::MAX) + I512::ONE).is_err());
+        assert!(I256::try_from(I512::from(I256::MIN) - I512::ONE).is_err());
+        assert_eq!(I1::MINUS_ONE.wrapping_resize(), I256::MINUS_ONE);
+        assert_eq!(I256::MINUS_ONE.checked_resize(), Some(I1::MINUS_ONE));
+        assert_eq!(I256::ONE.checked_resize::<1, 1>(), None);
+        assert_eq!(I256::ONE.checked_resize::<0, 0>(), None);
+        assert_eq!(I0::ZERO.wrapping_resize(), I256::ZERO);
+    }
+
+    #[test]
+    fn matches_std_i8() {
+        type U8 = Uint<8, 1>;
+
+        let i = |x: i8| I8::try_from(x).unwrap();
+        let pair = |(x, overflow): (i8, bool)| (i(x), overflow);
+
+        for a in i8::MIN..=i8::MAX {
+            let sa = i(a);
+            assert_eq!(sa.overflowing_neg(), pair(a.overflowing_neg()), "-{a}");
+            assert_eq!(sa.checked_neg(), a.checked_neg().map(i), "-{a}");
+            assert_eq!(sa.saturating_neg(), i(a.saturating_neg()), "-{a}");
+            assert_eq!(sa.wrapping_neg(), i(a.wrapping_neg()), "-{a}");
+            assert_eq!(sa.overflowing_abs(), pair(a.overflowing_abs()), "|{a}|");
+            assert_eq!(sa.checked_abs(), a.checked_abs().map(i), "|{a}|");
+            assert_eq!(sa.saturating_abs(), i(a.saturating_abs()), "|{a}|");
+            assert_eq!(sa.wrapping_abs(), i(a.wrapping_abs()), "|{a}|");
+            assert_eq!(sa.unsigned_abs(), U8::from(a.unsigned_abs()), "|{a}|");
+
+            for exp in 0..=9u32 {
+                let e = U8::from(exp);
+                assert_eq!(
+                    sa.overflowing_pow(e),
+                    pair(a.overflowing_pow(exp)),
+                    "{a}^{exp}"
+                );
+                assert_eq!(sa.checked_pow(e), a.checked_pow(exp).map(i), "{a}^{exp}");
+                assert_eq!(sa.saturating_pow(e), i(a.saturating_pow(exp)), "{a}^{exp}");
+                assert_eq!(sa.wrapping_pow(e), i(a.wrapping_pow(exp)), "{a}^{exp}");
+            }
+
+            for shift in 0..=9u32 {
+                let s = shift as usize;
+                assert_eq!(
+                    sa.checked_shl(s).is_some(),
+                    a.checked_shl(shift).is_some(),
+                    "{a}<<{shift}"
+                );
+                assert_eq!(
+                    sa.checked_shr(s).is_some(),
+                    a.checked_shr(shift).is_some(),
+                    "{a}>>{shift}"
+                );
+                assert_eq!(sa.asr(s), i(a >> shift.min(7)), "{a}>>{shift}");
+                // shifting zero by any amount is lossless
+                let shifted = match a.checked_shl(shift) {
+                    Some(x) => (x >> shift == a).then_some(x),
+                    None => (a == 0).then_some(0),
+                };
+                assert_eq!(sa.asl(s), shifted.map(i), "{a}<<{shift}");
+            }
+
+            for b in i8::MIN..=i8::MAX {
+                let sb = i(b);
+                assert_eq!(
+                    sa.overflowing_add(sb),
+                    pair(a.overflowing_add(b)),
+                    "{a}+{b}"
+                );
+                assert_eq!(sa.checked_add(sb), a.checked_add(b).map(i), "{a}+{b}");
+                assert_eq!(sa.saturating_add(sb), i(a.saturating_add(b)), "{a}+{b}");
+                assert_eq!(sa.wrapping_add(sb), i(a.wrapping_add(b)), "{a}+{b}");
+
+                assert_eq!(
+                    sa.overflowing_sub(sb),
+                    pair(a.overflowing_sub(b)),
+                    "{a}-{b}"
+                );
+                assert_eq!(sa.checked_sub(sb), a.checked_sub(b).map(i), "{a}-{b}");
+                assert_eq!(sa.saturating_sub(sb), i(a.saturating_sub(b)), "{a}-{b}");
+                assert_eq!(sa.wrapping_sub(sb), i(a.wrapping_sub(b)), "{a}-{b}");
+
+                assert_eq!(
+                    sa.overflowing_mul(sb),
+                    pair(a.overflowing_mul(b)),
+                    "{a}*{b}"
+                );
+                assert_eq!(sa.checked_mul(sb), a.checked_mul(b).map(i), "{a}*{b}");
+                assert_eq!(sa.saturating_mul(sb), i(a.saturating_mul(b)), "{a}*{b}");
+                assert_eq!(sa.wrapping_mul(sb), i(a.wrapping_mul(b)), "{a}*{b}");
+
+                assert_eq!(sa.checked_div(sb), a.checked_div(b).map(i), "{a}/{b}");
+                assert_eq!(sa.checked_rem(sb), a.checked_rem(b).map(i), "{a}%{b}");
+                assert_eq!(
+                    sa.checked_div_euclid(sb),
+                    a.checked_div_euclid(b).map(i),
+                    "{a}/{b}"
+                );
+                assert_eq!(
+                    sa.checked_rem_euclid(sb),
+                    a.checked_rem_euclid(b).map(i),
+                    "{a}%{b}"
+                );
+                if b == 0 {
+                    continue
+                }
+                assert_eq!(
+                    sa.overflowing_div(sb),
+                    pair(a.overflowing_div(b)),
+                    "{a}/{b}"
+                );
+                assert_eq!(sa.saturating_div(sb), i(a.saturating_div(b)), "{a}/{b}");
+                assert_eq!(sa.wrapping_div(sb), i(a.wrapping_div(b)), "{a}/{b}");
+                assert_eq!(
+                    sa.overflowing_rem(sb),
+                    pair(a.overflowing_rem(b)),
+                    "{a}%{b}"
+                );
+                assert_eq!(sa.wrapping_rem(sb), i(a.wrapping_rem(b)), "{a}%{b}");
+                assert_eq!(
+                    sa.overflowing_div_euclid(sb),
+                    pair(a.overflowing_div_euclid(b)),
+                    "{a}/{b}"
+                );
+                assert_eq!(
+                    sa.wrapping_div_euclid(sb),
+                    i(a.wrapping_div_euclid(b)),
+                    "{a}/{b}"
+                );
+                assert_eq!(
+                    sa.overflowing_rem_euclid(sb),
+                    pair(a.overflowing_rem_euclid(b)),
+                    "{a}%{b}"
+                );
+                assert_eq!(
+                    sa.wrapping_rem_euclid(sb),
+                    i(a.wrapping_rem_euclid(b)),
+                    "{a}%{b}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn div_rounding() {
+        let i = |x: i8| I8::try_from(x).unwrap();
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (sa, sb) = (i(a), i(b));
+                let checked = a.checked_div(b);
+                for rounding in [
+                    Rounding::Floor,
+                    Rounding::Ceil,
+                    Rounding::Trunc,
+                    Rounding::Expand,
+                    Rounding::HalfEven,
+                ] {
+                    let expected = checked.map(|_| {
+                        let exact = f64::from(a) / f64::from(b);
+                        let rounded = match rounding {
+                            Rounding::Floor => exact.floor(),
+                            Rounding::Ceil => exact.ceil(),
+                            Rounding::Trunc => exact.trunc(),
+                            Rounding::Expand if exact < 0.0 => exact.floor(),
+                            Rounding::Expand => exact.ceil(),
+                            Rounding::HalfEven => {
+                                let floor = exact.floor();
+                                match exact - floor {
+                                    d if d < 0.5 => floor,
+                                    d if d > 0.5 => floor + 1.0,
+                                    _ if floor % 2.0 == 0.0 => floor,
+                                    _ => floor + 1.0,
+                                }
+                            }
+                        };
+                        i(rounded as i8)
+                    });
+                    let actual = sa.checked_div_rounding(sb, rounding);
+                    assert_eq!(actual, expected, "{a}/{b} {rounding:?}");
+                }
+                if checked.is_some() {
+                    assert_eq!(sa.div_floor(sb), sa.div_rounding(sb, Rounding::Floor));
+                    assert_eq!(sa.div_ceil(sb), sa.div_rounding(sb, Rounding::Ceil));
+                    assert_eq!(sa.div_euclid(sb), i(a.div_euclid(b)), "{a}/{b}");
+                    assert_eq!(sa.rem_euclid(sb), i(a.rem_euclid(b)), "{a}%{b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn roots_and_logs() {
+        for a in 0..=i8::MAX {
+            let sa = I8::try_from(a).unwrap();
+            let sqrt = f64::from(a).sqrt() as i8;
+            assert_eq!(sa.isqrt(), I8::try_from(sqrt).unwrap(), "sqrt({a})");
+            if a > 0 {
+                assert_eq!(sa.log2(), 7 - a.leading_zeros() as usize, "log2({a})");
+                assert_eq!(sa.log10(), a.to_string().len() - 1, "log10({a})");
+            }
+        }
+
+        assert_eq!(I8::MINUS_ONE.checked_isqrt(), None);
+        assert_eq!(I8::ZERO.checked_log2(), None);
+        assert_eq!(I8::MIN.checked_log10(), None);
+
+        assert_eq!(
+            I256::MAX.isqrt(),
+            I256::from_raw((U256::MAX >> 1usize).root(2))
+        );
+        assert_eq!(I256::MAX.log2(), 254);
+        assert_eq!(I256::exp10(76).log10(), 76);
+        assert_eq!((I256::exp10(76) - I256::ONE).log10(), 75);
+    }
+
+    #[test]
+    #[should_panic = "argument of integer square root cannot be negative"]
+    fn isqrt_negative() {
+        let _ = I256::MINUS_ONE.isqrt();
+    }
+
+    #[test]
+    fn f64_lossy() {
+        for value in [
+            0i64,
+            1,
+            -1,
+            42,
+            -42,
+            i64::MAX,
+            i64::MIN,
+            1 << 53,
+            -(1 << 53),
+        ] {
+            let signed = I256::try_from(value).unwrap();
+            assert_eq!(signed.to_f64_lossy(), value as f64, "{value}");
+            assert_eq!(
+                I256::from_f64_lossy(value as f64).to_f64_lossy(),
+                value as f64,
+                "{value}"
+            );
+        }
+
+        assert_eq!(I256::from_f64_lossy(0.5), I256::ONE);
+        assert_eq!(I256::from_f64_lossy(0.4), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(-0.4), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(-0.0), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(-1.5), I256::try_from(-2).unwrap());
+
+        assert_eq!(I256::MAX.to_f64_lossy(), 2f64.powi(255));
+        assert_eq!(I256::from_f64_lossy(2f64.powi(255)), I256::MAX);
+        assert_eq!(I256::from_f64_lossy(-(2f64.powi(255))), I256::MIN);
+        assert_eq!(I256::from_f64_lossy(f64::INFINITY), I256::MAX);
+        assert_eq!(I256::from_f64_lossy(f64::MIN), I256::MIN);
+        assert_eq!(I256::from_f64_lossy(f64::NAN), I256::ZERO);
+
+        assert_eq!(I0::from_f64_lossy(1.0), I0::ZERO);
+        assert_eq!(I8::from_f64_lossy(-300.0), I8::MIN);
+    }
 }
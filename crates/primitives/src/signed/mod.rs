@@ -3,6 +3,10 @@
 /// Conversion implementations.
 mod conversions;
 
+/// `defmt` support.
+#[cfg(feature = "defmt")]
+mod defmt;
+
 /// Error types for signed integers.
 mod errors;
 pub use errors::{BigIntConversionError, ParseSignedError};
@@ -14,10 +18,26 @@ pub use int::Signed;
 /// Operation implementations.
 mod ops;
 
+/// Rounding modes for division.
+mod rounding;
+pub use rounding::Rounding;
+
 /// A simple [`Sign`] enum, for dealing with integer signs.
 mod sign;
 pub use sign::Sign;
 
+/// Borsh support.
+#[cfg(feature = "borsh")]
+mod borsh;
+
+/// RLP support.
+#[cfg(feature = "rlp")]
+mod rlp;
+
+/// JSON Schema support.
+#[cfg(feature = "schemars")]
+mod schemars;
+
 /// Serde support.
 #[cfg(feature = "serde")]
 mod serde;
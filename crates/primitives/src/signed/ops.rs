@@ -1,6 +1,6 @@
 use super::{
     utils::{handle_overflow, twos_complement},
-    Sign, Signed,
+    Rounding, Sign, Signed,
 };
 use core::{cmp, iter, ops};
 use ruint::Uint;
@@ -578,6 +578,94 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         }
     }
 
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards
+    /// negative infinity.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0 or the division results in overflow.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub fn div_floor(self, rhs: Self) -> Self {
+        self.div_rounding(rhs, Rounding::Floor)
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards
+    /// positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0 or the division results in overflow.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub fn div_ceil(self, rhs: Self) -> Self {
+        self.div_rounding(rhs, Rounding::Ceil)
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result
+    /// according to `rounding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{I256, Rounding};
+    /// let a = I256::try_from(-7).unwrap();
+    /// let b = I256::try_from(2).unwrap();
+    /// assert_eq!(a.div_rounding(b, Rounding::Floor), I256::try_from(-4).unwrap());
+    /// assert_eq!(a.div_rounding(b, Rounding::Ceil), I256::try_from(-3).unwrap());
+    /// assert_eq!(a.div_rounding(b, Rounding::HalfEven), I256::try_from(-4).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is 0 or the division results in overflow.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn div_rounding(self, rhs: Self, rounding: Rounding) -> Self {
+        let q = self / rhs;
+        let r = self % rhs;
+        if r.is_zero() {
+            return q
+        }
+
+        // `r` is non-zero, so the exact quotient is not an integer and lies
+        // strictly between `q` and `q` rounded away from zero
+        let negative = self.is_negative() != rhs.is_negative();
+        let round_away = match rounding {
+            Rounding::Floor => negative,
+            Rounding::Ceil => !negative,
+            Rounding::Trunc => false,
+            Rounding::Expand => true,
+            Rounding::HalfEven => {
+                let r = r.unsigned_abs();
+                let rest = rhs.unsigned_abs() - r;
+                r > rest || (r == rest && q.0.bit(0))
+            }
+        };
+
+        // `abs(rhs) >= 2` here, so `q` can't be at the numeric bounds
+        match (round_away, negative) {
+            (false, _) => q,
+            (true, false) => q + Self::ONE,
+            (true, true) => q - Self::ONE,
+        }
+    }
+
+    /// Checked rounding division. Computes `self.div_rounding(rhs, rounding)`,
+    /// returning `None` if `rhs == 0` or the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div_rounding(self, rhs: Self, rounding: Rounding) -> Option<Self> {
+        if rhs.is_zero() || (self == Self::MIN && rhs == Self::MINUS_ONE) {
+            None
+        } else {
+            Some(self.div_rounding(rhs, rounding))
+        }
+    }
+
     /// Returns the sign of `self` to the exponent `exp`.
     ///
     /// Note that this method does not actually try to compute the `self` to the
@@ -678,6 +766,78 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         self.overflowing_pow(exp).0
     }
 
+    /// Returns the square root of `self`, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is negative.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub fn isqrt(self) -> Self {
+        self.checked_isqrt()
+            .expect("argument of integer square root cannot be negative")
+    }
+
+    /// Returns the square root of `self`, rounded down, or `None` if `self` is
+    /// negative.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_isqrt(self) -> Option<Self> {
+        match self.sign() {
+            Sign::Positive => Some(Self(self.0.root(2))),
+            Sign::Negative => None,
+        }
+    }
+
+    /// Returns the base 2 logarithm of `self`, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is less than or equal to zero.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub fn log2(self) -> usize {
+        self.checked_log2()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 2 logarithm of `self`, rounded down, or `None` if
+    /// `self` is less than or equal to zero.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_log2(self) -> Option<usize> {
+        match self.sign() {
+            Sign::Positive => self.0.checked_log2(),
+            Sign::Negative => None,
+        }
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is less than or equal to zero.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub fn log10(self) -> usize {
+        self.checked_log10()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the base 10 logarithm of `self`, rounded down, or `None` if
+    /// `self` is less than or equal to zero.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_log10(self) -> Option<usize> {
+        match self.sign() {
+            Sign::Positive => self.0.checked_log10(),
+            Sign::Negative => None,
+        }
+    }
+
     /// Shifts self left by `rhs` bits.
     ///
     /// Returns a tuple of the shifted version of self along with a boolean
@@ -686,7 +846,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn overflowing_shl(self, rhs: usize) -> (Self, bool) {
-        if rhs >= 256 {
+        if rhs >= BITS {
             (Self::ZERO, true)
         } else {
             (Self(self.0 << rhs), false)
@@ -720,7 +880,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     #[inline(always)]
     #[must_use]
     pub fn overflowing_shr(self, rhs: usize) -> (Self, bool) {
-        if rhs >= 256 {
+        if rhs >= BITS {
             (Self::ZERO, true)
         } else {
             (Self(self.0 >> rhs), false)
@@ -784,21 +944,21 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// Arithmetic shift left operation. Computes `self << rhs`, checking for
     /// overflow on the final result.
     ///
-    /// Returns `None` if the operation overflowed (most significant bit
-    /// changes).
+    /// Returns `None` if the operation overflowed, that is if any of the bits
+    /// shifted out, or the sign bit, differ from the original sign.
     #[inline(always)]
     #[must_use]
     pub fn asl(self, rhs: usize) -> Option<Self> {
         if rhs == 0 || BITS == 0 {
-            Some(self)
+            return Some(self)
+        }
+
+        let result = self.wrapping_shl(rhs);
+        // the shift is lossless iff it can be undone
+        if result.asr(rhs) == self {
+            Some(result)
         } else {
-            let result = self.wrapping_shl(rhs);
-            if result.sign() != self.sign() {
-                // Overflow occurred
-                None
-            } else {
-                Some(result)
-            }
+            None
         }
     }
 
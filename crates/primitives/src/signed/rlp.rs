@@ -0,0 +1,96 @@
+use super::Signed;
+use alloy_rlp::{length_of_length, Decodable, Encodable, Error, Header, EMPTY_STRING_CODE};
+use ruint::Uint;
+
+/// Signed integers are RLP-encoded like unsigned integers, using their two's
+/// complement representation: big-endian, with no leading zero bytes.
+impl<const BITS: usize, const LIMBS: usize> Encodable for Signed<BITS, LIMBS> {
+    #[inline]
+    fn length(&self) -> usize {
+        match self.0.bit_len() {
+            0..=7 => 1,
+            _ => {
+                let len = self.0.byte_len();
+                len + length_of_length(len)
+            }
+        }
+    }
+
+    fn encode(&self, out: &mut dyn bytes::BufMut) {
+        match self.0.bit_len() {
+            0 => out.put_u8(EMPTY_STRING_CODE),
+            1..=7 => out.put_u8(self.0.as_limbs()[0] as u8),
+            _ => {
+                let len = self.0.byte_len();
+                Header {
+                    list: false,
+                    payload_length: len,
+                }
+                .encode(out);
+                let limbs = self.0.as_limbs();
+                for i in (0..len).rev() {
+                    out.put_u8((limbs[i / 8] >> (i % 8 * 8)) as u8);
+                }
+            }
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decodable for Signed<BITS, LIMBS> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if header.list {
+            return Err(Error::UnexpectedList)
+        }
+
+        let bytes = &buf[..header.payload_length];
+        if bytes.first() == Some(&0) {
+            return Err(Error::LeadingZero)
+        }
+        *buf = &buf[header.payload_length..];
+        Uint::try_from_be_slice(bytes)
+            .map(Self)
+            .ok_or(Error::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        aliases::{I512, I8},
+        hex, I256,
+    };
+    use alloy_rlp::{Decodable, Encodable};
+
+    #[test]
+    fn rlp() {
+        let cases = [
+            (I256::ZERO, &hex!("80")[..]),
+            (I256::ONE, &hex!("01")[..]),
+            (I256::try_from(128).unwrap(), &hex!("8180")[..]),
+            (
+                I256::MINUS_ONE,
+                &hex!("a0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")[..],
+            ),
+            (
+                I256::MIN,
+                &hex!("a08000000000000000000000000000000000000000000000000000000000000000")[..],
+            ),
+        ];
+        for (value, expected) in cases {
+            let encoded = alloy_rlp::encode(value);
+            assert_eq!(encoded, expected);
+            assert_eq!(value.length(), expected.len());
+            assert_eq!(I256::decode(&mut &encoded[..]).unwrap(), value);
+        }
+
+        let encoded = alloy_rlp::encode(I512::MINUS_ONE);
+        assert_eq!(encoded[..2], [0xb8, 64]);
+        assert_eq!(encoded[2..], [0xff; 64]);
+        assert_eq!(I512::decode(&mut &encoded[..]).unwrap(), I512::MINUS_ONE);
+
+        assert_eq!(I8::decode(&mut &hex!("81ff")[..]).unwrap(), I8::MINUS_ONE);
+        assert!(I8::decode(&mut &hex!("820100")[..]).is_err());
+        assert!(I8::decode(&mut &hex!("00")[..]).is_err());
+    }
+}
@@ -0,0 +1,15 @@
+/// Rounding mode used by [`Signed::div_rounding`](crate::Signed::div_rounding).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards zero. This is the behavior of the `/` operator.
+    #[default]
+    Trunc,
+    /// Round away from zero.
+    Expand,
+    /// Round to the nearest integer, with ties rounded to the even neighbor.
+    HalfEven,
+}
@@ -0,0 +1,41 @@
+use super::Signed;
+use alloc::{boxed::Box, format, string::String};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+
+/// Signed integers are described as strings holding either a signed decimal
+/// number or a `0x`-prefixed hex number, as they are deserialized.
+impl<const BITS: usize, const LIMBS: usize> JsonSchema for Signed<BITS, LIMBS> {
+    #[inline]
+    fn schema_name() -> String {
+        format!("I{BITS}")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(String::from("^-?(0x[0-9a-fA-F]+|[0-9]+)$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::I256;
+
+    #[test]
+    fn schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(I256)).unwrap();
+        assert_eq!(schema["title"], "I256");
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["pattern"], "^-?(0x[0-9a-fA-F]+|[0-9]+)$");
+    }
+}
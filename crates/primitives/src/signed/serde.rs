@@ -1,17 +1,30 @@
 use super::Signed;
 use alloc::string::String;
 use core::fmt;
+use ruint::Uint;
 use serde::{
     de::{self, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
+/// Serializes a signed decimal string in human-readable formats, and the
+/// big-endian two's complement bytes in binary formats.
 impl<const BITS: usize, const LIMBS: usize> Serialize for Signed<BITS, LIMBS> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.collect_str(self)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0.to_be_bytes_vec())
+        }
     }
 }
 
+/// Deserializes integers, signed decimal strings and `0x`-prefixed hex strings
+/// in human-readable formats, and big-endian two's complement bytes in binary
+/// formats.
+///
+/// Hex strings are interpreted as a sign and magnitude if they are prefixed
+/// with `-`, and as two's complement otherwise.
 impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Signed<BITS, LIMBS> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         struct SignedVisitor<const BITS: usize, const LIMBS: usize>;
@@ -46,10 +59,65 @@ impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Signed<BIT
             fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
                 self.visit_str(&v)
             }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                if v.len() != Uint::<BITS, LIMBS>::BYTES {
+                    return Err(de::Error::invalid_length(v.len(), &self))
+                }
+                Uint::try_from_be_slice(v)
+                    .map(Signed::from_raw)
+                    .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Bytes(v), &self))
+            }
         }
 
-        deserializer.deserialize_any(SignedVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignedVisitor)
+        } else {
+            deserializer.deserialize_bytes(SignedVisitor)
+        }
     }
 }
 
-// TODO: Tests
+#[cfg(test)]
+mod tests {
+    use crate::{aliases::I8, I256};
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn serde_human_readable() {
+        let value = I256::try_from(-1234).unwrap();
+        let ser = serde_json::to_string(&value).unwrap();
+        assert_eq!(ser, "\"-1234\"");
+        assert_eq!(serde_json::from_str::<I256>(&ser).unwrap(), value);
+
+        let values: Vec<I8> =
+            serde_json::from_str(r#"[-128, 127, "-12", "0x7f", "0x80", "-0x80", "0xff"]"#).unwrap();
+        let expected = [-128, 127, -12, 127, -128, -128, -1];
+        assert_eq!(values, expected.map(|x: i8| I8::try_from(x).unwrap()));
+
+        assert!(serde_json::from_str::<I8>("128").is_err());
+        assert!(serde_json::from_str::<I8>("\"-129\"").is_err());
+        assert!(serde_json::from_str::<I8>("\"0x100\"").is_err());
+    }
+
+    #[test]
+    fn serde_binary() {
+        for value in [
+            I256::MIN,
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::try_from(1234).unwrap(),
+        ] {
+            let mut ser = Vec::new();
+            ciborium::into_writer(&value, &mut ser).unwrap();
+            // byte string of length 32, followed by the two's complement bytes
+            assert_eq!(ser[..2], [0x58, 32]);
+            assert_eq!(ser[2..], value.into_raw().to_be_bytes::<32>());
+            assert_eq!(ciborium::from_reader::<I256, _>(&ser[..]).unwrap(), value);
+        }
+
+        let mut ser = Vec::new();
+        ciborium::into_writer(&ciborium::Value::Bytes(vec![0xff; 31]), &mut ser).unwrap();
+        assert!(ciborium::from_reader::<I256, _>(&ser[..]).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Common Ethereum utilities.
+
+use crate::{bits::FixedBytes, B256};
+use core::fmt;
+
+#[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))]
+use alloc::vec::Vec;
+
+pub mod units;
+pub use units::{
+    format_ether, format_units, parse_ether, parse_units, ParseUnits, Unit, UnitsError,
+};
+
+/// The prefix used for hashing messages according to [EIP-191].
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub const EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+#[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))]
+#[link(wasm_import_module = "vm_hooks")]
+extern "C" {
+    /// When targeting VMs with native keccak hooks, the `native-keccak` feature
+    /// can be enabled to import and use the host environment's implementation
+    /// of [`keccak256`] in place of [`tiny_keccak`]. This is overridden when
+    /// the `tiny-keccak` feature is enabled, and takes precedence over the
+    /// `asm-keccak` feature.
+    ///
+    /// # SAFETY
+    ///
+    /// The VM accepts the preimage by pointer and length, and writes the
+    /// 32-byte hash.
+    /// - `bytes` must point to an input buffer at least `len` long.
+    /// - `output` must point to a buffer that is at least 32-bytes long.
+    ///
+    /// [`keccak256`]: https://en.wikipedia.org/wiki/SHA-3
+    /// [`tiny_keccak`]: https://docs.rs/tiny-keccak/latest/tiny_keccak/
+    fn native_keccak256(bytes: *const u8, len: usize, output: *mut u8);
+}
+
+/// Simple interface to the [`keccak256`] hash function.
+///
+/// [`keccak256`]: https://en.wikipedia.org/wiki/SHA-3
+pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> FixedBytes<32> {
+    cfg_if::cfg_if! {
+        if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+            /// Calls an external native keccak hook when `native-keccak` is enabled.
+            /// This is overridden when `tiny-keccak` is enabled.
+            fn keccak256(bytes: &[u8]) -> FixedBytes<32> {
+                let mut output = [0u8; 32];
+
+                // SAFETY: The output is 32-bytes, and the input comes from a slice.
+                unsafe { native_keccak256(bytes.as_ptr(), bytes.len(), output.as_mut_ptr()) };
+                output.into()
+            }
+        } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+            /// Calls [`keccak-asm`] when the `asm-keccak` feature is enabled.
+            /// This is overridden when `tiny-keccak` is enabled.
+            ///
+            /// [`keccak-asm`]: https://docs.rs/keccak-asm/latest/keccak_asm/
+            fn keccak256(bytes: &[u8]) -> FixedBytes<32> {
+                use keccak_asm::{Digest, Keccak256};
+
+                let mut output = FixedBytes::ZERO;
+                let mut hasher = Keccak256::new();
+                hasher.update(bytes);
+                hasher.finalize_into((&mut output.0).into());
+                output
+            }
+        } else {
+            /// Calls [`tiny-keccak`] when the `tiny-keccak` feature is enabled or
+            /// when no particular keccak feature flag is specified.
+            ///
+            /// [`tiny_keccak`]: https://docs.rs/tiny-keccak/latest/tiny_keccak/
+            fn keccak256(bytes: &[u8]) -> FixedBytes<32> {
+                use tiny_keccak::{Hasher, Keccak};
+
+                let mut output = [0u8; 32];
+                let mut hasher = Keccak::v256();
+                hasher.update(bytes);
+                hasher.finalize(&mut output);
+                output.into()
+            }
+        }
+    }
+
+    keccak256(bytes.as_ref())
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+        // The native hook only hashes whole inputs, so the data is buffered
+        // until the hasher is finalized.
+        type Hasher = Vec<u8>;
+    } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+        type Hasher = keccak_asm::Keccak256;
+    } else {
+        type Hasher = tiny_keccak::Keccak;
+    }
+}
+
+/// Simple [`Keccak-256`] hasher, which can be fed incrementally.
+///
+/// Uses the same backend as [`keccak256`].
+///
+/// [`Keccak-256`]: https://en.wikipedia.org/wiki/SHA-3
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{keccak256, utils::Keccak256};
+///
+/// let mut hasher = Keccak256::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// assert_eq!(hasher.finalize(), keccak256(b"hello world"));
+/// ```
+#[derive(Clone)]
+pub struct Keccak256 {
+    hasher: Hasher,
+}
+
+impl Default for Keccak256 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Keccak256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keccak256").finish_non_exhaustive()
+    }
+}
+
+impl Keccak256 {
+    /// Creates a new [`Keccak256`] hasher.
+    #[inline]
+    pub fn new() -> Self {
+        cfg_if::cfg_if! {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+                let hasher = Hasher::new();
+            } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+                let hasher = <Hasher as keccak_asm::Digest>::new();
+            } else {
+                let hasher = Hasher::v256();
+            }
+        }
+        Self { hasher }
+    }
+
+    /// Absorbs additional input. Can be called multiple times.
+    #[inline]
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let bytes = bytes.as_ref();
+        cfg_if::cfg_if! {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+                self.hasher.extend_from_slice(bytes);
+            } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+                keccak_asm::Digest::update(&mut self.hasher, bytes);
+            } else {
+                tiny_keccak::Hasher::update(&mut self.hasher, bytes);
+            }
+        }
+    }
+
+    /// Pads and squeezes the state into the output.
+    #[inline]
+    pub fn finalize(self) -> B256 {
+        let mut output = B256::ZERO;
+        self.finalize_into(&mut output.0);
+        output
+    }
+
+    /// Pads and squeezes the state into the given output buffer.
+    #[inline]
+    pub fn finalize_into(self, output: &mut [u8; 32]) {
+        cfg_if::cfg_if! {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+                *output = keccak256(self.hasher).0;
+            } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+                keccak_asm::Digest::finalize_into(self.hasher, output.into());
+            } else {
+                tiny_keccak::Hasher::finalize(self.hasher, output);
+            }
+        }
+    }
+}
+
+/// Hashes a message according to [EIP-191] (version `0x45`, personal sign),
+/// prefixing it with [`EIP191_PREFIX`] and its length in decimal:
+///
+/// `keccak256("\x19Ethereum Signed Message:\n" + len(message) + message)`
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, eip191_hash_message};
+///
+/// assert_eq!(
+///     eip191_hash_message("Hello World"),
+///     b256!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2"),
+/// );
+/// ```
+pub fn eip191_hash_message<T: AsRef<[u8]>>(message: T) -> B256 {
+    fn eip191_hash_message(message: &[u8]) -> B256 {
+        let mut len = itoa::Buffer::new();
+        let len = len.format(message.len());
+
+        let mut hasher = Keccak256::new();
+        hasher.update(EIP191_PREFIX);
+        hasher.update(len);
+        hasher.update(message);
+        hasher.finalize()
+    }
+
+    eip191_hash_message(message.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eip191_prefix() {
+        let message = b"abc";
+        let expected = keccak256(b"\x19Ethereum Signed Message:\n3abc");
+        assert_eq!(eip191_hash_message(message), expected);
+
+        let message = [0xff; 100];
+        let mut prefixed = b"\x19Ethereum Signed Message:\n100".to_vec();
+        prefixed.extend_from_slice(&message);
+        assert_eq!(eip191_hash_message(message), keccak256(prefixed));
+    }
+
+    #[test]
+    fn streaming_keccak256() {
+        let data = (0..=255u8)
+            .cycle()
+            .take(1000)
+            .collect::<alloc::vec::Vec<_>>();
+        let expected = keccak256(&data);
+
+        for chunk_size in [1, 7, 136, 137, 1000] {
+            let mut hasher = Keccak256::new();
+            for chunk in data.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.clone().finalize(), expected, "{chunk_size}");
+
+            let mut output = [0; 32];
+            hasher.finalize_into(&mut output);
+            assert_eq!(output, expected.0);
+        }
+        assert_eq!(Keccak256::default().finalize(), keccak256([]));
+    }
+}
@@ -0,0 +1,516 @@
+//! Parsing and formatting of Ethereum denominations, such as ether and gwei.
+
+use crate::{BigIntConversionError, Sign, I256, U256};
+use alloc::string::{String, ToString};
+use core::{convert::Infallible, fmt, str::FromStr};
+
+/// Converts the input to a [`ParseUnits`] and multiplies it by the given
+/// unit's value in wei.
+///
+/// The input may be prefixed with `-`, in which case the result is a negative
+/// [`ParseUnits::I256`], and may contain a fractional part, with at most as
+/// many digits as the unit has decimals, not counting trailing zeros.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{
+///     utils::{parse_units, ParseUnits},
+///     I256, U256,
+/// };
+///
+/// let amount = parse_units("1.5", "gwei").unwrap();
+/// assert_eq!(amount, ParseUnits::U256(U256::from(1_500_000_000u64)));
+/// assert_eq!(U256::try_from(parse_units("42", 6).unwrap()).unwrap(), U256::from(42_000_000u64));
+///
+/// let delta = parse_units("-1.5", "gwei").unwrap();
+/// assert_eq!(delta, ParseUnits::I256(I256::try_from(-1_500_000_000i64).unwrap()));
+///
+/// assert!(parse_units("1.0000001", 6).is_err());
+/// ```
+pub fn parse_units<K, E>(amount: &str, units: K) -> Result<ParseUnits, UnitsError>
+where
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    let unit = units.try_into()?;
+    match amount.strip_prefix('-') {
+        Some(abs) => {
+            let abs = parse_abs(abs, unit)?;
+            // `-0` is not negative, so it would be reported as an overflow
+            if abs == U256::ZERO {
+                return Ok(ParseUnits::I256(I256::ZERO))
+            }
+            I256::checked_from_sign_and_abs(Sign::Negative, abs)
+                .map(ParseUnits::I256)
+                .ok_or(UnitsError::Overflow)
+        }
+        None => parse_abs(amount, unit).map(ParseUnits::U256),
+    }
+}
+
+/// Converts the input to a [`ParseUnits`] in wei, from an amount of ether.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::parse_ether, U256};
+///
+/// let amount = parse_ether("1").unwrap();
+/// assert_eq!(amount.get_absolute(), U256::from(10u64).pow(U256::from(18)));
+/// assert!(parse_ether("-0.000000001").unwrap().is_negative());
+/// ```
+pub fn parse_ether(eth: &str) -> Result<ParseUnits, UnitsError> {
+    parse_units(eth, Unit::Ether)
+}
+
+/// Formats the given amount of wei in the given unit, with all of the unit's
+/// decimals.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::format_units, I256, U256};
+///
+/// let amount = U256::from(1_500_000_000u64);
+/// assert_eq!(format_units(amount, "gwei").unwrap(), "1.500000000");
+/// assert_eq!(format_units(amount, 0).unwrap(), "1500000000");
+///
+/// let delta = I256::try_from(-1_500_000_000i64).unwrap();
+/// assert_eq!(format_units(delta, "gwei").unwrap(), "-1.500000000");
+/// ```
+pub fn format_units<T, K, E>(amount: T, units: K) -> Result<String, UnitsError>
+where
+    T: Into<ParseUnits>,
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    let amount = amount.into();
+    let unit = units.try_into()?;
+    let decimals = unit.decimals() as usize;
+    let sign = if amount.is_negative() { "-" } else { "" };
+    let abs = amount.get_absolute();
+    if decimals == 0 {
+        return Ok(format!("{sign}{abs}"))
+    }
+
+    let (int, frac) = abs.div_rem(unit.checked_wei().ok_or(UnitsError::Overflow)?);
+    // `U256`'s `Display` implementation ignores the width, so pad the string
+    let frac = frac.to_string();
+    Ok(format!("{sign}{int}.{frac:0>decimals$}"))
+}
+
+/// Formats the given amount of wei as ether, with 18 decimals.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::format_ether, U256};
+///
+/// assert_eq!(format_ether(U256::from(1_500_000_000u64)), "0.000000001500000000");
+/// ```
+pub fn format_ether<T: Into<ParseUnits>>(amount: T) -> String {
+    // `Ether` is always a valid unit
+    format_units(amount, Unit::Ether).unwrap()
+}
+
+/// Parses an unsigned decimal amount in the given unit.
+fn parse_abs(amount: &str, unit: Unit) -> Result<U256, UnitsError> {
+    let decimals = unit.decimals() as usize;
+    let (int, frac) = amount.split_once('.').unwrap_or((amount, ""));
+    let frac = frac.trim_end_matches('0');
+    if (int.is_empty() && frac.is_empty()) || frac.len() > decimals {
+        return Err(UnitsError::InvalidAmount)
+    }
+
+    // the fractional part is less than `10^decimals`, so this cannot overflow
+    let scale = U256::from(10u64).pow(U256::from(decimals - frac.len()));
+    let frac = parse_digits(frac)? * scale;
+    let int = parse_digits(int)?;
+    int.checked_mul(unit.checked_wei().ok_or(UnitsError::Overflow)?)
+        .and_then(|int| int.checked_add(frac))
+        .ok_or(UnitsError::Overflow)
+}
+
+/// Parses a non-empty string of decimal digits, or an empty string as zero.
+fn parse_digits(digits: &str) -> Result<U256, UnitsError> {
+    if digits.is_empty() {
+        return Ok(U256::ZERO)
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(UnitsError::InvalidAmount)
+    }
+    U256::from_str_radix(digits, 10).map_err(|_| UnitsError::Overflow)
+}
+
+/// The result of parsing an amount of units, which is signed if the amount
+/// was negative.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseUnits {
+    /// An unsigned amount.
+    U256(U256),
+    /// A signed amount, which is returned when the input is negative.
+    I256(I256),
+}
+
+impl From<U256> for ParseUnits {
+    #[inline]
+    fn from(value: U256) -> Self {
+        Self::U256(value)
+    }
+}
+
+impl From<I256> for ParseUnits {
+    #[inline]
+    fn from(value: I256) -> Self {
+        Self::I256(value)
+    }
+}
+
+impl TryFrom<ParseUnits> for U256 {
+    type Error = BigIntConversionError;
+
+    #[inline]
+    fn try_from(value: ParseUnits) -> Result<Self, Self::Error> {
+        match value {
+            ParseUnits::U256(value) => Ok(value),
+            ParseUnits::I256(value) => value.try_into(),
+        }
+    }
+}
+
+impl TryFrom<ParseUnits> for I256 {
+    type Error = BigIntConversionError;
+
+    #[inline]
+    fn try_from(value: ParseUnits) -> Result<Self, Self::Error> {
+        match value {
+            ParseUnits::U256(value) => value.try_into(),
+            ParseUnits::I256(value) => Ok(value),
+        }
+    }
+}
+
+impl fmt::Display for ParseUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U256(value) => value.fmt(f),
+            Self::I256(value) => value.fmt(f),
+        }
+    }
+}
+
+impl ParseUnits {
+    /// Returns `true` if the amount is negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        match self {
+            Self::U256(_) => false,
+            Self::I256(value) => value.is_negative(),
+        }
+    }
+
+    /// Returns the absolute value of the amount.
+    #[inline]
+    pub fn get_absolute(self) -> U256 {
+        match self {
+            Self::U256(value) => value,
+            Self::I256(value) => value.into_sign_and_abs().1,
+        }
+    }
+
+    /// Formats the amount in the given unit. See [`format_units`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the unit was constructed manually as [`Unit::Other`] with more
+    /// than [`Unit::MAX_DECIMALS`] decimals.
+    #[inline]
+    pub fn format_units(self, unit: Unit) -> String {
+        format_units(self, unit).unwrap()
+    }
+}
+
+/// An Ethereum denomination, expressed as a number of decimals relative to
+/// wei.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// Wei, the smallest denomination: `10^0` wei.
+    Wei,
+    /// Kwei: `10^3` wei.
+    Kwei,
+    /// Mwei: `10^6` wei.
+    Mwei,
+    /// Gwei: `10^9` wei.
+    Gwei,
+    /// Twei: `10^12` wei.
+    Twei,
+    /// Pwei: `10^15` wei.
+    Pwei,
+    /// Ether: `10^18` wei.
+    Ether,
+    /// Any other number of decimals, at most [`Unit::MAX_DECIMALS`].
+    Other(u8),
+}
+
+impl Unit {
+    /// The maximum number of decimals of a unit, as `10^77` is the largest
+    /// power of ten that fits in a [`U256`].
+    pub const MAX_DECIMALS: u8 = 77;
+
+    /// Creates a new unit with the given number of decimals, returning `None`
+    /// if it exceeds [`Unit::MAX_DECIMALS`].
+    #[inline]
+    pub const fn new(decimals: u8) -> Option<Self> {
+        Some(match decimals {
+            0 => Self::Wei,
+            3 => Self::Kwei,
+            6 => Self::Mwei,
+            9 => Self::Gwei,
+            12 => Self::Twei,
+            15 => Self::Pwei,
+            18 => Self::Ether,
+            decimals if decimals <= Self::MAX_DECIMALS => Self::Other(decimals),
+            _ => return None,
+        })
+    }
+
+    /// Returns the number of decimals of the unit.
+    #[inline]
+    pub const fn decimals(self) -> u8 {
+        match self {
+            Self::Wei => 0,
+            Self::Kwei => 3,
+            Self::Mwei => 6,
+            Self::Gwei => 9,
+            Self::Twei => 12,
+            Self::Pwei => 15,
+            Self::Ether => 18,
+            Self::Other(decimals) => decimals,
+        }
+    }
+
+    /// Returns the value of one unit in wei: `10^decimals`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the unit was constructed manually as [`Unit::Other`] with more
+    /// than [`Unit::MAX_DECIMALS`] decimals.
+    #[inline]
+    pub fn wei(self) -> U256 {
+        self.checked_wei().expect("unit has too many decimals")
+    }
+
+    /// Returns the value of one unit in wei: `10^decimals`, or `None` if it
+    /// does not fit in a [`U256`].
+    #[inline]
+    pub fn checked_wei(self) -> Option<U256> {
+        U256::from(10u64).checked_pow(U256::from(self.decimals()))
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wei => f.write_str("wei"),
+            Self::Kwei => f.write_str("kwei"),
+            Self::Mwei => f.write_str("mwei"),
+            Self::Gwei => f.write_str("gwei"),
+            Self::Twei => f.write_str("twei"),
+            Self::Pwei => f.write_str("pwei"),
+            Self::Ether => f.write_str("ether"),
+            Self::Other(decimals) => write!(f, "{decimals} decimals"),
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = UnitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "wei" => Self::Wei,
+            "kwei" | "babbage" => Self::Kwei,
+            "mwei" | "lovelace" => Self::Mwei,
+            "gwei" | "shannon" | "nano" => Self::Gwei,
+            "twei" | "szabo" | "micro" => Self::Twei,
+            "pwei" | "finney" | "milli" => Self::Pwei,
+            "ether" | "eth" => Self::Ether,
+            _ => return Err(UnitsError::InvalidUnit(s.to_string())),
+        })
+    }
+}
+
+impl TryFrom<u8> for Unit {
+    type Error = UnitsError;
+
+    #[inline]
+    fn try_from(decimals: u8) -> Result<Self, Self::Error> {
+        Self::new(decimals).ok_or_else(|| UnitsError::InvalidUnit(decimals.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Unit {
+    type Error = UnitsError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// The error type that is returned when parsing or formatting units.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnitsError {
+    /// The unit name or number of decimals is invalid.
+    InvalidUnit(String),
+
+    /// The amount is not a valid decimal number, or has more fractional
+    /// digits than the unit has decimals.
+    InvalidAmount,
+
+    /// The amount does not fit in a [`U256`].
+    Overflow,
+}
+
+impl From<Infallible> for UnitsError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitsError {}
+
+impl fmt::Display for UnitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUnit(unit) => write!(f, "invalid unit: {unit}"),
+            Self::InvalidAmount => f.write_str("invalid amount"),
+            Self::Overflow => f.write_str("amount does not fit in a U256"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_decimals() {
+        for decimals in 0..=Unit::MAX_DECIMALS {
+            let unit = Unit::new(decimals).unwrap();
+            assert_eq!(unit.decimals(), decimals);
+            assert_eq!(unit.wei(), U256::from(10u64).pow(U256::from(decimals)));
+        }
+        assert_eq!(Unit::new(Unit::MAX_DECIMALS + 1), None);
+        assert!(Unit::try_from(78).is_err());
+        assert_eq!(Unit::Other(78).checked_wei(), None);
+
+        assert_eq!(Unit::new(9), Some(Unit::Gwei));
+        assert_eq!("Gwei".parse::<Unit>().unwrap(), Unit::Gwei);
+        assert_eq!("eth".parse::<Unit>().unwrap(), Unit::Ether);
+        assert!("foo".parse::<Unit>().is_err());
+    }
+
+    #[test]
+    fn parse() {
+        let gwei = ParseUnits::U256(U256::from(1_000_000_000u64));
+        assert_eq!(parse_units("1", "gwei").unwrap(), gwei);
+        assert_eq!(parse_units("1.", "gwei").unwrap(), gwei);
+        assert_eq!(parse_units("1.000000000000", "gwei").unwrap(), gwei);
+        assert_eq!(
+            parse_units(".5", "gwei").unwrap().get_absolute(),
+            gwei.get_absolute() / U256::from(2)
+        );
+        assert_eq!(parse_units("0.000000001", 9).unwrap(), U256::from(1).into());
+        assert_eq!(
+            parse_units("123", Unit::Wei).unwrap(),
+            U256::from(123).into()
+        );
+        assert_eq!(
+            parse_ether("1.5").unwrap(),
+            U256::from(1_500_000_000_000_000_000u64).into()
+        );
+
+        let max = U256::MAX.to_string();
+        assert_eq!(parse_units(&max, 0).unwrap(), U256::MAX.into());
+        assert_eq!(parse_units(&max, 1), Err(UnitsError::Overflow));
+        assert_eq!(
+            parse_units(&format!("{max}0"), 0),
+            Err(UnitsError::Overflow)
+        );
+
+        for invalid in [
+            "",
+            ".",
+            "-",
+            "1.2.3",
+            "--1",
+            "+1",
+            "1e18",
+            " 1",
+            "0x10",
+            "1.0000000001",
+        ] {
+            assert_eq!(
+                parse_units(invalid, "gwei"),
+                Err(UnitsError::InvalidAmount),
+                "{invalid}"
+            );
+        }
+        assert!(matches!(
+            parse_units("1", "foo"),
+            Err(UnitsError::InvalidUnit(_))
+        ));
+        assert!(matches!(
+            parse_units("1", 78),
+            Err(UnitsError::InvalidUnit(_))
+        ));
+    }
+
+    #[test]
+    fn parse_negative() {
+        let amount = parse_units("-1.5", "gwei").unwrap();
+        assert!(amount.is_negative());
+        assert_eq!(amount, I256::try_from(-1_500_000_000i64).unwrap().into());
+        assert_eq!(amount.get_absolute(), U256::from(1_500_000_000u64));
+        assert!(U256::try_from(amount).is_err());
+
+        let zero = parse_units("-0", 0).unwrap();
+        assert!(!zero.is_negative());
+        assert_eq!(I256::try_from(zero).unwrap(), I256::ZERO);
+
+        let min = I256::MIN.to_string();
+        assert_eq!(parse_units(&min, 0).unwrap(), I256::MIN.into());
+        assert_eq!(parse_units(&min, 1), Err(UnitsError::Overflow));
+        assert_eq!(
+            parse_units(&format!("-{}", U256::MAX), 0),
+            Err(UnitsError::Overflow)
+        );
+    }
+
+    #[test]
+    fn format() {
+        let amount = U256::from(1_500_000_000_000_000_000u64);
+        assert_eq!(format_ether(amount), "1.500000000000000000");
+        assert_eq!(
+            format_units(amount, "gwei").unwrap(),
+            "1500000000.000000000"
+        );
+        assert_eq!(format_units(U256::from(1), 3).unwrap(), "0.001");
+        assert_eq!(format_units(U256::ZERO, Unit::Wei).unwrap(), "0");
+        assert_eq!(format_units(U256::MAX, 77).unwrap().len(), 79);
+        assert!(format_units(U256::MAX, 78).is_err());
+
+        for s in [
+            "0.000000000000000001",
+            "123.456000000000000000",
+            "1.000000000000000000",
+        ] {
+            assert_eq!(format_ether(parse_ether(s).unwrap()), s);
+            let negative = format!("-{s}");
+            assert_eq!(format_ether(parse_ether(&negative).unwrap()), negative);
+        }
+    }
+}
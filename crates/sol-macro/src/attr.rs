@@ -1,5 +1,6 @@
 use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
-use syn::{Attribute, Error, LitStr, Result};
+use proc_macro2::Ident;
+use syn::{Attribute, Error, LitStr, Path, Result};
 
 pub fn docs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
     attrs.iter().filter(|attr| attr.path().is_ident("doc"))
@@ -9,6 +10,18 @@ pub fn derives(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
     attrs.iter().filter(|attr| attr.path().is_ident("derive"))
 }
 
+/// Returns the Rust identifier specified with `#[sol(rename = "...")]`, if any.
+pub fn rename(attrs: &[Attribute]) -> Result<Option<Ident>> {
+    let (attrs, _) = SolAttrs::parse(attrs)?;
+    attrs
+        .rename
+        .map(|lit| {
+            syn::parse_str::<Ident>(&lit.value())
+                .map_err(|_| Error::new(lit.span(), "expected a valid identifier"))
+        })
+        .transpose()
+}
+
 /// `#[sol(...)]` attributes.
 ///
 /// When adding a new attribute:
@@ -20,13 +33,24 @@ pub fn derives(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct SolAttrs {
     pub all_derives: Option<()>,
-    // TODO: Implement
+    pub default: Option<()>,
+    pub serde: Option<()>,
+    pub arbitrary: Option<()>,
+    pub abi: Option<()>,
+    pub handler: Option<()>,
+    pub flatten: Option<()>,
     pub rename: Option<LitStr>,
     // TODO: Implement
     pub rename_all: Option<CasingStyle>,
 
     pub bytecode: Option<LitStr>,
     pub deployed_bytecode: Option<LitStr>,
+
+    /// `crate = "..."`
+    pub crate_path: Option<Path>,
+
+    /// `use <path>`, may be repeated
+    pub uses: Vec<Path>,
 }
 
 impl SolAttrs {
@@ -46,11 +70,18 @@ impl SolAttrs {
                     .ok_or_else(|| meta.error("expected ident"))?;
                 let s = path.to_string();
 
+                if s == "use" {
+                    this.uses.push(meta.input.parse()?);
+                    return Ok(())
+                }
+
                 macro_rules! match_ {
-                    ($($l:ident => $e:expr),* $(,)?) => {
+                    (@name $l:ident) => { stringify!($l) };
+                    (@name $l:ident $name:literal) => { $name };
+                    ($($l:ident $(as $name:literal)? => $e:expr),* $(,)?) => {
                         match s.as_str() {
                             $(
-                                stringify!($l) => if this.$l.is_some() {
+                                match_!(@name $l $($name)?) => if this.$l.is_some() {
                                     return Err(meta.error("duplicate attribute"))
                                 } else {
                                     this.$l = Some($e);
@@ -77,11 +108,19 @@ impl SolAttrs {
 
                 match_! {
                     all_derives => (),
+                    default => (),
+                    serde => (),
+                    arbitrary => (),
+                    abi => (),
+                    handler => (),
+                    flatten => (),
                     rename => lit()?,
                     rename_all => CasingStyle::from_lit(&lit()?)?,
 
                     bytecode => bytes()?,
                     deployed_bytecode => bytes()?,
+
+                    crate_path as "crate" => lit()?.parse::<Path>()?,
                 };
                 Ok(())
             })?;
@@ -210,6 +249,21 @@ mod tests {
 
             #[sol(all_derives)] => Ok(sol_attrs! { all_derives: () }),
             #[sol(all_derives)] #[sol(all_derives)] => Err("duplicate attribute"),
+            #[sol(default)] => Ok(sol_attrs! { default: () }),
+            #[sol(default)] #[sol(default)] => Err("duplicate attribute"),
+
+            #[sol(serde)] => Ok(sol_attrs! { serde: () }),
+            #[sol(all_derives, serde)] => Ok(sol_attrs! { all_derives: (), serde: () }),
+
+            #[sol(arbitrary)] => Ok(sol_attrs! { arbitrary: () }),
+            #[sol(arbitrary)] #[sol(arbitrary)] => Err("duplicate attribute"),
+
+            #[sol(abi)] => Ok(sol_attrs! { abi: () }),
+            #[sol(abi = "")] => Err("expected `,`"),
+            #[sol(handler)] => Ok(sol_attrs! { handler: () }),
+            #[sol(handler)] #[sol(handler)] => Err("duplicate attribute"),
+            #[sol(flatten)] => Ok(sol_attrs! { flatten: () }),
+            #[sol(serde = "")] => Err("expected `,`"),
 
             #[sol(rename = "foo")] => Ok(sol_attrs! { rename: parse_quote!("foo") }),
 
@@ -224,6 +278,14 @@ mod tests {
             #[sol(bytecode = "12 34")] => Err("expected hex literal"),
             #[sol(bytecode = "xyz")] => Err("expected hex literal"),
             #[sol(bytecode = "123")] => Err("expected even number of hex digits"),
+
+            #[sol(crate = "::my_crate::sol_types")] => Ok(sol_attrs! { crate_path: parse_quote!(::my_crate::sol_types) }),
+            #[sol(crate = "my crate")] => Err("unexpected token"),
+            #[sol(crate_path = "foo")] => Err("unknown `sol` attribute"),
+
+            #[sol(use crate::types::Order)] => Ok(SolAttrs { uses: vec![parse_quote!(crate::types::Order)], ..Default::default() }),
+            #[sol(use a::A, use b::B)] => Ok(SolAttrs { uses: vec![parse_quote!(a::A), parse_quote!(b::B)], ..Default::default() }),
+            #[sol(use = "a::A")] => Err("expected identifier"),
         }
     }
 }
@@ -0,0 +1,154 @@
+//! `arbitrary::Arbitrary` and `proptest::arbitrary::Arbitrary` implementations,
+//! enabled with `#[sol(arbitrary)]`.
+
+use super::{expand_type, ExpCtxt};
+use ast::Parameters;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+
+/// Expands the implementations for a struct with the given members and their
+/// Rust types.
+pub(super) fn expand_struct<I, M>(cx: &ExpCtxt<'_>, name: &Ident, fields: I) -> Option<TokenStream>
+where
+    I: IntoIterator<Item = (M, TokenStream)>,
+    M: ToTokens,
+{
+    cx.attrs.arbitrary?;
+
+    let (members, types): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+    let bindings: Vec<_> = (0..members.len()).map(|i| format_ident!("f{i}")).collect();
+
+    // `proptest` only implements `Arbitrary` for tuples of up to 12 elements,
+    // so we nest pairs instead: `(a, (b, (c, ())))`
+    let mut strategy = quote!(::alloy_sol_types::private::proptest::strategy::Just(()));
+    let mut pattern = quote!(());
+    for (binding, ty) in bindings.iter().zip(&types).rev() {
+        strategy = quote! {
+            (::alloy_sol_types::private::proptest::arbitrary::any::<#ty>(), #strategy)
+        };
+        pattern = quote!((#binding, #pattern));
+    }
+
+    Some(quote! {
+        #[automatically_derived]
+        impl<'a> ::alloy_sol_types::private::arbitrary::Arbitrary<'a> for #name {
+            fn arbitrary(
+                u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'a>,
+            ) -> ::alloy_sol_types::private::arbitrary::Result<Self> {
+                ::core::result::Result::Ok(Self {
+                    #(#members: <#types as ::alloy_sol_types::private::arbitrary::Arbitrary<'a>>::arbitrary(u)?,)*
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::proptest::arbitrary::Arbitrary for #name {
+            type Parameters = ();
+            type Strategy = ::alloy_sol_types::private::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                use ::alloy_sol_types::private::proptest::strategy::Strategy;
+                #strategy
+                    .prop_map(|#pattern| Self { #(#members: #bindings,)* })
+                    .boxed()
+            }
+        }
+    })
+}
+
+/// Expands the implementations for a struct generated from a list of
+/// parameters.
+pub(super) fn expand_params<P>(
+    cx: &ExpCtxt<'_>,
+    name: &Ident,
+    params: &Parameters<P>,
+    names: &[Ident],
+) -> Option<TokenStream> {
+    let fields = params.iter().zip(names).map(|(param, name)| {
+        let ty = expand_type(&param.ty);
+        (
+            name.clone(),
+            quote!(<#ty as ::alloy_sol_types::SolType>::RustType),
+        )
+    });
+    expand_struct(cx, name, fields)
+}
+
+/// Expands the implementations for an enum with only unit variants.
+pub(super) fn expand_unit_enum<'a, I>(
+    cx: &ExpCtxt<'_>,
+    name: &Ident,
+    variants: I,
+) -> Option<TokenStream>
+where
+    I: IntoIterator<Item = &'a Ident>,
+{
+    cx.attrs.arbitrary?;
+
+    let variants = variants.into_iter();
+    let values = quote!(&[#(Self::#variants),*]);
+    Some(quote! {
+        #[automatically_derived]
+        impl<'a> ::alloy_sol_types::private::arbitrary::Arbitrary<'a> for #name {
+            fn arbitrary(
+                u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'a>,
+            ) -> ::alloy_sol_types::private::arbitrary::Result<Self> {
+                u.choose(#values).copied()
+            }
+        }
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::proptest::arbitrary::Arbitrary for #name {
+            type Parameters = ();
+            type Strategy = ::alloy_sol_types::private::proptest::sample::Select<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                let values: &'static [Self] = #values;
+                ::alloy_sol_types::private::proptest::sample::select(values)
+            }
+        }
+    })
+}
+
+/// Expands the implementations for an enum whose variants each wrap a single
+/// type.
+pub(super) fn expand_newtype_enum(
+    cx: &ExpCtxt<'_>,
+    name: &Ident,
+    variants: &[Ident],
+    types: &[Ident],
+) -> Option<TokenStream> {
+    cx.attrs.arbitrary?;
+
+    let count = variants.len();
+    let indices = 0..count;
+    Some(quote! {
+        #[automatically_derived]
+        impl<'a> ::alloy_sol_types::private::arbitrary::Arbitrary<'a> for #name {
+            fn arbitrary(
+                u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'a>,
+            ) -> ::alloy_sol_types::private::arbitrary::Result<Self> {
+                ::core::result::Result::Ok(match u.choose_index(#count)? {
+                    #(#indices => Self::#variants(u.arbitrary()?),)*
+                    _ => ::core::unreachable!(),
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::private::proptest::arbitrary::Arbitrary for #name {
+            type Parameters = ();
+            type Strategy = ::alloy_sol_types::private::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                use ::alloy_sol_types::private::proptest::strategy::Strategy;
+                ::alloy_sol_types::private::proptest::strategy::Union::new([#(
+                    ::alloy_sol_types::private::proptest::arbitrary::any::<#types>()
+                        .prop_map(Self::#variants)
+                        .boxed(),
+                )*])
+                .boxed()
+            }
+        }
+    })
+}
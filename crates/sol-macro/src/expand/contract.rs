@@ -1,17 +1,25 @@
 //! [`ItemContract`] expansion.
 
-use super::{ty, ExpCtxt};
+use super::{arbitrary, expand_type, function, item_name, ty, var_def, var_names, ExpCtxt};
 use crate::{attr, utils::ExprArray};
-use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent};
+use ast::{
+    Item, ItemContract, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, Type,
+    VariableDeclaration, VariableDefinition, Visibility,
+};
 use heck::ToSnakeCase;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
+use std::collections::{hash_map::Entry, HashMap};
 use syn::{ext::IdentExt, parse_quote, Attribute, Result};
 
 /// Expands an [`ItemContract`]:
 ///
 /// ```ignore (pseudo-code)
 /// pub mod #name {
+///     pub struct #{name}Constructor {
+///         ...
+///     }
+///
 ///     pub enum #{name}Calls {
 ///         ...
 ///    }
@@ -23,7 +31,11 @@ use syn::{ext::IdentExt, parse_quote, Attribute, Result};
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenStream> {
     let ItemContract {
-        attrs, name, body, ..
+        attrs,
+        kind,
+        name,
+        body,
+        ..
     } = contract;
 
     let (sol_attrs, attrs) = crate::attr::SolAttrs::parse(attrs)?;
@@ -43,56 +55,277 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         }
     });
 
+    // public state variables have an implicit getter function
+    let getters = public_getters(cx, body);
+
+    let flatten = sol_attrs.flatten.or(cx.attrs.flatten).is_some();
+    let bases = if flatten {
+        base_contracts(cx, contract)
+    } else {
+        Vec::new()
+    };
+    let base_getters: Vec<Vec<ItemFunction>> = bases
+        .iter()
+        .map(|base| public_getters(cx, &base.body))
+        .collect();
+
     let mut functions = Vec::with_capacity(contract.body.len());
     let mut errors = Vec::with_capacity(contract.body.len());
     let mut events = Vec::with_capacity(contract.body.len());
 
+    let mut constructor = None;
+    let mut fallback = None;
+    let mut receive = None;
     let mut item_tokens = TokenStream::new();
     let d_attrs: Vec<Attribute> = attr::derives(&attrs).cloned().collect();
     for item in body {
         match item {
-            Item::Function(function) => functions.push(function),
+            Item::Function(function) if function.kind.is_constructor() => {
+                if let Some(other) = constructor.replace(function) {
+                    let mut err = syn::Error::new(function.span(), "constructor defined twice");
+                    err.combine(syn::Error::new(other.span(), "other declaration is here"));
+                    return Err(err)
+                }
+            }
+            Item::Function(function)
+                if function.kind.is_fallback() || function.kind.is_receive() =>
+            {
+                let (slot, name) = if function.kind.is_fallback() {
+                    (&mut fallback, "fallback")
+                } else {
+                    (&mut receive, "receive")
+                };
+                if let Some(other) = slot.replace(function) {
+                    let msg = format!("{name} function defined twice");
+                    let mut err = syn::Error::new(function.span(), msg);
+                    err.combine(syn::Error::new(other.span(), "other declaration is here"));
+                    return Err(err)
+                }
+            }
+            Item::Function(function) if is_external(cx, function) => functions.push(function),
             Item::Error(error) => errors.push(error),
             Item::Event(event) => events.push(event),
             _ => {}
         }
-        if !d_attrs.is_empty() {
-            item_tokens.extend(quote!(#(#d_attrs)*));
+
+        // the contract's derives apply to every type generated from its items
+        let mut item = item.clone();
+        if let Some(attrs) = item.attrs_mut() {
+            attrs.extend(d_attrs.iter().cloned());
         }
-        item_tokens.extend(cx.expand_item(item)?);
+        item_tokens.extend(match &item {
+            Item::Function(function) if function.kind.is_constructor() => {
+                function::expand_constructor(cx, name, function)?
+            }
+            Item::Function(function) if function.kind.is_function() && kind.is_library() => {
+                function::expand_library(cx, function)?
+            }
+            Item::Variable(var) => var_def::expand(cx, var, Some(contract))?,
+            item => cx.expand_item(item)?,
+        });
     }
 
-    let functions_enum = (functions.len() > 1).then(|| {
+    for getter in &getters {
+        let mut function = getter.clone();
+        function.attrs.extend(d_attrs.iter().cloned());
+        item_tokens.extend(function::expand(cx, &function)?);
+        functions.push(getter);
+    }
+
+    // the items of the base contracts are re-exported from their own module;
+    // a name that is declared in more than one base, such as with diamond
+    // inheritance, is only re-exported from the first one
+    let mut imported = Vec::new();
+    for (base, getters) in bases.iter().zip(&base_getters) {
+        let mut imports = Vec::new();
+        let mut import = |name: Ident| {
+            let taken = imported.contains(&name)
+                || body
+                    .iter()
+                    .any(|item| item.name().map_or(false, |n| n.0 == name));
+            if !taken {
+                imported.push(name.clone());
+                imports.push(name);
+            }
+            !taken
+        };
+        let base_functions = base
+            .body
+            .iter()
+            .filter_map(|item| match item {
+                Item::Function(function) if is_external(cx, function) => Some(function),
+                _ => None,
+            })
+            .chain(getters);
+        for function in base_functions {
+            let signature = cx.function_signature(function).to_string();
+            if functions
+                .iter()
+                .any(|f| cx.function_signature(f).to_string() == signature)
+            {
+                continue
+            }
+            let name = cx.function_name_ident(function);
+            let call_name = cx.raw_call_name(&name);
+            if functions
+                .iter()
+                .any(|f| cx.raw_call_name(cx.function_name_ident(f)) == call_name)
+            {
+                continue
+            }
+            if import(call_name) {
+                import(cx.raw_return_name(&name));
+                functions.push(function);
+            }
+        }
+        for item in &base.body {
+            match item {
+                Item::Function(function) if function.kind.is_fallback() => {
+                    fallback = fallback.or(Some(function))
+                }
+                Item::Function(function) if function.kind.is_receive() => {
+                    receive = receive.or(Some(function))
+                }
+                Item::Error(error) => {
+                    let signature = cx.error_signature(error).to_string();
+                    if !errors
+                        .iter()
+                        .any(|e| cx.error_signature(e).to_string() == signature)
+                        && import(item_name(&error.name, &error.attrs))
+                    {
+                        errors.push(error);
+                    }
+                }
+                Item::Event(event) => {
+                    let signature = cx.event_signature(event).to_string();
+                    let name = item_name(&event.name, &event.attrs);
+                    if !events
+                        .iter()
+                        .any(|e| cx.event_signature(e).to_string() == signature)
+                        && import(name.clone())
+                    {
+                        import(format_ident!("{name}Filter"));
+                        events.push(event);
+                    }
+                }
+                Item::Struct(ast::ItemStruct { name, .. })
+                | Item::Enum(ast::ItemEnum { name, .. })
+                | Item::Udt(ast::ItemUdt { name, .. }) => {
+                    import(name.0.clone());
+                }
+                _ => {}
+            }
+        }
+        let base_name = &base.name;
+        item_tokens.extend(quote! {
+            pub use super::#base_name::{#(#imports),*};
+        });
+    }
+
+    let abi = if sol_attrs.abi.or(cx.attrs.abi).is_some() {
+        Some(expand_abi(
+            cx,
+            name,
+            ContractItems {
+                constructor,
+                fallback,
+                receive,
+                functions: &functions,
+                errors: &errors,
+                events: &events,
+            },
+        )?)
+    } else {
+        None
+    };
+
+    // selectors which are only known at compile time cannot be checked
+    check_selector_collisions(
+        "function",
+        functions.iter().filter_map(|f| {
+            if kind.is_library() {
+                let selector = cx.library_function_selector(f)?;
+                Some((
+                    f.span(),
+                    cx.library_function_signature(f).to_string(),
+                    selector,
+                ))
+            } else {
+                let selector = cx.function_selector(f)?;
+                Some((f.span(), cx.function_signature(f).to_string(), selector))
+            }
+        }),
+    )?;
+    check_selector_collisions(
+        "error",
+        errors.iter().filter_map(|e| {
+            let selector = cx.error_selector(e)?;
+            Some((e.span(), cx.error_signature(e).to_string(), selector))
+        }),
+    )?;
+
+    let handler = sol_attrs
+        .handler
+        .or(cx.attrs.handler)
+        .map(|()| expand_handler(cx, name, &functions))
+        .transpose()?;
+
+    let functions_enum = (!functions.is_empty()).then(|| {
         let mut attrs = d_attrs.clone();
         let doc_str = format!("Container for all the `{name}` function calls.");
         attrs.push(parse_quote!(#[doc = #doc_str]));
-        CallLikeExpander::from_functions(cx, name, functions).expand(attrs)
+        CallLikeExpander::from_functions(cx, name, functions, kind.is_library()).expand(attrs)
     });
 
-    let errors_enum = (errors.len() > 1).then(|| {
+    let errors_enum = (!errors.is_empty()).then(|| {
         let mut attrs = d_attrs.clone();
         let doc_str = format!("Container for all the `{name}` custom errors.");
         attrs.push(parse_quote!(#[doc = #doc_str]));
         CallLikeExpander::from_errors(cx, name, errors).expand(attrs)
     });
 
-    let events_enum = (events.len() > 1).then(|| {
+    let events_enum = (!events.is_empty()).then(|| {
         let mut attrs = d_attrs;
         let doc_str = format!("Container for all the `{name}` events.");
         attrs.push(parse_quote!(#[doc = #doc_str]));
         CallLikeExpander::from_events(cx, name, events).expand_event(attrs)
     });
 
+    let has_fallback = fallback.is_some();
+    let has_receive = receive.is_some();
+
+    let constants = expand_state_variables(cx, contract, |var| var.attributes.is_constant());
+    let immutables = expand_state_variables(cx, contract, |var| var.attributes.is_immutable());
+
     let mod_attrs = attr::docs(&attrs);
     let tokens = quote! {
         #(#mod_attrs)*
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         pub mod #name {
+            // types declared outside of the contract, including in other
+            // invocations
+            #[allow(unused_imports)]
+            use super::*;
+
             #bytecode
             #deployed_bytecode
 
+            /// Whether the contract declares a `fallback` function.
+            pub const HAS_FALLBACK: bool = #has_fallback;
+            /// Whether the contract declares a `receive` function.
+            pub const HAS_RECEIVE: bool = #has_receive;
+
+            /// The `constant` state variables of the contract.
+            pub const CONSTANTS: &[::alloy_sol_types::StateVariable] = &[#(#constants),*];
+            /// The `immutable` state variables of the contract, in declaration
+            /// order.
+            pub const IMMUTABLES: &[::alloy_sol_types::StateVariable] = &[#(#immutables),*];
+
+            #abi
+
             #item_tokens
             #functions_enum
+            #handler
             #errors_enum
             #events_enum
         }
@@ -100,6 +333,274 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
     Ok(tokens)
 }
 
+/// The ABI items of a contract.
+#[cfg_attr(not(feature = "json"), allow(dead_code))]
+pub(super) struct ContractItems<'a> {
+    pub(super) constructor: Option<&'a ItemFunction>,
+    pub(super) fallback: Option<&'a ItemFunction>,
+    pub(super) receive: Option<&'a ItemFunction>,
+    pub(super) functions: &'a [&'a ItemFunction],
+    pub(super) errors: &'a [&'a ItemError],
+    pub(super) events: &'a [&'a ItemEvent],
+}
+
+#[cfg(feature = "json")]
+fn expand_abi(cx: &ExpCtxt<'_>, name: &SolIdent, items: ContractItems<'_>) -> Result<TokenStream> {
+    let abi = super::to_abi::generate(cx, &items);
+    let name = Ident::new("ABI", name.span());
+    Ok(quote! {
+        /// The JSON ABI of the contract.
+        pub const #name: &str = #abi;
+    })
+}
+
+#[cfg(not(feature = "json"))]
+fn expand_abi(_: &ExpCtxt<'_>, name: &SolIdent, _: ContractItems<'_>) -> Result<TokenStream> {
+    Err(syn::Error::new(
+        name.span(),
+        "the `abi` attribute requires the `json` feature",
+    ))
+}
+
+/// Expands the handler trait of a contract and the calls enum dispatcher,
+/// enabled with `#[sol(handler)]`:
+///
+/// ```ignore (pseudo-code)
+/// pub trait #{name}Handler {
+///     #(fn #function_name(&mut self, #(#argument_name: #argument_type),*) -> #{function_name}Return;)*
+/// }
+///
+/// impl #{name}Calls {
+///     pub fn dispatch<T: #{name}Handler + ?Sized>(self, handler: &mut T) -> Vec<u8> { ... }
+///
+///     pub fn dispatch_calldata<T: #{name}Handler + ?Sized>(handler: &mut T, data: &[u8], validate: bool) -> Result<Vec<u8>> { ... }
+/// }
+/// ```
+fn expand_handler(
+    cx: &ExpCtxt<'_>,
+    name: &SolIdent,
+    functions: &[&ItemFunction],
+) -> Result<TokenStream> {
+    let trait_name = format_ident!("{name}Handler");
+    let calls_name = format_ident!("{name}Calls");
+
+    let mut methods = Vec::with_capacity(functions.len());
+    let mut arms = Vec::with_capacity(functions.len());
+    for function in functions {
+        let method = cx.function_name_ident(function).0;
+        let call_name = cx.raw_call_name(&method);
+        let return_name = cx.raw_return_name(&method);
+
+        let names = var_names(&function.arguments)?;
+        let types = function.arguments.types().map(expand_type);
+        let docs = attr::docs(&function.attrs);
+        let signature_doc = format!("Handles a call to `{}`.", cx.function_signature(function));
+        methods.push(quote! {
+            #(#docs)*
+            #[doc = #signature_doc]
+            fn #method(
+                &mut self,
+                #(#names: <#types as ::alloy_sol_types::SolType>::RustType,)*
+            ) -> #return_name;
+        });
+        arms.push(quote! {
+            Self::#method(call) => {
+                let ret = handler.#method(#(call.#names,)*);
+                <#call_name as ::alloy_sol_types::SolCall>::encode_returns(&ret)
+            }
+        });
+    }
+
+    let doc = format!(
+        "Implements the `{name}` functions, for example to mock the contract.\n\n\
+         Calls are dispatched to the handler with [`{calls_name}::dispatch`] and \
+         [`{calls_name}::dispatch_calldata`]."
+    );
+    let dispatch = (!functions.is_empty()).then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #calls_name {
+                /// Invokes the handler method corresponding to this call, and
+                /// returns its ABI-encoded return values.
+                pub fn dispatch<T: #trait_name + ?::core::marker::Sized>(
+                    self,
+                    handler: &mut T,
+                ) -> ::alloy_sol_types::private::Vec<u8> {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+
+                /// Decodes the given calldata, including the selector, and
+                /// dispatches the call to the handler.
+                ///
+                /// See [`dispatch`](Self::dispatch).
+                pub fn dispatch_calldata<T: #trait_name + ?::core::marker::Sized>(
+                    handler: &mut T,
+                    data: &[u8],
+                    validate: bool,
+                ) -> ::alloy_sol_types::Result<::alloy_sol_types::private::Vec<u8>> {
+                    <Self as ::alloy_sol_types::SolInterface>::decode(data, validate)
+                        .map(|call| call.dispatch(handler))
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        #[doc = #doc]
+        pub trait #trait_name {
+            #(#methods)*
+        }
+
+        #dispatch
+    })
+}
+
+/// Returns an error for every pair of items that share the same selector, as
+/// these could not be told apart when decoding.
+fn check_selector_collisions<I>(kind: &str, items: I) -> Result<()>
+where
+    I: IntoIterator<Item = (Span, String, ExprArray<u8, 4>)>,
+{
+    let mut seen: HashMap<[u8; 4], (Span, String)> = HashMap::new();
+    let mut errors = Vec::new();
+    for (span, signature, selector) in items {
+        match seen.entry(selector.array) {
+            Entry::Occupied(entry) => {
+                let (other_span, other_signature) = entry.get();
+                let hex: String = selector.array.iter().map(|b| format!("{b:02x}")).collect();
+                let msg = format!(
+                    "{kind} selector collision: `{signature}` and `{other_signature}` \
+                     both have selector 0x{hex}"
+                );
+                let mut err = syn::Error::new(span, msg);
+                err.combine(syn::Error::new(*other_span, "other declaration is here"));
+                errors.push(err);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((span, signature));
+            }
+        }
+    }
+    match crate::utils::combine_errors(errors) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Expands the metadata of the state variables that match `filter`.
+fn expand_state_variables(
+    cx: &ExpCtxt<'_>,
+    contract: &ItemContract,
+    filter: impl Fn(&VariableDefinition) -> bool,
+) -> Vec<TokenStream> {
+    contract
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            Item::Variable(var) if filter(var) => Some(var),
+            _ => None,
+        })
+        .map(|var| {
+            let name = var.name.as_string();
+            let ty = var.ty.to_string();
+            let value = match var_def::initializer_value(cx, var, contract) {
+                Some(value) => quote!(::core::option::Option::Some(#value)),
+                None => quote!(::core::option::Option::None),
+            };
+            quote! {
+                ::alloy_sol_types::StateVariable {
+                    name: #name,
+                    ty: #ty,
+                    value: #value,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns `true` if the item is a function that can be called externally, and
+/// is thus part of the ABI.
+fn is_external(cx: &ExpCtxt<'_>, function: &ItemFunction) -> bool {
+    function.kind.is_function()
+        && !cx.is_storage_only(function)
+        && !matches!(
+            function.attributes.visibility(),
+            Some(Visibility::Internal(_) | Visibility::Private(_))
+        )
+}
+
+/// Returns the base contracts of a contract which are declared in the same
+/// macro invocation, recursively and without duplicates.
+fn base_contracts<'a>(cx: &'a ExpCtxt<'_>, contract: &ItemContract) -> Vec<&'a ItemContract> {
+    fn visit<'a>(cx: &'a ExpCtxt<'_>, contract: &ItemContract, bases: &mut Vec<&'a ItemContract>) {
+        let Some(inheritance) = &contract.inheritance else {
+            return
+        };
+        for modifier in &inheritance.inheritance {
+            let Some(Item::Contract(base)) = cx.try_get_item(&modifier.name) else {
+                continue
+            };
+            if base.name != contract.name && !bases.iter().any(|b| b.name == base.name) {
+                bases.push(base);
+                visit(cx, base, bases);
+            }
+        }
+    }
+
+    let mut bases = Vec::new();
+    visit(cx, contract, &mut bases);
+    bases.retain(|base| base.name != contract.name);
+    bases
+}
+
+/// Returns the getter functions of the public state variables of a contract.
+fn public_getters(cx: &ExpCtxt<'_>, body: &[Item]) -> Vec<ItemFunction> {
+    body.iter()
+        .filter_map(|item| match item {
+            Item::Variable(var)
+                if matches!(var.attributes.visibility(), Some(Visibility::Public(_))) =>
+            {
+                Some(expand_getter(cx, var))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the getter function of a public state variable.
+///
+/// Struct values are returned as their members, except for mappings and
+/// arrays, like the Solidity compiler does.
+fn expand_getter(cx: &ExpCtxt<'_>, var: &VariableDefinition) -> ItemFunction {
+    let mut getter = ItemFunction::new_getter(var.name.clone(), var.ty.clone());
+    if let Some(returns) = &mut getter.returns {
+        let strukt = match returns.returns.first() {
+            Some(VariableDeclaration {
+                ty: Type::Custom(name),
+                ..
+            }) if returns.returns.len() == 1 => match cx.try_get_item(name) {
+                Some(Item::Struct(strukt)) => Some(strukt),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(strukt) = strukt {
+            let mut members = Parameters::new();
+            for field in strukt.fields.iter() {
+                if !matches!(field.ty, Type::Mapping(_) | Type::Array(_)) {
+                    members.push(field.clone());
+                }
+            }
+            returns.returns = members;
+        }
+    }
+    if getter.is_void() {
+        getter.returns = None;
+    }
+    getter
+}
+
 // note that item impls generated here do not need to be wrapped in an anonymous
 // constant (`const _: () = { ... };`) because they are in one already
 
@@ -126,21 +627,27 @@ struct CallLikeExpander<'a> {
     cx: &'a ExpCtxt<'a>,
     name: Ident,
     variants: Vec<Ident>,
+    /// The types of the fields of all the variants, used to determine which
+    /// traits can be derived.
+    field_types: Vec<Type>,
     min_data_len: usize,
     trait_: Ident,
     data: CallLikeExpanderData,
 }
 
+/// The selectors are sorted at expansion time, or `None` if any of them is
+/// only known at compile time.
 enum CallLikeExpanderData {
     Function {
-        selectors: Vec<ExprArray<u8, 4>>,
+        selectors: Option<Vec<ExprArray<u8, 4>>>,
         types: Vec<Ident>,
     },
     Error {
-        selectors: Vec<ExprArray<u8, 4>>,
+        selectors: Option<Vec<ExprArray<u8, 4>>>,
     },
     Event {
-        selectors: Vec<ExprArray<u8, 32>>,
+        selectors: Option<Vec<ExprArray<u8, 32>>>,
+        anonymous: Vec<bool>,
     },
 }
 
@@ -149,6 +656,7 @@ impl<'a> CallLikeExpander<'a> {
         cx: &'a ExpCtxt<'a>,
         contract_name: &SolIdent,
         functions: Vec<&ItemFunction>,
+        library: bool,
     ) -> Self {
         let variants: Vec<_> = functions
             .iter()
@@ -157,13 +665,22 @@ impl<'a> CallLikeExpander<'a> {
 
         let types: Vec<_> = variants.iter().map(|name| cx.raw_call_name(name)).collect();
 
-        let mut selectors: Vec<_> = functions.iter().map(|f| cx.function_selector(f)).collect();
-        selectors.sort_unstable_by_key(|a| a.array);
+        let selectors = sorted_selectors(functions.iter().map(|f| {
+            if library {
+                cx.library_function_selector(f)
+            } else {
+                cx.function_selector(f)
+            }
+        }));
 
         Self {
             cx,
             name: format_ident!("{contract_name}Calls"),
             variants,
+            field_types: functions
+                .iter()
+                .flat_map(|f| f.arguments.types().cloned())
+                .collect(),
             min_data_len: functions
                 .iter()
                 .map(|function| ty::params_base_data_size(cx, &function.arguments))
@@ -175,13 +692,19 @@ impl<'a> CallLikeExpander<'a> {
     }
 
     fn from_errors(cx: &'a ExpCtxt<'a>, contract_name: &SolIdent, errors: Vec<&ItemError>) -> Self {
-        let mut selectors: Vec<_> = errors.iter().map(|e| cx.error_selector(e)).collect();
-        selectors.sort_unstable_by_key(|a| a.array);
+        let selectors = sorted_selectors(errors.iter().map(|e| cx.error_selector(e)));
 
         Self {
             cx,
             name: format_ident!("{contract_name}Errors"),
-            variants: errors.iter().map(|error| error.name.0.clone()).collect(),
+            variants: errors
+                .iter()
+                .map(|error| item_name(&error.name, &error.attrs))
+                .collect(),
+            field_types: errors
+                .iter()
+                .flat_map(|error| error.parameters.types().cloned())
+                .collect(),
             min_data_len: errors
                 .iter()
                 .map(|error| ty::params_base_data_size(cx, &error.parameters))
@@ -193,20 +716,35 @@ impl<'a> CallLikeExpander<'a> {
     }
 
     fn from_events(cx: &'a ExpCtxt<'a>, contract_name: &SolIdent, events: Vec<&ItemEvent>) -> Self {
-        let mut selectors: Vec<_> = events.iter().map(|e| cx.event_selector(e)).collect();
-        selectors.sort_unstable_by_key(|a| a.array);
+        // anonymous events do not emit their selector as the first topic
+        let selectors = sorted_selectors(
+            events
+                .iter()
+                .filter(|e| !e.is_anonymous())
+                .map(|e| cx.event_selector(e)),
+        );
 
         Self {
             cx,
             name: format_ident!("{contract_name}Events"),
-            variants: events.iter().map(|event| event.name.0.clone()).collect(),
+            variants: events
+                .iter()
+                .map(|event| item_name(&event.name, &event.attrs))
+                .collect(),
+            field_types: events
+                .iter()
+                .flat_map(|event| event.parameters.iter().map(|p| p.ty.clone()))
+                .collect(),
             min_data_len: events
                 .iter()
                 .map(|event| ty::params_base_data_size(cx, &event.params()))
                 .min()
                 .unwrap(),
             trait_: Ident::new("SolEvent", Span::call_site()),
-            data: CallLikeExpanderData::Event { selectors },
+            data: CallLikeExpanderData::Event {
+                selectors,
+                anonymous: events.iter().map(|e| e.is_anonymous()).collect(),
+            },
         }
     }
 
@@ -304,7 +842,73 @@ impl<'a> CallLikeExpander<'a> {
 
     fn expand_event(self, attrs: Vec<Attribute>) -> TokenStream {
         // TODO: SolInterface for events
-        self.generate_enum(attrs)
+        let def = self.generate_enum(attrs);
+        let Self {
+            name,
+            variants,
+            data: CallLikeExpanderData::Event { anonymous, .. },
+            ..
+        } = &self
+        else {
+            unreachable!()
+        };
+
+        // anonymous events cannot be identified by their first topic, and all
+        // the others have at least that one topic
+        let (variants, types): (Vec<_>, Vec<_>) = variants
+            .iter()
+            .zip(self.types())
+            .zip(anonymous)
+            .filter(|(_, &anonymous)| !anonymous)
+            .map(|(vt, _)| vt)
+            .unzip();
+        let name_s = name.to_string();
+
+        quote! {
+            #def
+
+            #[automatically_derived]
+            impl #name {
+                /// Decodes a log into one of the non-anonymous events of this
+                /// enum, dispatching on its first topic.
+                ///
+                /// See [`SolEvent::decode_raw_log`](::alloy_sol_types::SolEvent::decode_raw_log)
+                /// for more details.
+                pub fn decode_log<I, D>(
+                    topics: I,
+                    data: &[u8],
+                    validate: bool,
+                ) -> ::alloy_sol_types::Result<Self>
+                where
+                    I: ::core::iter::IntoIterator<Item = D>,
+                    D: ::core::convert::Into<::alloy_sol_types::token::WordToken>,
+                {
+                    let mut topics = topics
+                        .into_iter()
+                        .map(::core::convert::Into::<::alloy_sol_types::token::WordToken>::into)
+                        .peekable();
+                    let topic0 = match topics.peek() {
+                        ::core::option::Option::Some(topic) => topic.0,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(::alloy_sol_types::Error::TopicLengthMismatch {
+                                expected: 1,
+                                actual: 0,
+                            })
+                        }
+                    };
+                    #(
+                        if topic0 == <#types as ::alloy_sol_types::SolEvent>::SIGNATURE_HASH {
+                            return <#types as ::alloy_sol_types::SolEvent>::decode_raw_log(topics, data, validate)
+                                .map(Self::#variants);
+                        }
+                    )*
+                    ::core::result::Result::Err(::alloy_sol_types::Error::UnknownEventSignature {
+                        name: #name_s,
+                        hash: topic0,
+                    })
+                }
+            }
+        }
     }
 
     fn generate_enum(&self, mut attrs: Vec<Attribute>) -> TokenStream {
@@ -314,28 +918,49 @@ impl<'a> CallLikeExpander<'a> {
             data,
             ..
         } = self;
+        let types = self.types();
+        let trait_ = &self.trait_;
         let (selectors, selector_type) = match data {
             CallLikeExpanderData::Function { selectors, .. }
             | CallLikeExpanderData::Error { selectors } => {
-                (quote!(#(#selectors,)*), quote!([u8; 4]))
+                let consts = types
+                    .iter()
+                    .map(|ty| quote!(<#ty as ::alloy_sol_types::#trait_>::SELECTOR));
+                (expand_selectors(selectors, consts), quote!([u8; 4]))
             }
-            CallLikeExpanderData::Event { selectors } => {
-                (quote!(#(#selectors,)*), quote!([u8; 32]))
+            CallLikeExpanderData::Event {
+                selectors,
+                anonymous,
+            } => {
+                let consts = types
+                    .iter()
+                    .zip(anonymous)
+                    .filter(|(_, &anonymous)| !anonymous)
+                    .map(|(ty, _)| quote!(<#ty as ::alloy_sol_types::#trait_>::SIGNATURE_HASH.0));
+                (expand_selectors(selectors, consts), quote!([u8; 32]))
             }
         };
 
-        let types = self.types();
-        self.cx.type_derives(
-            &mut attrs,
-            types.iter().cloned().map(ast::Type::custom),
-            false,
-        );
+        self.cx.type_derives(&mut attrs, &self.field_types, false);
 
         let conversions = variants
             .iter()
             .zip(types)
             .map(|(v, t)| generate_variant_conversions(name, v, t));
         let methods = variants.iter().zip(types).map(generate_variant_methods);
+        let arbitrary = arbitrary::expand_newtype_enum(self.cx, name, variants, types);
+        let display = matches!(data, CallLikeExpanderData::Function { .. }).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::core::fmt::Display for #name {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {#(
+                            Self::#variants(inner) => ::core::fmt::Display::fmt(inner, f),
+                        )*}
+                    }
+                }
+            }
+        });
 
         quote! {
             #(#attrs)*
@@ -345,13 +970,16 @@ impl<'a> CallLikeExpander<'a> {
 
             #(#conversions)*
 
+            #arbitrary
+            #display
+
             #[automatically_derived]
             impl #name {
                 /// All the selectors of this enum.
                 ///
                 /// Note that the selectors might not be in the same order as the
                 /// variants, as they are sorted instead of ordered by definition.
-                pub const SELECTORS: &'static [#selector_type] = &[#selectors];
+                pub const SELECTORS: &'static [#selector_type] = #selectors;
 
                 #(#methods)*
             }
@@ -359,6 +987,35 @@ impl<'a> CallLikeExpander<'a> {
     }
 }
 
+/// Sorts the selectors of an interface enum, if they are all known at
+/// expansion time.
+fn sorted_selectors<const N: usize>(
+    selectors: impl IntoIterator<Item = Option<ExprArray<u8, N>>>,
+) -> Option<Vec<ExprArray<u8, N>>> {
+    let mut selectors = selectors.into_iter().collect::<Option<Vec<_>>>()?;
+    selectors.sort_unstable_by_key(|a| a.array);
+    Some(selectors)
+}
+
+/// Expands the `SELECTORS` of an interface enum, which are sorted at compile
+/// time from the selector constants of its variants if they are not known at
+/// expansion time.
+fn expand_selectors<const N: usize>(
+    selectors: &Option<Vec<ExprArray<u8, N>>>,
+    consts: impl Iterator<Item = TokenStream>,
+) -> TokenStream {
+    if let Some(selectors) = selectors {
+        return quote!(&[#(#selectors,)*])
+    }
+    let consts: Vec<_> = consts.collect();
+    let count = consts.len();
+    quote! {{
+        const SELECTORS: [[u8; #N]; #count] =
+            ::alloy_sol_types::private::sort_selectors([#(#consts),*]);
+        &SELECTORS
+    }}
+}
+
 fn generate_variant_conversions(name: &Ident, variant: &Ident, ty: &Ident) -> TokenStream {
     quote! {
         #[automatically_derived]
@@ -1,6 +1,6 @@
 //! [`ItemEnum`] expansion.
 
-use super::ExpCtxt;
+use super::{arbitrary, ExpCtxt};
 use ast::ItemEnum;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -58,6 +58,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         quote! { expect("unreachable") }
     };
 
+    let arbitrary = arbitrary::expand_unit_enum(cx, &name.0, variants.iter().map(|v| &v.0));
+
     let uint8 = quote!(::alloy_sol_types::sol_data::Uint<8>);
     let uint8_st = quote!(<#uint8 as ::alloy_sol_types::SolType>);
 
@@ -73,6 +75,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
+            #arbitrary
+
             #[automatically_derived]
             impl ::core::convert::From<#name> for u8 {
                 #[inline]
@@ -114,12 +118,18 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                 type TokenType<'a> = #uint8_st::TokenType<'a>;
 
                 const ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::ENCODED_SIZE;
+                const PACKED_ENCODED_SIZE: ::core::option::Option<usize> = #uint8_st::PACKED_ENCODED_SIZE;
 
                 #[inline]
                 fn sol_type_name() -> ::alloy_sol_types::private::Cow<'static, str> {
                     #uint8_st::sol_type_name()
                 }
 
+                #[inline]
+                fn fmt_value(rust: &Self::RustType, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(rust.as_u8(), f)
+                }
+
                 #[inline]
                 fn type_check(token: &Self::TokenType<'_>) -> ::alloy_sol_types::Result<()> {
                     #uint8_st::type_check(token)?;
@@ -144,6 +154,30 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                 fn encode_packed_to(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
                     out.push(*rust as u8);
                 }
+
+                #[inline]
+                fn decode_packed(data: &[u8]) -> ::alloy_sol_types::Result<Self::RustType> {
+                    let value = #uint8_st::decode_packed(data)?;
+                    <Self as ::core::convert::TryFrom<u8>>::try_from(value)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::EventTopic for #name {
+                #[inline]
+                fn topic_preimage_length(rust: &Self::RustType) -> usize {
+                    <#uint8 as ::alloy_sol_types::EventTopic>::topic_preimage_length(rust.as_u8())
+                }
+
+                #[inline]
+                fn encode_topic_preimage(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                    <#uint8 as ::alloy_sol_types::EventTopic>::encode_topic_preimage(rust.as_u8(), out)
+                }
+
+                #[inline]
+                fn encode_topic(rust: &Self::RustType) -> ::alloy_sol_types::token::WordToken {
+                    <#uint8 as ::alloy_sol_types::EventTopic>::encode_topic(rust.as_u8())
+                }
             }
 
             #[automatically_derived]
@@ -151,6 +185,12 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                 const COUNT: usize = #count;
             }
 
+            #[automatically_derived]
+            impl ::alloy_sol_types::private::SolTypeInfo for #name {
+                const ABI_TYPE: &'static str = "uint8";
+                const EIP712_TYPE: &'static str = "uint8";
+            }
+
             #[automatically_derived]
             impl #name {
                 #[allow(unsafe_code, clippy::inline_always)]
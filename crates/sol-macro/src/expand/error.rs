@@ -1,6 +1,8 @@
 //! [`ItemError`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, ty::expand_tokenize_func, ExpCtxt};
+use super::{
+    arbitrary, expand_fields, expand_from_into_tuples, ty::expand_tokenize_func, var_names, ExpCtxt,
+};
 use ast::ItemError;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -25,29 +27,34 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
         ..
     } = error;
     cx.assert_resolved(params)?;
+    let names = var_names(params)?;
 
+    let name = crate::attr::rename(attrs)?.unwrap_or_else(|| name.0.clone());
     let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, params, true);
 
-    let tokenize_impl = expand_tokenize_func(params.iter());
+    let tokenize_impl = expand_tokenize_func(params.iter(), &names);
 
     let signature = cx.error_signature(error);
-    let selector = crate::utils::selector(&signature);
+    let selector = super::function::expand_selector(&signature, quote!(SolError));
 
-    let converts = expand_from_into_tuples(&name.0, params);
-    let fields = expand_fields(params);
+    let converts = expand_from_into_tuples(&name, params, &names);
+    let arbitrary = arbitrary::expand_params(cx, &name, params, &names);
+    let fields = expand_fields(cx, params, &names);
     let tokens = quote! {
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #name {
-            #(pub #fields,)*
+            #(#fields,)*
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
             #converts
 
+            #arbitrary
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolError for #name {
                 type Parameters<'a> = UnderlyingSolTuple<'a>;
@@ -1,10 +1,10 @@
 //! [`ItemEvent`] expansion.
 
-use super::{anon_name, expand_tuple_types, expand_type, ExpCtxt};
+use super::{arbitrary, expand_tuple_types, expand_type, item_name, param_names, ExpCtxt};
 use crate::expand::ty::expand_event_tokenize_func;
-use ast::{EventParameter, ItemEvent, SolIdent};
-use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use ast::{EventParameter, ItemEvent, Type};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
 use syn::Result;
 
 /// Expands an [`ItemEvent`]:
@@ -22,19 +22,37 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let ItemEvent { name, attrs, .. } = event;
     let params = event.params();
 
-    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
-    cx.derives(&mut attrs, &params, true);
-
     cx.assert_resolved(&params)?;
     event.assert_valid()?;
+    assert_indexed_resolved(cx, event)?;
+    let names = param_names(
+        event
+            .parameters
+            .iter()
+            .map(|p| (p.name.as_ref(), &p.attrs[..])),
+    )?;
+    crate::attr::rename(attrs)?;
 
-    let signature = cx.signature(name.as_string(), &params);
-    let selector = crate::utils::event_selector(&signature);
+    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    cx.derives(&mut attrs, &params, true);
+
+    let signature = cx.event_signature(event);
+    let selector = match cx.event_selector(event) {
+        Some(selector) => quote!(::alloy_sol_types::private::B256::new(#selector)),
+        None => quote! {
+            ::alloy_sol_types::private::B256::new(::alloy_sol_types::private::const_keccak256(
+                <Self as ::alloy_sol_types::SolEvent>::SIGNATURE.as_bytes(),
+            ))
+        },
+    };
+    let name = &item_name(name, &event.attrs);
     let anonymous = event.is_anonymous();
 
     // prepend the first topic if not anonymous
     let first_topic = (!anonymous).then(|| quote!(::alloy_sol_types::sol_data::FixedBytes<32>));
-    let topic_list = event.indexed_params().map(expand_event_topic_type);
+    let topic_list = event
+        .indexed_params()
+        .map(|p| expand_event_topic_type(cx, p));
     let topic_list = first_topic.into_iter().chain(topic_list);
 
     let (data_tuple, _) = expand_tuple_types(event.non_indexed_params().map(|p| &p.ty));
@@ -42,10 +60,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     // skip first topic if not anonymous, which is the hash of the signature
     let mut topic_i = !anonymous as usize;
     let mut data_i = 0usize;
-    let new_impl = event.parameters.iter().enumerate().map(|(i, p)| {
-        let name = anon_name((i, p.name.as_ref()));
+    let new_impl = event.parameters.iter().zip(&names).map(|(p, name)| {
         let param;
-        if p.is_indexed() {
+        if indexed_as_hash(cx, p) {
+            let i = syn::Index::from(topic_i);
+            param = quote!(topics.#i.into());
+            topic_i += 1;
+        } else if p.is_indexed() {
             let i = syn::Index::from(topic_i);
             param = quote!(topics.#i);
             topic_i += 1;
@@ -57,28 +78,40 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         quote!(#name: #param)
     });
 
-    let topic_tuple_names = event
-        .indexed_params()
-        .map(|p| p.name.as_ref())
-        .enumerate()
-        .map(anon_name);
+    // parameters are named by their index in the whole parameter list
+    let indexed_params = || {
+        event
+            .parameters
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_indexed())
+    };
+
+    let topic_tuple_names = indexed_params().map(|(i, p)| {
+        let name = &names[i];
+        if indexed_as_hash(cx, p) {
+            quote!(self.#name.0)
+        } else {
+            quote!(self.#name.clone())
+        }
+    });
 
     let topics_impl = if anonymous {
-        quote! {(#(self.#topic_tuple_names.clone(),)*)}
+        quote! {(#(#topic_tuple_names,)*)}
     } else {
-        quote! {(Self::SIGNATURE_HASH.into(), #(self.#topic_tuple_names.clone(),)*)}
+        quote! {(Self::SIGNATURE_HASH.into(), #(#topic_tuple_names,)*)}
     };
 
     let encode_first_topic =
         (!anonymous).then(|| quote!(::alloy_sol_types::token::WordToken(Self::SIGNATURE_HASH)));
 
-    let encode_topics_impl = event.indexed_params().enumerate().map(|(i, p)| {
-        let name = anon_name((i, p.name.as_ref()));
+    let encode_topics_impl = indexed_params().map(|(i, p)| {
+        let name = &names[i];
         let ty = expand_type(&p.ty);
 
-        if p.indexed_as_hash() {
+        if indexed_as_hash(cx, p) {
             quote! {
-                <::alloy_sol_types::sol_data::FixedBytes<32> as ::alloy_sol_types::EventTopic>::encode_topic(&self.#name)
+                ::alloy_sol_types::token::WordToken(self.#name)
             }
         } else {
             quote! {
@@ -90,10 +123,24 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let fields = event
         .parameters
         .iter()
-        .enumerate()
-        .map(|(i, p)| expand_event_topic_field(i, p, p.name.as_ref()));
+        .zip(&names)
+        .map(|(p, name)| expand_event_topic_field(cx, name, p));
+
+    let arbitrary_fields = event.parameters.iter().zip(&names).map(|(p, name)| {
+        let ty = if indexed_as_hash(cx, p) {
+            quote!(::alloy_sol_types::private::B256)
+        } else {
+            let ty = expand_type(&p.ty);
+            quote!(<#ty as ::alloy_sol_types::SolType>::RustType)
+        };
+        (name.clone(), ty)
+    });
+    let arbitrary = arbitrary::expand_struct(cx, name, arbitrary_fields);
 
-    let tokenize_body_impl = expand_event_tokenize_func(event.parameters.iter());
+    let tokenize_body_impl = expand_event_tokenize_func(event.parameters.iter(), &names);
+
+    let filter_name = format_ident!("{name}Filter");
+    let filter = expand_filter(event, &names, name, &filter_name);
 
     let encode_topics_impl = encode_first_topic
         .into_iter()
@@ -105,11 +152,24 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         pub struct #name {
-            #(pub #fields,)*
+            #(#fields,)*
         }
 
+        #filter
+
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
+            #arbitrary
+
+            impl #name {
+                /// Returns a builder for the topics used to filter this
+                /// event's logs by its indexed parameters.
+                #[inline]
+                pub fn filter() -> #filter_name {
+                    ::core::default::Default::default()
+                }
+            }
+
             impl ::alloy_sol_types::SolEvent for #name {
                 type DataTuple<'a> = #data_tuple;
                 type DataToken<'a> = <Self::DataTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
@@ -117,8 +177,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                 type TopicList = (#(#topic_list,)*);
 
                 const SIGNATURE: &'static str = #signature;
-                const SIGNATURE_HASH: ::alloy_sol_types::private::B256 =
-                    ::alloy_sol_types::private::B256::new(#selector);
+                const SIGNATURE_HASH: ::alloy_sol_types::private::B256 = #selector;
 
                 const ANONYMOUS: bool = #anonymous;
 
@@ -160,30 +219,157 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     Ok(tokens)
 }
 
-fn expand_event_topic_type(param: &EventParameter) -> TokenStream {
+/// Expands the topic filter builder of an event:
+///
+/// ```ignore (pseudo-code)
+/// pub struct #filter_name {
+///     #(pub #indexed_parameter_name: Option<B256>,)*
+/// }
+///
+/// impl #filter_name {
+///     #(pub fn #indexed_parameter_name(self, value: #parameter_type) -> Self;)*
+///
+///     pub fn topics(&self) -> [Option<B256>; 4];
+/// }
+/// ```
+fn expand_filter(
+    event: &ItemEvent,
+    param_names: &[Ident],
+    name: &Ident,
+    filter_name: &Ident,
+) -> TokenStream {
+    let (names, types): (Vec<_>, Vec<_>) = event
+        .parameters
+        .iter()
+        .zip(param_names)
+        .filter(|(p, _)| p.is_indexed())
+        .map(|(p, name)| (name.clone(), expand_type(&p.ty)))
+        .unzip();
+
+    let first_topic = (!event.is_anonymous())
+        .then(|| quote!(::core::option::Option::Some(#name::SIGNATURE_HASH)));
+    let padding = names.len() + first_topic.is_some() as usize..4;
+    let topics = first_topic
+        .into_iter()
+        .chain(names.iter().map(|name| quote!(self.#name)))
+        .chain(padding.map(|_| quote!(::core::option::Option::None)));
+
+    let doc = format!(
+        "Topic filter for the [`{name}`] event, built with [`{name}::filter`].\n\n\
+         Each indexed parameter matches any value unless set."
+    );
+    let method_docs = event
+        .parameters
+        .iter()
+        .filter(|p| p.is_indexed())
+        .zip(&names)
+        .map(|(p, rust_name)| {
+            let param = p
+                .name
+                .as_ref()
+                .map_or_else(|| rust_name.to_string(), |name| name.as_string());
+            format!("Only matches logs whose indexed `{param}` parameter is `value`.")
+        });
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        pub struct #filter_name {
+            #(
+                #[doc = "The expected topic of the indexed parameter, if any."]
+                pub #names: ::core::option::Option<::alloy_sol_types::private::B256>,
+            )*
+        }
+
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            use ::alloy_sol_types::SolEvent as _;
+
+            impl #filter_name {
+                #(
+                    #[doc = #method_docs]
+                    #[inline]
+                    pub fn #names(
+                        mut self,
+                        value: <#types as ::alloy_sol_types::SolType>::RustType,
+                    ) -> Self {
+                        self.#names = ::core::option::Option::Some(
+                            <#types as ::alloy_sol_types::EventTopic>::encode_topic(&value).0,
+                        );
+                        self
+                    }
+                )*
+
+                /// Returns the topics to filter logs by, in the format used by
+                /// `eth_getLogs`. `None` matches any topic.
+                #[inline]
+                pub fn topics(&self) -> [::core::option::Option<::alloy_sol_types::private::B256>; 4] {
+                    [#(#topics),*]
+                }
+            }
+        };
+    }
+}
+
+/// Indexed parameters are stored either as their value or as a hash, which is
+/// not known for types declared in other invocations.
+fn assert_indexed_resolved(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<()> {
+    let errors = event
+        .indexed_params()
+        .filter_map(|p| match &p.ty {
+            Type::Custom(name) if !cx.custom_types.contains_key(name.last_tmp()) => {
+                let msg = "indexed parameters cannot refer to types declared in another `sol!` \
+                           invocation";
+                Some(syn::Error::new(name.span(), msg))
+            }
+            _ => None,
+        })
+        .collect();
+    match crate::utils::combine_errors(errors) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Returns `true` if the indexed parameter is stored in the topics as the
+/// Keccak-256 hash of its encoding, resolving custom types.
+fn indexed_as_hash(cx: &ExpCtxt<'_>, param: &EventParameter) -> bool {
+    param.is_indexed()
+        && match &param.ty {
+            Type::Custom(name) => !cx.custom_type(name).is_one_word(),
+            ty => !ty.is_one_word(),
+        }
+}
+
+fn expand_event_topic_type(cx: &ExpCtxt<'_>, param: &EventParameter) -> TokenStream {
     debug_assert!(param.is_indexed());
-    if param.is_abi_dynamic() {
+    if indexed_as_hash(cx, param) {
         quote_spanned! {param.ty.span()=> ::alloy_sol_types::sol_data::FixedBytes<32> }
     } else {
         expand_type(&param.ty)
     }
 }
 
-fn expand_event_topic_field(
-    i: usize,
-    param: &EventParameter,
-    name: Option<&SolIdent>,
-) -> TokenStream {
-    let name = anon_name((i, name));
+fn expand_event_topic_field(cx: &ExpCtxt<'_>, name: &Ident, param: &EventParameter) -> TokenStream {
+    let docs = crate::attr::docs(&param.attrs);
 
-    if param.indexed_as_hash() {
+    if indexed_as_hash(cx, param) {
+        let doc = format!(
+            "The Keccak-256 hash of the indexed `{}` parameter, as stored in the topics.",
+            param.ty
+        );
         quote! {
-            #name: <::alloy_sol_types::sol_data::FixedBytes<32> as ::alloy_sol_types::SolType>::RustType
+            #(#docs)*
+            #[doc = #doc]
+            pub #name: ::alloy_sol_types::private::B256
         }
     } else {
         let ty = expand_type(&param.ty);
+        let serde = cx.serde_field_attrs(&param.ty);
         quote! {
-            #name: <#ty as ::alloy_sol_types::SolType>::RustType
+            #(#docs)*
+            #serde
+            pub #name: <#ty as ::alloy_sol_types::SolType>::RustType
         }
     }
 }
@@ -1,12 +1,12 @@
 //! [`ItemFunction`] expansion.
 
 use super::{
-    expand_fields, expand_from_into_tuples, expand_from_into_unit, expand_tuple_types,
-    ty::expand_tokenize_func, ExpCtxt,
+    arbitrary, expand_fields, expand_from_into_tuples, expand_from_into_unit, expand_tuple_types,
+    expand_type, ty::expand_tokenize_func, var_names, ConstStr, ExpCtxt,
 };
-use ast::ItemFunction;
-use proc_macro2::TokenStream;
-use quote::quote;
+use ast::{ItemFunction, Mutability, Parameters, SolIdent, Visibility};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
 use syn::Result;
 
 /// Expands an [`ItemFunction`]:
@@ -26,30 +26,84 @@ use syn::Result;
 /// }
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenStream> {
+    if cx.is_storage_only(function) {
+        return Ok(TokenStream::new())
+    }
+    assert_resolved(cx, function)?;
+    expand_with_signature(cx, function, cx.function_signature(function))
+}
+
+/// Expands an [`ItemFunction`] declared inside of a library, whose signature
+/// follows different rules than the contract ABI.
+///
+/// See [`expand`] for more information.
+pub(super) fn expand_library(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenStream> {
+    if cx.is_storage_only(function) {
+        return Ok(TokenStream::new())
+    }
+    assert_resolved(cx, function)?;
+    // structs and enums are referred to by name in library signatures, which
+    // types from other invocations do not provide
+    let signature = cx.library_function_signature(function);
+    if signature.literal().is_none() {
+        let msg = "library functions cannot refer to types declared in another `sol!` invocation";
+        return Err(syn::Error::new(function.span(), msg))
+    }
+    expand_with_signature(cx, function, signature)
+}
+
+/// Signatures can only be computed once all the parameter types are resolved.
+fn assert_resolved(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<()> {
+    cx.assert_resolved(&function.arguments)?;
+    if let Some(returns) = &function.returns {
+        cx.assert_resolved(&returns.returns)?;
+    }
+    Ok(())
+}
+
+/// Expands the selector of a function or error, which is computed at compile
+/// time if its signature refers to types declared in other invocations.
+pub(super) fn expand_selector(signature: &ConstStr, trait_: TokenStream) -> TokenStream {
+    match signature.literal() {
+        Some(signature) => crate::utils::selector(signature).into_token_stream(),
+        None => quote! {
+            ::alloy_sol_types::private::const_selector(<Self as ::alloy_sol_types::#trait_>::SIGNATURE)
+        },
+    }
+}
+
+fn expand_with_signature(
+    cx: &ExpCtxt<'_>,
+    function: &ItemFunction,
+    signature: ConstStr,
+) -> Result<TokenStream> {
     let ItemFunction {
         attrs,
         arguments,
         returns,
         ..
     } = function;
-    cx.assert_resolved(arguments)?;
-    if let Some(returns) = returns {
-        cx.assert_resolved(&returns.returns)?;
-    }
 
+    crate::attr::rename(attrs)?;
     let (_sol_attrs, mut call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let call_names = var_names(arguments)?;
+    let return_names = match returns {
+        Some(returns) => var_names(&returns.returns)?,
+        None => Vec::new(),
+    };
     let mut return_attrs = call_attrs.clone();
     cx.derives(&mut call_attrs, arguments, true);
-    if let Some(returns) = returns {
-        cx.derives(&mut return_attrs, &returns.returns, true);
+    match returns {
+        Some(returns) => cx.derives(&mut return_attrs, &returns.returns, true),
+        None => cx.derives(&mut return_attrs, [], true),
     }
 
     let call_name = cx.call_name(function);
     let return_name = cx.return_name(function);
 
-    let call_fields = expand_fields(arguments);
+    let call_fields = expand_fields(cx, arguments, &call_names);
     let return_fields = if let Some(returns) = returns {
-        expand_fields(&returns.returns).collect::<Vec<_>>()
+        expand_fields(cx, &returns.returns, &return_names).collect::<Vec<_>>()
     } else {
         vec![]
     };
@@ -61,29 +115,38 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
         quote! { () }
     };
 
-    let converts = expand_from_into_tuples(&call_name, arguments);
+    let converts = expand_from_into_tuples(&call_name, arguments, &call_names);
     let return_converts = returns
         .as_ref()
-        .map(|returns| expand_from_into_tuples(&return_name, &returns.returns))
+        .map(|returns| expand_from_into_tuples(&return_name, &returns.returns, &return_names))
         .unwrap_or_else(|| expand_from_into_unit(&return_name));
+    let arbitrary = arbitrary::expand_params(cx, &call_name, arguments, &call_names);
+    let return_arbitrary = match returns {
+        Some(returns) => {
+            arbitrary::expand_params(cx, &return_name, &returns.returns, &return_names)
+        }
+        None => arbitrary::expand_struct(cx, &return_name, Vec::<(Ident, TokenStream)>::new()),
+    };
 
-    let signature = cx.function_signature(function);
-    let selector = crate::utils::selector(&signature);
-    let tokenize_impl = expand_tokenize_func(arguments.iter());
+    let selector = expand_selector(&signature, quote!(SolCall));
+    let tokenize_impl = expand_tokenize_func(arguments.iter(), &call_names);
+    let display_impl = expand_display(&function.name().as_string(), arguments, &call_names);
+    let state_mutability = expand_state_mutability(function);
+    let visibility = expand_visibility(function);
 
     let tokens = quote! {
         #(#call_attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #call_name {
-            #(pub #call_fields,)*
+            #(#call_fields,)*
         }
 
         #(#return_attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #return_name {
-            #(pub #return_fields,)*
+            #(#return_fields,)*
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
@@ -91,6 +154,16 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
             { #converts }
             { #return_converts }
 
+            #arbitrary
+            #return_arbitrary
+
+            #[automatically_derived]
+            impl ::core::fmt::Display for #call_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #display_impl
+                }
+            }
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolCall for #call_name {
                 type Arguments<'a> = #call_tuple;
@@ -103,6 +176,10 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
 
                 const SIGNATURE: &'static str = #signature;
                 const SELECTOR: [u8; 4] = #selector;
+                const STATE_MUTABILITY: ::alloy_sol_types::StateMutability =
+                    ::alloy_sol_types::StateMutability::#state_mutability;
+                const VISIBILITY: ::alloy_sol_types::Visibility =
+                    ::alloy_sol_types::Visibility::#visibility;
 
                 fn new<'a>(tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType) -> Self {
                     tuple.into()
@@ -120,3 +197,111 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     };
     Ok(tokens)
 }
+
+fn expand_state_mutability(function: &ItemFunction) -> Ident {
+    let s = match function.attributes.mutability() {
+        Some(Mutability::Pure(_)) => "Pure",
+        Some(Mutability::View(_) | Mutability::Constant(_)) => "View",
+        Some(Mutability::Payable(_)) => "Payable",
+        None => "NonPayable",
+    };
+    Ident::new(s, Span::call_site())
+}
+
+fn expand_visibility(function: &ItemFunction) -> Ident {
+    let s = match function.attributes.visibility() {
+        Some(Visibility::External(_)) | None => "External",
+        Some(Visibility::Public(_)) => "Public",
+        Some(Visibility::Internal(_)) => "Internal",
+        Some(Visibility::Private(_)) => "Private",
+    };
+    Ident::new(s, Span::call_site())
+}
+
+/// Formats a call as `name(arg0, arg1, ...)`, with each argument formatted
+/// with `SolType::fmt_value`.
+fn expand_display<P>(name: &str, arguments: &Parameters<P>, names: &[Ident]) -> TokenStream {
+    let args = arguments
+        .iter()
+        .zip(names)
+        .enumerate()
+        .map(|(i, (arg, field))| {
+            let ty = expand_type(&arg.ty);
+            let sep = (i > 0).then(|| quote!(f.write_str(", ")?;));
+            quote! {
+                #sep
+                <#ty as ::alloy_sol_types::SolType>::fmt_value(&self.#field, f)?;
+            }
+        });
+    quote! {
+        f.write_str(#name)?;
+        f.write_str("(")?;
+        #(#args)*
+        f.write_str(")")
+    }
+}
+
+/// Expands the constructor of a contract:
+///
+/// ```ignore (pseudo-code)
+/// pub struct #{contract_name}Constructor {
+///     #(pub #argument_name: #argument_type,)*
+/// }
+///
+/// impl SolConstructor for #{contract_name}Constructor {
+///     ...
+/// }
+/// ```
+pub(super) fn expand_constructor(
+    cx: &ExpCtxt<'_>,
+    contract_name: &SolIdent,
+    constructor: &ItemFunction,
+) -> Result<TokenStream> {
+    let ItemFunction {
+        attrs, arguments, ..
+    } = constructor;
+    cx.assert_resolved(arguments)?;
+
+    let names = var_names(arguments)?;
+
+    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    cx.derives(&mut attrs, arguments, true);
+
+    let name = format_ident!("{contract_name}Constructor");
+    let fields = expand_fields(cx, arguments, &names);
+    let tuple = expand_tuple_types(arguments.types()).0;
+    let converts = expand_from_into_tuples(&name, arguments, &names);
+    let arbitrary = arbitrary::expand_params(cx, &name, arguments, &names);
+    let tokenize_impl = expand_tokenize_func(arguments.iter(), &names);
+
+    let tokens = quote! {
+        #(#attrs)*
+        #[allow(non_camel_case_types, non_snake_case)]
+        #[derive(Clone)]
+        pub struct #name {
+            #(#fields,)*
+        }
+
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            { #converts }
+
+            #arbitrary
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolConstructor for #name {
+                type Arguments<'a> = #tuple;
+                type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+
+                fn new<'a>(tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType) -> Self {
+                    tuple.into()
+                }
+
+                fn tokenize(&self) -> Self::Token<'_> {
+                    #tokenize_impl
+                }
+            }
+        };
+    };
+    Ok(tokens)
+}
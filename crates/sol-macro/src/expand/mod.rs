@@ -5,24 +5,29 @@ use crate::{
     utils::ExprArray,
 };
 use ast::{
-    File, Item, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, SolPath, Type,
-    VariableDeclaration, Visit,
+    File, FunctionKind, Item, ItemContract, ItemError, ItemEvent, ItemFunction, Parameters,
+    SolIdent, SolPath, Type, VariableDeclaration, Visit,
 };
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::{format_ident, quote, IdentFragment};
-use std::{borrow::Borrow, collections::HashMap, fmt::Write};
-use syn::{parse_quote, Attribute, Error, Result};
+use proc_macro2::{Group, Ident, Spacing, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, IdentFragment, ToTokens};
+use std::{borrow::Borrow, collections::HashMap};
+use syn::{parse_quote, Attribute, Error, Path, Result};
 
 mod ty;
 pub use ty::expand_type;
+use ty::ConstStr;
 
+mod arbitrary;
 mod contract;
 mod r#enum;
 mod error;
 mod event;
 mod function;
 mod r#struct;
+#[cfg(feature = "json")]
+mod to_abi;
 mod udt;
+mod var_def;
 
 /// The limit for the number of times to resolve a type.
 const RESOLVE_LIMIT: usize = 8;
@@ -36,10 +41,17 @@ struct ExpCtxt<'ast> {
     all_items: Vec<&'ast Item>,
     custom_types: HashMap<SolIdent, Type>,
 
-    /// `name => functions`
-    functions: HashMap<String, Vec<&'ast ItemFunction>>,
+    /// `(contract_name, name) => functions`
+    functions: HashMap<(Option<&'ast SolIdent>, String), Vec<&'ast ItemFunction>>,
+    /// The contract which is currently being visited.
+    current_contract: Option<&'ast SolIdent>,
+    /// `struct_enum_or_udt_name => contract_name`
+    item_scopes: HashMap<&'ast SolIdent, &'ast SolIdent>,
     /// `function_signature => new_name`
     function_overloads: HashMap<String, String>,
+    /// The types declared in other invocations, from `#[sol(use ...)]` on the
+    /// file or on any item.
+    uses: Vec<Path>,
 
     attrs: SolAttrs,
     ast: &'ast File,
@@ -52,7 +64,10 @@ impl<'ast> ExpCtxt<'ast> {
             all_items: Vec::new(),
             custom_types: HashMap::new(),
             functions: HashMap::new(),
+            current_contract: None,
+            item_scopes: HashMap::new(),
             function_overloads: HashMap::new(),
+            uses: Vec::new(),
             attrs: SolAttrs::default(),
             ast,
         }
@@ -69,6 +84,7 @@ impl<'ast> ExpCtxt<'ast> {
         if self.all_items.len() > 1 {
             self.resolve_custom_types()?;
             self.mk_overloads_map()?;
+            self.check_renames()?;
         }
 
         for item in &self.ast.items {
@@ -81,6 +97,26 @@ impl<'ast> ExpCtxt<'ast> {
             };
             tokens.extend(t);
         }
+        if let Some(root) = self.flatten_root() {
+            // flattened contracts re-export the items of their bases through
+            // `super`, which cannot name items declared in a block, so wrap
+            // the expansion in a module to give it a nameable root
+            tokens = quote! {
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                mod #root {
+                    #[allow(unused_imports)]
+                    use super::*;
+
+                    #tokens
+                }
+                #[allow(unused_imports)]
+                pub use #root::*;
+            };
+        }
+        if let Some(path) = &self.attrs.crate_path {
+            tokens = replace_crate_path(tokens, &path.to_token_stream());
+        }
         Ok(tokens)
     }
 
@@ -90,13 +126,20 @@ impl<'ast> ExpCtxt<'ast> {
             Item::Enum(enumm) => r#enum::expand(self, enumm),
             Item::Error(error) => error::expand(self, error),
             Item::Event(event) => event::expand(self, event),
-            Item::Function(function) => function::expand(self, function),
+            Item::Function(function) => match function.kind {
+                FunctionKind::Function(_) => function::expand(self, function),
+                FunctionKind::Constructor(_) => Err(Error::new(
+                    function.span(),
+                    "constructors can only be declared inside of a contract",
+                )),
+                // not part of the ABI
+                FunctionKind::Fallback(_)
+                | FunctionKind::Receive(_)
+                | FunctionKind::Modifier(_) => Ok(TokenStream::new()),
+            },
             Item::Struct(strukt) => r#struct::expand(self, strukt),
             Item::Udt(udt) => udt::expand(self, udt),
-            Item::Variable(_) => {
-                // TODO: Expand getter function for public variables
-                Ok(TokenStream::new())
-            }
+            Item::Variable(var) => var_def::expand(self, var, None),
             Item::Import(_) | Item::Pragma(_) | Item::Using(_) => Ok(TokenStream::new()),
         }
     }
@@ -104,8 +147,23 @@ impl<'ast> ExpCtxt<'ast> {
 
 // resolve
 impl ExpCtxt<'_> {
+    /// Returns the name of the module that wraps the expansion if any contract
+    /// re-exports the items of its base contracts with `#[sol(flatten)]`.
+    fn flatten_root(&self) -> Option<Ident> {
+        self.ast.items.iter().find_map(|item| match item {
+            Item::Contract(contract) if contract.inheritance.is_some() => {
+                let flatten = self.attrs.flatten.is_some()
+                    || attr::SolAttrs::parse(&contract.attrs)
+                        .map_or(false, |(attrs, _)| attrs.flatten.is_some());
+                flatten.then(|| format_ident!("__{}_flatten", contract.name.0))
+            }
+            _ => None,
+        })
+    }
+
     fn parse_file_attributes(&mut self) -> Result<()> {
         let (attrs, others) = attr::SolAttrs::parse(&self.ast.attrs)?;
+        self.uses.extend(attrs.uses.iter().cloned());
         self.attrs = attrs;
 
         let errs = others
@@ -216,7 +274,7 @@ impl ExpCtxt<'_> {
                     errors.push(err);
                 }
 
-                overloads_map.insert(self.function_signature(function), new_name);
+                overloads_map.insert(self.function_signature(function).to_string(), new_name);
             }
         }
 
@@ -227,32 +285,112 @@ impl ExpCtxt<'_> {
             Err(crate::utils::combine_errors(errors).unwrap())
         }
     }
+
+    /// Checks that `#[sol(rename = "...")]` attributes do not make the Rust
+    /// names of two items in the same scope collide.
+    fn check_renames(&self) -> Result<()> {
+        let mut errors = Vec::new();
+        self.check_renames_in(&self.ast.items, &mut errors);
+        for item in &self.ast.items {
+            if let Item::Contract(contract) = item {
+                self.check_renames_in(&contract.body, &mut errors);
+            }
+        }
+        match crate::utils::combine_errors(errors) {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn check_renames_in(&self, items: &[Item], errors: &mut Vec<Error>) {
+        // `(rust_name, span, renamed)`
+        let mut names = Vec::with_capacity(items.len());
+        for item in items {
+            let Some(name) = item.name() else { continue };
+            if matches!(item, Item::Function(function) if !function.kind.is_function()) {
+                continue
+            }
+            // invalid attributes are reported when expanding the item
+            let rename = match item {
+                Item::Function(_) | Item::Error(_) | Item::Event(_) => item
+                    .attrs()
+                    .and_then(|attrs| SolAttrs::parse(attrs).ok())
+                    .and_then(|(attrs, _)| attrs.rename)
+                    .filter(|lit| syn::parse_str::<Ident>(&lit.value()).is_ok()),
+                _ => None,
+            };
+            let rust_name = match (item, &rename) {
+                (Item::Function(function), _) => {
+                    self.raw_call_name(self.function_name(function)).to_string()
+                }
+                (_, Some(rename)) => rename.value(),
+                _ => name.as_string(),
+            };
+            let span = rename
+                .as_ref()
+                .map_or_else(|| name.span(), |lit| lit.span());
+            names.push((rust_name, span, rename.is_some()));
+        }
+
+        for (i, a) in names.iter().enumerate() {
+            for b in &names[i + 1..] {
+                // collisions between non-renamed items are reported elsewhere
+                if a.0 != b.0 || !(a.2 || b.2) {
+                    continue
+                }
+                let (this, other) = if b.2 { (b, a) } else { (a, b) };
+                let msg = format!("renamed item collides with another item named `{}`", this.0);
+                let mut err = Error::new(this.1, msg);
+
+                let msg = "other declaration is here";
+                let note = Error::new(other.1, msg);
+
+                err.combine(note);
+                errors.push(err);
+            }
+        }
+    }
 }
 
 impl<'ast> Visit<'ast> for ExpCtxt<'ast> {
     fn visit_item(&mut self, item: &'ast Item) {
         self.all_items.push(item);
+        // invalid attributes are reported when expanding the item
+        if let Some(Ok((attrs, _))) = item.attrs().map(|attrs| SolAttrs::parse(attrs)) {
+            self.uses.extend(attrs.uses);
+        }
+        if let (
+            Some(contract),
+            Item::Struct(ast::ItemStruct { name, .. })
+            | Item::Enum(ast::ItemEnum { name, .. })
+            | Item::Udt(ast::ItemUdt { name, .. }),
+        ) = (self.current_contract, item)
+        {
+            self.item_scopes.insert(name, contract);
+        }
         ast::visit::visit_item(self, item);
     }
 
+    fn visit_item_contract(&mut self, contract: &'ast ItemContract) {
+        // functions are only overloaded within the same contract
+        let prev = self.current_contract.replace(&contract.name);
+        ast::visit::visit_item_contract(self, contract);
+        self.current_contract = prev;
+    }
+
     fn visit_item_function(&mut self, function: &'ast ItemFunction) {
-        self.functions
-            .entry(function.name().as_string())
-            .or_default()
-            .push(function);
+        if function.kind.is_function() {
+            self.functions
+                .entry((self.current_contract, function.name().as_string()))
+                .or_default()
+                .push(function);
+        }
         ast::visit::visit_item_function(self, function);
     }
 }
 
 // utils
 impl ExpCtxt<'_> {
-    fn get_item(&self, name: &SolPath) -> &Item {
-        match self.try_get_item(name) {
-            Some(item) => item,
-            None => panic!("unresolved item: {name}"),
-        }
-    }
-
     fn try_get_item(&self, name: &SolPath) -> Option<&Item> {
         let name = name.last_tmp();
         self.all_items
@@ -268,18 +406,54 @@ impl ExpCtxt<'_> {
         }
     }
 
-    /// Returns the name of the function, adjusted for overloads.
+    /// Returns `true` if the type contains a mapping, resolving custom types.
+    /// Such types can only be used in storage, and are not ABI types.
+    fn has_mapping(&self, ty: &Type) -> bool {
+        let mut found = false;
+        ty.visit(|ty| match ty {
+            Type::Mapping(_) => found = true,
+            Type::Custom(name) => {
+                if let Some(resolved) = self.custom_types.get(name.last_tmp()) {
+                    resolved.visit(|ty| found |= matches!(ty, Type::Mapping(_)));
+                }
+            }
+            _ => {}
+        });
+        found
+    }
+
+    /// Returns `true` if the function has parameters or return values which
+    /// contain mappings. Such functions can only be called internally.
+    fn is_storage_only(&self, function: &ItemFunction) -> bool {
+        let returns = function.returns.iter().flat_map(|r| r.returns.types());
+        function
+            .arguments
+            .types()
+            .chain(returns)
+            .any(|ty| self.has_mapping(ty))
+    }
+
+    /// Returns the name of the function, adjusted for renames and overloads.
+    ///
+    /// Invalid `#[sol(rename = "...")]` attributes are ignored here, as they
+    /// are reported when expanding the function itself.
     fn function_name(&self, function: &ItemFunction) -> String {
-        let sig = self.function_signature(function);
+        if let Ok(Some(name)) = attr::rename(&function.attrs) {
+            return name.to_string()
+        }
+        let sig = self.function_signature(function).to_string();
         match self.function_overloads.get(&sig) {
             Some(name) => name.clone(),
             None => function.name().as_string(),
         }
     }
 
-    /// Returns the name of the function, adjusted for overloads.
+    /// Returns the name of the function, adjusted for renames and overloads.
     fn function_name_ident(&self, function: &ItemFunction) -> SolIdent {
-        let sig = self.function_signature(function);
+        if let Ok(Some(name)) = attr::rename(&function.attrs) {
+            return SolIdent::new_spanned(&name.to_string(), function.name().span())
+        }
+        let sig = self.function_signature(function).to_string();
         match self.function_overloads.get(&sig) {
             Some(name) => SolIdent::new_spanned(name, function.name().span()),
             None => function.name().clone(),
@@ -304,48 +478,99 @@ impl ExpCtxt<'_> {
         self.raw_return_name(function_name)
     }
 
+    /// Returns the signature of a function, error or event.
+    ///
+    /// Types declared in other `sol!` invocations are only known at compile
+    /// time, in which case so is the signature.
     fn signature<'a, I: IntoIterator<Item = &'a VariableDeclaration>>(
         &self,
         mut name: String,
         params: I,
-    ) -> String {
+    ) -> ConstStr {
         name.push('(');
-        let mut first = true;
-        for param in params {
-            if !first {
-                name.push(',');
+        let mut signature = ConstStr::new(name);
+        for (i, param) in params.into_iter().enumerate() {
+            if i > 0 {
+                signature.push_str(",");
             }
-            write!(name, "{}", ty::TypePrinter::new(self, &param.ty)).unwrap();
-            first = false;
+            ty::TypePrinter::new(self, &param.ty).write(&mut signature);
         }
-        name.push(')');
-        name
+        signature.push_str(")");
+        signature
     }
 
-    fn function_signature(&self, function: &ItemFunction) -> String {
+    fn function_signature(&self, function: &ItemFunction) -> ConstStr {
         self.signature(function.name().as_string(), &function.arguments)
     }
 
-    fn function_selector(&self, function: &ItemFunction) -> ExprArray<u8, 4> {
-        crate::utils::selector(self.function_signature(function))
+    /// Returns the selector of a function, if it is known at expansion time.
+    fn function_selector(&self, function: &ItemFunction) -> Option<ExprArray<u8, 4>> {
+        self.function_signature(function)
+            .literal()
+            .map(crate::utils::selector)
+    }
+
+    /// Returns the signature of a library function.
+    ///
+    /// Unlike in the contract ABI, structs and enums are referred to by their
+    /// fully qualified name, and storage pointers are suffixed with
+    /// ` storage`.
+    ///
+    /// Reference: <https://docs.soliditylang.org/en/latest/contracts.html#function-signatures-and-selectors-in-libraries>
+    fn library_function_signature(&self, function: &ItemFunction) -> ConstStr {
+        let mut name = function.name().as_string();
+        name.push('(');
+        let mut signature = ConstStr::new(name);
+        for (i, param) in function.arguments.iter().enumerate() {
+            if i > 0 {
+                signature.push_str(",");
+            }
+            ty::TypePrinter::new_library(self, &param.ty).write(&mut signature);
+            if let Some(ast::Storage::Storage(_)) = param.storage {
+                signature.push_str(" storage");
+            }
+        }
+        signature.push_str(")");
+        signature
+    }
+
+    fn library_function_selector(&self, function: &ItemFunction) -> Option<ExprArray<u8, 4>> {
+        self.library_function_signature(function)
+            .literal()
+            .map(crate::utils::selector)
+    }
+
+    /// Returns the fully qualified name of a struct, enum or UDVT, as used in
+    /// library function signatures and JSON ABI internal types.
+    fn qualified_name(&self, name: &SolPath) -> String {
+        if name.len() > 1 {
+            return name.to_string()
+        }
+        let name = name.last_tmp();
+        match self.item_scopes.get(name) {
+            Some(contract) => format!("{contract}.{name}"),
+            None => name.as_string(),
+        }
     }
 
-    fn error_signature(&self, error: &ItemError) -> String {
+    fn error_signature(&self, error: &ItemError) -> ConstStr {
         self.signature(error.name.as_string(), &error.parameters)
     }
 
-    fn error_selector(&self, error: &ItemError) -> ExprArray<u8, 4> {
-        crate::utils::selector(self.error_signature(error))
+    fn error_selector(&self, error: &ItemError) -> Option<ExprArray<u8, 4>> {
+        self.error_signature(error)
+            .literal()
+            .map(crate::utils::selector)
     }
 
-    #[allow(dead_code)]
-    fn event_signature(&self, event: &ItemEvent) -> String {
+    fn event_signature(&self, event: &ItemEvent) -> ConstStr {
         self.signature(event.name.as_string(), &event.params())
     }
 
-    #[allow(dead_code)]
-    fn event_selector(&self, event: &ItemEvent) -> ExprArray<u8, 32> {
-        crate::utils::event_selector(self.event_signature(event))
+    fn event_selector(&self, event: &ItemEvent) -> Option<ExprArray<u8, 32>> {
+        self.event_signature(event)
+            .literal()
+            .map(crate::utils::event_selector)
     }
 
     /// Extends `attrs` with all possible derive attributes for the given type
@@ -375,17 +600,35 @@ impl ExpCtxt<'_> {
         self.type_derives(attrs, params.into_iter().map(|p| &p.ty), derive_default)
     }
 
-    fn type_derives<T, I>(&self, attrs: &mut Vec<Attribute>, types: I, mut derive_default: bool)
+    fn type_derives<T, I>(&self, attrs: &mut Vec<Attribute>, types: I, derive_default: bool)
     where
         I: IntoIterator<Item = T>,
         T: Borrow<Type>,
     {
-        if self.attrs.all_derives.is_none() {
+        self.type_derives_except(attrs, types, derive_default, &[])
+    }
+
+    /// Like [`type_derives`](Self::type_derives), but skips the builtin traits
+    /// in `derived`, which are already derived by other means.
+    fn type_derives_except<T, I>(
+        &self,
+        attrs: &mut Vec<Attribute>,
+        types: I,
+        mut derive_default: bool,
+        derived: &[&str],
+    ) where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Type>,
+    {
+        self.serde_derives(attrs);
+        let all_derives = self.attrs.all_derives.is_some();
+        derive_default &= all_derives || self.attrs.default.is_some();
+        if !all_derives && !derive_default {
             return
         }
 
         let mut derives = Vec::with_capacity(5);
-        let mut derive_others = true;
+        let mut derive_others = all_derives;
         for ty in types {
             if !derive_default && !derive_others {
                 break
@@ -399,11 +642,72 @@ impl ExpCtxt<'_> {
         if derive_others {
             derives.extend(["Debug", "PartialEq", "Eq", "Hash"]);
         }
+        derives.retain(|derive| !derived.contains(derive));
+        if derives.is_empty() {
+            return
+        }
         let derives = derives.iter().map(|s| Ident::new(s, Span::call_site()));
         attrs.push(parse_quote! { #[derive(#(#derives),*)] });
     }
 
-    /// Returns an error if any of the types in the parameters are unresolved.
+    /// Returns the path to the `alloy-sol-types` crate as a string.
+    ///
+    /// Used in attributes which take a path as a string literal, like
+    /// `serde`'s, since these are not rewritten by [`replace_crate_path`].
+    fn crate_path_str(&self) -> String {
+        match &self.attrs.crate_path {
+            Some(path) => path.to_token_stream().to_string().replace(' ', ""),
+            None => "::alloy_sol_types".into(),
+        }
+    }
+
+    /// Extends `attrs` with the `serde` derive attributes, if `#[sol(serde)]`
+    /// was passed.
+    fn serde_derives(&self, attrs: &mut Vec<Attribute>) {
+        if self.attrs.serde.is_none() {
+            return
+        }
+        let serde = format!("{}::private::serde", self.crate_path_str());
+        attrs.push(parse_quote! {
+            #[derive(
+                ::alloy_sol_types::private::serde::Serialize,
+                ::alloy_sol_types::private::serde::Deserialize,
+            )]
+        });
+        attrs.push(parse_quote!(#[serde(crate = #serde)]));
+    }
+
+    /// Returns the `serde` attributes of a struct field of the given type, if
+    /// `#[sol(serde)]` was passed.
+    ///
+    /// Byte arrays are serialized as hex strings, instead of arrays of numbers.
+    fn serde_field_attrs(&self, ty: &Type) -> Option<TokenStream> {
+        self.attrs.serde?;
+        match ty {
+            Type::Bytes(_) | Type::FixedBytes(..) => {
+                let with = format!("{}::private::serde_hex", self.crate_path_str());
+                Some(quote!(#[serde(with = #with)]))
+            }
+            Type::Custom(name) => match self.try_get_item(name) {
+                Some(Item::Udt(udt)) => self.serde_field_attrs(&udt.ty),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `name` refers to a type declared in another
+    /// invocation, given with `#[sol(use ...)]`.
+    fn is_used(&self, name: &SolPath) -> bool {
+        let name = name.last_tmp();
+        self.uses
+            .iter()
+            .any(|used| used.segments.last().map_or(false, |s| s.ident == name.0))
+    }
+
+    /// Returns an error if any of the types in the parameters, or in the
+    /// structs they refer to, are unresolved and were not given with
+    /// `#[sol(use ...)]`.
     ///
     /// Provides a better error message than an `unwrap` or `expect` when we
     /// know beforehand that we will be needing types to be resolved.
@@ -414,12 +718,20 @@ impl ExpCtxt<'_> {
         let mut errors = Vec::new();
         for param in params {
             param.ty.visit(|ty| {
-                if let Type::Custom(name) = ty {
-                    if !self.custom_types.contains_key(name.last_tmp()) {
-                        let e = syn::Error::new(name.span(), "unresolved type");
-                        errors.push(e);
+                let Type::Custom(name) = ty else { return };
+                let Some(resolved) = self.custom_types.get(name.last_tmp()) else {
+                    if !self.is_used(name) {
+                        errors.push(syn::Error::new(name.span(), "unresolved type"));
                     }
-                }
+                    return
+                };
+                resolved.visit(|ty| {
+                    let Type::Custom(inner) = ty else { return };
+                    if !self.custom_types.contains_key(inner.last_tmp()) && !self.is_used(inner) {
+                        let msg = format!("unresolved type `{inner}` in `{name}`");
+                        errors.push(syn::Error::new(name.span(), msg));
+                    }
+                });
             });
         }
         if errors.is_empty() {
@@ -427,7 +739,11 @@ impl ExpCtxt<'_> {
         } else {
             let mut e = crate::utils::combine_errors(errors).unwrap();
             let note =
-                "Custom types must be declared inside of the same scope they are referenced in,\n\
+                "Custom types must be declared inside of the same `sol!` invocation they are \
+                 referenced in,\n\
+                 as their definition is needed to compute signatures and selectors.\n\
+                 Structs, enums and UDVTs declared in another invocation can be used with \
+                 `#[sol(use path::to::Type)]`,\n\
                  or \"imported\" as a UDT with `type ... is (...);`";
             e.combine(Error::new(Span::call_site(), note));
             Err(e)
@@ -436,22 +752,69 @@ impl ExpCtxt<'_> {
 }
 
 // helper functions
-/// Expands a list of parameters into a list of struct fields.
+/// Replaces the `::alloy_sol_types` paths in the generated code with the path
+/// given with `#[sol(crate = "...")]`.
+fn replace_crate_path(tokens: TokenStream, path: &TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut out = TokenStream::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i..] {
+            [TokenTree::Punct(a), TokenTree::Punct(b), TokenTree::Ident(ident), ..]
+                if a.as_char() == ':'
+                    && a.spacing() == Spacing::Joint
+                    && b.as_char() == ':'
+                    && ident == "alloy_sol_types"
+                    && !matches!(
+                        i.checked_sub(1).map(|i| &tokens[i]),
+                        Some(TokenTree::Ident(_))
+                    ) =>
+            {
+                out.extend(path.clone());
+                i += 3;
+            }
+            [TokenTree::Group(group), ..] => {
+                let stream = replace_crate_path(group.stream(), path);
+                let mut new = Group::new(group.delimiter(), stream);
+                new.set_span(group.span());
+                out.extend([TokenTree::Group(new)]);
+                i += 1;
+            }
+            [token, ..] => {
+                out.extend([token.clone()]);
+                i += 1;
+            }
+            [] => unreachable!(),
+        }
+    }
+    out
+}
+
+/// Expands a list of parameters into a list of struct fields, given their
+/// Rust names from [`var_names`].
 ///
 /// See [`expand_field`].
-fn expand_fields<P>(params: &Parameters<P>) -> impl Iterator<Item = TokenStream> + '_ {
+fn expand_fields<'a, P>(
+    cx: &'a ExpCtxt<'_>,
+    params: &'a Parameters<P>,
+    names: &'a [Ident],
+) -> impl Iterator<Item = TokenStream> + 'a {
     params
         .iter()
-        .enumerate()
-        .map(|(i, var)| expand_field(i, &var.ty, var.name.as_ref()))
+        .zip(names)
+        .map(move |(var, name)| expand_field(cx, name, var))
 }
 
-/// Expands a single parameter into a struct field.
-fn expand_field(i: usize, ty: &Type, name: Option<&SolIdent>) -> TokenStream {
-    let name = anon_name((i, name));
-    let ty = expand_type(ty);
+/// Expands a single parameter into a public struct field, keeping its
+/// documentation.
+fn expand_field(cx: &ExpCtxt<'_>, name: &Ident, var: &VariableDeclaration) -> TokenStream {
+    let ty = expand_type(&var.ty);
+    let docs = attr::docs(&var.attrs);
+    let serde = cx.serde_field_attrs(&var.ty);
     quote! {
-        #name: <#ty as ::alloy_sol_types::SolType>::RustType
+        #(#docs)*
+        #serde
+        pub #name: <#ty as ::alloy_sol_types::SolType>::RustType
     }
 }
 
@@ -461,6 +824,50 @@ pub fn generate_name(i: usize) -> Ident {
     format_ident!("_{}", i)
 }
 
+/// Returns the Rust names of a list of parameters, given their names and
+/// attributes: the one specified with `#[sol(rename = "...")]`, their name, or
+/// a name generated from their index.
+///
+/// The attributes are only parsed here, so the names are computed once when
+/// expanding the parameters' item and then passed around. Invalid attributes
+/// are reported all at once.
+fn param_names<'a, I>(params: I) -> Result<Vec<Ident>>
+where
+    I: IntoIterator<Item = (Option<&'a SolIdent>, &'a [Attribute])>,
+{
+    let mut errors = Vec::new();
+    let names = params
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, attrs))| match attr::rename(attrs) {
+            Ok(rename) => rename.unwrap_or_else(|| anon_name((i, name))),
+            Err(e) => {
+                errors.push(e);
+                generate_name(i)
+            }
+        })
+        .collect();
+    match crate::utils::combine_errors(errors) {
+        Some(e) => Err(e),
+        None => Ok(names),
+    }
+}
+
+/// Returns the Rust names of a list of variable declarations. See
+/// [`param_names`].
+fn var_names<P>(params: &Parameters<P>) -> Result<Vec<Ident>> {
+    param_names(params.iter().map(|var| (var.name.as_ref(), &var.attrs[..])))
+}
+
+/// Returns the Rust name of an error or event: the one specified with
+/// `#[sol(rename = "...")]`, or its name.
+fn item_name(name: &SolIdent, attrs: &[Attribute]) -> Ident {
+    match attr::rename(attrs) {
+        Ok(Some(rename)) => rename,
+        _ => name.0.clone(),
+    }
+}
+
 /// Returns the name of a parameter, or a generated name if it is `None`.
 fn anon_name<T: Into<Ident> + Clone>((i, name): (usize, Option<&T>)) -> Ident {
     match name {
@@ -499,12 +906,16 @@ fn expand_from_into_unit(name: &Ident) -> TokenStream {
 /// Expands `From` impls for a list of types and the corresponding tuple.
 ///
 /// See [`expand_from_into_tuples`].
-fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStream {
+fn expand_from_into_tuples<P>(
+    name: &Ident,
+    fields: &Parameters<P>,
+    names: &[Ident],
+) -> TokenStream {
     if fields.is_empty() {
         return expand_from_into_unit(name)
     }
 
-    let names = fields.names().enumerate().map(anon_name);
+    let names = names.iter();
 
     let names2 = names.clone();
     let idxs = (0..fields.len()).map(syn::Index::from);
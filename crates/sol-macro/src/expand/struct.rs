@@ -1,12 +1,14 @@
 //! [`ItemStruct`] expansion.
 
 use super::{
-    expand_fields, expand_from_into_tuples, expand_type, ty::expand_tokenize_func, ExpCtxt,
+    arbitrary, expand_fields, expand_from_into_tuples, expand_type,
+    ty::{expand_tokenize_func, TypePrinter},
+    var_names, ConstStr, ExpCtxt,
 };
-use ast::{ItemStruct, VariableDeclaration};
+use ast::{Item, ItemStruct, Type};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::Result;
+use syn::{Error, Result};
 
 /// Expands an [`ItemStruct`]:
 ///
@@ -32,41 +34,89 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         ..
     } = s;
 
-    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    // structs containing mappings can only be used in storage
+    if fields.iter().any(|f| cx.has_mapping(&f.ty)) {
+        return Ok(TokenStream::new())
+    }
+
+    let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    if let Some(rename) = &sol_attrs.rename {
+        return Err(Error::new(
+            rename.span(),
+            "`rename` is not supported on structs",
+        ))
+    }
+    let field_names = var_names(fields)?;
+
     cx.derives(&mut attrs, fields, true);
 
-    let field_types_s = fields.iter().map(|f| f.ty.to_string());
+    let field_types_s = fields.iter().map(|f| {
+        let mut ty = ConstStr::default();
+        eip712_type(cx, &f.ty, &mut ty);
+        ty
+    });
     let field_names_s = fields.iter().map(|f| f.name.as_ref().unwrap().to_string());
 
-    let (field_types, field_names): (Vec<_>, Vec<_>) = fields
-        .iter()
-        .map(|f| (expand_type(&f.ty), f.name.as_ref().unwrap()))
-        .unzip();
+    let field_types: Vec<_> = fields.iter().map(|f| expand_type(&f.ty)).collect();
 
-    let encoded_type = fields.eip712_signature(name.as_string());
-    let encode_type_impl = if fields.iter().any(|f| f.ty.is_custom()) {
-        quote! {
-            {
-                let mut encoded = String::from(#encoded_type);
-                #(
-                    if let Some(s) = <#field_types as ::alloy_sol_types::SolType>::eip712_encode_type() {
-                        encoded.push_str(&s);
-                    }
-                )*
-                encoded
+    let root_type = {
+        let mut root = ConstStr::new(name.as_string());
+        root.push_str("(");
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                root.push_str(",");
             }
+            eip712_type(cx, &field.ty, &mut root);
+            root.push_str(" ");
+            root.push_str(&field.name.as_ref().unwrap().as_string());
         }
-    } else {
-        quote!(#encoded_type)
+        root.push_str(")");
+        root
     };
+    // the ABI type is only known if all the types from other invocations were
+    // given with `#[sol(use ...)]`
+    let type_info_impl = cx.assert_resolved(fields).is_ok().then(|| {
+        let abi_type = TypePrinter::new(cx, &s.as_type()).to_const_str();
+        quote! {
+            #[automatically_derived]
+            impl ::alloy_sol_types::private::SolTypeInfo for #name {
+                const ABI_TYPE: &'static str = #abi_type;
+                const EIP712_TYPE: &'static str = <Self as ::alloy_sol_types::SolStruct>::NAME;
 
-    let tokenize_impl = expand_tokenize_func(fields.iter());
+                fn eip712_components() -> ::alloy_sol_types::private::Vec<::alloy_sol_types::private::Cow<'static, str>> {
+                    let mut components = ::alloy_sol_types::private::Vec::new();
+                    components.push(<Self as ::alloy_sol_types::SolStruct>::eip712_root_type());
+                    components.extend(<Self as ::alloy_sol_types::SolStruct>::eip712_components());
+                    components
+                }
+            }
+        }
+    });
+
+    // the structs referenced by this struct's fields, including through arrays
+    let components = fields
+        .iter()
+        .filter_map(|f| expand_components(cx, &f.ty))
+        .collect::<Vec<_>>();
+    let components_impl = (!components.is_empty()).then(|| {
+        quote! {
+            fn eip712_components() -> ::alloy_sol_types::private::Vec<::alloy_sol_types::private::Cow<'static, str>> {
+                let mut components = ::alloy_sol_types::private::Vec::new();
+                #(#components)*
+                components.sort_unstable();
+                components.dedup();
+                components
+            }
+        }
+    });
+
+    let tokenize_impl = expand_tokenize_func(fields.iter(), &field_names);
 
     let encode_data_impl = match fields.len() {
         0 => unreachable!(),
         1 => {
-            let VariableDeclaration { ty, name, .. } = fields.first().unwrap();
-            let ty = expand_type(ty);
+            let name = &field_names[0];
+            let ty = expand_type(&fields[0].ty);
             quote!(<#ty as ::alloy_sol_types::SolType>::eip712_data_word(&self.#name).0.to_vec())
         }
         _ => quote! {
@@ -76,23 +126,45 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         },
     };
 
+    let encode_packed_impl = fields
+        .iter()
+        .zip(&field_names)
+        .map(|(f, name)| expand_encode_packed(cx, &f.ty, quote!(&self.#name), false));
+
     let attrs = attrs.iter();
-    let convert = expand_from_into_tuples(&name.0, fields);
+    let convert = expand_from_into_tuples(&name.0, fields, &field_names);
+    let arbitrary = arbitrary::expand_params(cx, &name.0, fields, &field_names);
     let name_s = name.to_string();
-    let fields = expand_fields(fields);
+    let fields = expand_fields(cx, fields, &field_names);
 
     let tokens = quote! {
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #name {
-            #(pub #fields),*
+            #(#fields),*
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
             #convert
 
+            #arbitrary
+
+            #[automatically_derived]
+            impl #name {
+                /// Returns the non-standard packed encoding of this struct, as
+                /// the concatenation of the packed encodings of its fields.
+                ///
+                /// This follows Solidity's `abi.encodePacked` rules: the
+                /// elements of array fields are padded to 32 bytes.
+                pub fn abi_encode_packed(&self) -> ::alloy_sol_types::private::Vec<u8> {
+                    let mut out = ::alloy_sol_types::private::Vec::new();
+                    #(#encode_packed_impl)*
+                    out
+                }
+            }
+
             #[automatically_derived]
             impl ::alloy_sol_types::SolStruct for #name {
                 type Tuple<'a> = UnderlyingSolTuple<'a>;
@@ -116,15 +188,19 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                     #tokenize_impl
                 }
 
-                fn eip712_encode_type() -> ::alloy_sol_types::private::Cow<'static, str> {
-                    #encode_type_impl.into()
+                fn eip712_root_type() -> ::alloy_sol_types::private::Cow<'static, str> {
+                    ::alloy_sol_types::private::Cow::Borrowed(#root_type)
                 }
 
+                #components_impl
+
                 fn eip712_encode_data(&self) -> Vec<u8> {
                     #encode_data_impl
                 }
             }
 
+            #type_info_impl
+
             #[automatically_derived]
             impl ::alloy_sol_types::EventTopic for #name {
                 #[inline]
@@ -158,3 +234,87 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     };
     Ok(tokens)
 }
+
+/// Expands the packed encoding of a value of type `ty` into `out`. Array
+/// elements are padded to 32 bytes, unlike top-level values.
+fn expand_encode_packed(
+    cx: &ExpCtxt<'_>,
+    ty: &Type,
+    value: TokenStream,
+    padded: bool,
+) -> TokenStream {
+    match ty {
+        Type::Array(array) => {
+            let item = expand_encode_packed(cx, &array.ty, quote!(item), true);
+            quote! {
+                for item in #value {
+                    #item
+                }
+            }
+        }
+        // nested structs are encoded recursively, so that their arrays are
+        // padded as well
+        Type::Custom(path) if matches!(cx.try_get_item(path), Some(Item::Struct(_))) => {
+            quote!(out.extend((#value).abi_encode_packed());)
+        }
+        _ => {
+            let ty = expand_type(ty);
+            if padded {
+                quote!(out.extend(<#ty as ::alloy_sol_types::SolType>::encode_single(#value));)
+            } else {
+                quote!(<#ty as ::alloy_sol_types::SolType>::encode_packed_to(#value, &mut out);)
+            }
+        }
+    }
+}
+
+/// Writes the EIP-712 name of a type: structs are referred to by their name,
+/// and enums and UDVTs by their underlying type.
+///
+/// Types declared in other invocations with `#[sol(use ...)]` are only known
+/// at compile time. Any other unresolved type is referred to by its name.
+fn eip712_type(cx: &ExpCtxt<'_>, ty: &Type, out: &mut ConstStr) {
+    match ty {
+        Type::Array(array) => {
+            eip712_type(cx, &array.ty, out);
+            out.push_str("[");
+            if let Some(size) = &array.size {
+                out.push_str(size.base10_digits());
+            }
+            out.push_str("]");
+        }
+        Type::Custom(path) => match cx.try_get_item(path) {
+            Some(Item::Struct(strukt)) => out.push_str(&strukt.name.as_string()),
+            Some(Item::Enum(_) | Item::Udt(_)) => eip712_type(cx, cx.custom_type(path), out),
+            _ if cx.is_used(path) => out.push_type_info(ty, "EIP712_TYPE"),
+            _ => out.push_str(&path.to_string()),
+        },
+        ty => out.push_str(&ty.to_string()),
+    }
+}
+
+/// Expands the statements which push the root types of the structs referenced
+/// by a field type, including through arrays, to `components`.
+fn expand_components(cx: &ExpCtxt<'_>, ty: &Type) -> Option<TokenStream> {
+    match ty {
+        Type::Array(array) => expand_components(cx, &array.ty),
+        Type::Custom(path) => match cx.try_get_item(path) {
+            Some(Item::Struct(_)) => {
+                let ty = expand_type(ty);
+                Some(quote! {
+                    components.push(<#ty as ::alloy_sol_types::SolStruct>::eip712_root_type());
+                    components.extend(<#ty as ::alloy_sol_types::SolStruct>::eip712_components());
+                })
+            }
+            // structs, enums or UDVTs declared in other invocations
+            None if cx.is_used(path) => {
+                let ty = expand_type(ty);
+                Some(quote! {
+                    components.extend(<#ty as ::alloy_sol_types::private::SolTypeInfo>::eip712_components());
+                })
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
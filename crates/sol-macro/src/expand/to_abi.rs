@@ -0,0 +1,193 @@
+//! Conversion of `sol!` items to their JSON ABI representation, enabled with
+//! `#[sol(abi)]`.
+
+use super::{contract::ContractItems, ExpCtxt};
+use alloy_json_abi::{
+    Constructor, Error, Event, EventParam, Fallback, Function, InternalType, JsonAbi, Param,
+    Receive, StateMutability,
+};
+use ast::{
+    EventParameter, Item, ItemFunction, Mutability, Parameters, SolIdent, Type, VariableDeclaration,
+};
+
+/// Returns the JSON ABI of a contract, serialized to a string.
+pub(super) fn generate(cx: &ExpCtxt<'_>, items: &ContractItems<'_>) -> String {
+    let mut abi = JsonAbi {
+        constructor: items.constructor.map(|f| Constructor {
+            inputs: params(cx, &f.arguments),
+            state_mutability: state_mutability(f),
+        }),
+        fallback: items.fallback.map(|f| Fallback {
+            state_mutability: state_mutability(f),
+        }),
+        receive: items.receive.map(|f| Receive {
+            state_mutability: state_mutability(f),
+        }),
+        ..Default::default()
+    };
+    for f in items.functions {
+        let name = f.name().as_string();
+        let function = Function {
+            name: name.clone(),
+            inputs: params(cx, &f.arguments),
+            outputs: f
+                .returns
+                .as_ref()
+                .map(|returns| params(cx, &returns.returns))
+                .unwrap_or_default(),
+            state_mutability: state_mutability(f),
+        };
+        abi.functions.entry(name).or_default().push(function);
+    }
+    for e in items.errors {
+        let name = e.name.as_string();
+        let error = Error {
+            name: name.clone(),
+            inputs: params(cx, &e.parameters),
+        };
+        abi.errors.entry(name).or_default().push(error);
+    }
+    for e in items.events {
+        let name = e.name.as_string();
+        let event = Event {
+            name: name.clone(),
+            inputs: e.parameters.iter().map(|p| event_param(cx, p)).collect(),
+            anonymous: e.is_anonymous(),
+        };
+        abi.events.entry(name).or_default().push(event);
+    }
+    serde_json::to_string(&abi).expect("failed to serialize JSON ABI")
+}
+
+fn state_mutability(function: &ItemFunction) -> StateMutability {
+    match function.attributes.mutability() {
+        Some(Mutability::Pure(_)) => StateMutability::Pure,
+        Some(Mutability::View(_) | Mutability::Constant(_)) => StateMutability::View,
+        Some(Mutability::Payable(_)) => StateMutability::Payable,
+        _ => StateMutability::NonPayable,
+    }
+}
+
+fn params<P>(cx: &ExpCtxt<'_>, params: &Parameters<P>) -> Vec<Param> {
+    params.iter().map(|p| param(cx, p)).collect()
+}
+
+fn param(cx: &ExpCtxt<'_>, var: &VariableDeclaration) -> Param {
+    ty_param(cx, var.name.as_ref(), &var.ty)
+}
+
+fn event_param(cx: &ExpCtxt<'_>, param: &EventParameter) -> EventParam {
+    let Param {
+        name,
+        ty,
+        components,
+        internal_type,
+    } = ty_param(cx, param.name.as_ref(), &param.ty);
+    EventParam {
+        name,
+        ty,
+        indexed: param.is_indexed(),
+        components,
+        internal_type,
+    }
+}
+
+/// Converts a Solidity type to a JSON ABI parameter, resolving custom types.
+fn ty_param(cx: &ExpCtxt<'_>, name: Option<&SolIdent>, ty: &Type) -> Param {
+    let name = name.map(SolIdent::as_string).unwrap_or_default();
+    match ty {
+        Type::Array(array) => {
+            let suffix = match array.size() {
+                Some(size) => format!("[{size}]"),
+                None => "[]".into(),
+            };
+            let mut param = ty_param(cx, None, &array.ty);
+            param.name = name;
+            param.ty.push_str(&suffix);
+            if let Some(internal_type) = &mut param.internal_type {
+                match internal_type {
+                    InternalType::AddressPayable(ty) | InternalType::Contract(ty) => {
+                        ty.push_str(&suffix)
+                    }
+                    InternalType::Enum { ty, .. }
+                    | InternalType::Struct { ty, .. }
+                    | InternalType::Other { ty, .. } => ty.push_str(&suffix),
+                }
+            }
+            param
+        }
+        Type::Tuple(tuple) => Param {
+            name,
+            ty: "tuple".into(),
+            components: tuple
+                .types
+                .iter()
+                .map(|ty| ty_param(cx, None, ty))
+                .collect(),
+            internal_type: None,
+        },
+        Type::Address(_, Some(_)) => Param {
+            name,
+            ty: "address".into(),
+            components: vec![],
+            internal_type: Some(InternalType::AddressPayable("address payable".into())),
+        },
+        Type::Custom(path) => {
+            // items declared in a contract are qualified with its name, like
+            // `struct Vault.Position`
+            let qualified = cx.qualified_name(path);
+            let (contract, ty_name) = match qualified.rsplit_once('.') {
+                Some((contract, ty)) => (Some(contract.to_string()), ty.to_string()),
+                None => (None, qualified),
+            };
+            match cx.try_get_item(path) {
+                Some(Item::Struct(strukt)) => Param {
+                    name,
+                    ty: "tuple".into(),
+                    components: params(cx, &strukt.fields),
+                    internal_type: Some(InternalType::Struct {
+                        contract,
+                        ty: ty_name,
+                    }),
+                },
+                Some(Item::Enum(_)) => Param {
+                    name,
+                    ty: "uint8".into(),
+                    components: vec![],
+                    internal_type: Some(InternalType::Enum {
+                        contract,
+                        ty: ty_name,
+                    }),
+                },
+                Some(Item::Udt(udt)) => Param {
+                    name,
+                    ty: ty_param(cx, None, &udt.ty).ty,
+                    components: vec![],
+                    internal_type: Some(InternalType::Other {
+                        contract,
+                        ty: ty_name,
+                    }),
+                },
+                // contracts are encoded as addresses
+                _ => Param {
+                    name,
+                    ty: "address".into(),
+                    components: vec![],
+                    internal_type: Some(InternalType::Contract(ty_name)),
+                },
+            }
+        }
+        ty => {
+            let ty = ty.to_string();
+            Param {
+                name,
+                internal_type: Some(InternalType::Other {
+                    contract: None,
+                    ty: ty.clone(),
+                }),
+                ty,
+                components: vec![],
+            }
+        }
+    }
+}
@@ -1,9 +1,8 @@
 //! [`Type`] expansion.
 
 use super::ExpCtxt;
-use crate::expand::generate_name;
 use ast::{EventParameter, Item, Parameters, Type, TypeArray, VariableDeclaration};
-use proc_macro2::{Literal, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use std::{fmt, num::NonZeroU16};
 
@@ -16,43 +15,45 @@ pub fn expand_type(ty: &Type) -> TokenStream {
 
 /// Expands a [`VariableDeclaration`] into an invocation of its types tokenize
 /// method.
-fn expand_tokenize_statement(var: &VariableDeclaration, i: usize) -> TokenStream {
+fn expand_tokenize_statement(var: &VariableDeclaration, name: &Ident) -> TokenStream {
     let ty = expand_type(&var.ty);
-    let name = var.name.clone().unwrap_or_else(|| generate_name(i).into());
     quote! {
         <#ty as ::alloy_sol_types::SolType>::tokenize(&self.#name)
     }
 }
 
 /// Expand the tokenization function from an iterator of [`VariableDeclaration`]
+/// and their Rust names.
 pub fn expand_tokenize_func<'a>(
     iter: impl Iterator<Item = &'a VariableDeclaration>,
+    names: &[Ident],
 ) -> TokenStream {
     let statements = iter
-        .enumerate()
-        .map(|(i, var)| expand_tokenize_statement(var, i));
+        .zip(names)
+        .map(|(var, name)| expand_tokenize_statement(var, name));
     quote! {
         (#(#statements,)*)
     }
 }
 
 /// Expand a event parameter into an invocation of its types tokenize method.
-fn expand_event_tokenize_statement(var: &EventParameter, i: usize) -> TokenStream {
+fn expand_event_tokenize_statement(var: &EventParameter, name: &Ident) -> TokenStream {
     let ty = expand_type(&var.ty);
-    let name = var.name.clone().unwrap_or_else(|| generate_name(i).into());
     quote! {
         <#ty as ::alloy_sol_types::SolType>::tokenize(&self.#name)
     }
 }
 
 /// Expand the tokenization function from an iterator of [`EventParameter`]
+/// and their Rust names.
 pub fn expand_event_tokenize_func<'a>(
     iter: impl Iterator<Item = &'a EventParameter>,
+    names: &[Ident],
 ) -> TokenStream {
     let statements = iter
-        .filter(|p| !p.is_indexed())
-        .enumerate()
-        .map(|(i, var)| expand_event_tokenize_statement(var, i));
+        .zip(names)
+        .filter(|(p, _)| !p.is_indexed())
+        .map(|(var, name)| expand_event_tokenize_statement(var, name));
     quote! {
         (#(#statements,)*)
     }
@@ -89,6 +90,24 @@ fn rec_expand_type(ty: &Type, tokens: &mut TokenStream) {
                 ::alloy_sol_types::sol_data::#name<#size>
             }
         }
+        Type::Fixed(span, size) | Type::Ufixed(span, size) => {
+            let name = match ty {
+                Type::Fixed(..) => "Fixed",
+                Type::Ufixed(..) => "Ufixed",
+                _ => unreachable!(),
+            };
+            let name = syn::Ident::new(name, span);
+
+            let (size, decimals) =
+                size.map_or((128, 18), |(size, decimals)| (size.get(), decimals));
+            debug_assert!(size <= 256 && size % 8 == 0 && decimals <= 80);
+            let size = Literal::u16_unsuffixed(size);
+            let decimals = Literal::u8_unsuffixed(decimals);
+
+            quote_spanned! {span=>
+                ::alloy_sol_types::sol_data::#name<#size, #decimals>
+            }
+        }
 
         Type::Tuple(ref tuple) => {
             return tuple.paren_token.surround(tokens, |tokens| {
@@ -112,8 +131,16 @@ fn rec_expand_type(ty: &Type, tokens: &mut TokenStream) {
                 }
             }
         }
-        Type::Function(ref _function) => todo!(),
-        Type::Mapping(ref _mapping) => todo!(),
+        Type::Function(ref function) => {
+            let span = function.span();
+            quote_spanned! {span=> ::alloy_sol_types::sol_data::Function }
+        }
+        Type::Mapping(ref mapping) => {
+            let span = mapping.span();
+            quote_spanned! {span=>
+                ::core::compile_error!("mapping types cannot be ABI-encoded")
+            }
+        }
         Type::Custom(ref custom) => return custom.to_tokens(tokens),
     };
     tokens.extend(tts);
@@ -141,6 +168,8 @@ pub(super) fn type_base_data_size(cx: &ExpCtxt<'_>, ty: &Type) -> usize {
         | Type::Bool(_)
         | Type::Int(..)
         | Type::Uint(..)
+        | Type::Fixed(..)
+        | Type::Ufixed(..)
         | Type::FixedBytes(..)
         | Type::Function(_) => 32,
 
@@ -161,15 +190,17 @@ pub(super) fn type_base_data_size(cx: &ExpCtxt<'_>, ty: &Type) -> usize {
             .map(|ty| type_base_data_size(cx, ty))
             .sum(),
 
-        Type::Custom(name) => match cx.get_item(name) {
-            Item::Enum(_) => 32,
-            Item::Struct(strukt) => strukt
+        Type::Custom(name) => match cx.try_get_item(name) {
+            Some(Item::Enum(_)) => 32,
+            Some(Item::Struct(strukt)) => strukt
                 .fields
                 .types()
                 .map(|ty| type_base_data_size(cx, ty))
                 .sum(),
-            Item::Udt(udt) => type_base_data_size(cx, &udt.ty),
-            _ => unreachable!(),
+            Some(Item::Udt(udt)) => type_base_data_size(cx, &udt.ty),
+            // types from other invocations take at least one word
+            None => 32,
+            Some(_) => unreachable!(),
         },
 
         // not applicable
@@ -201,6 +232,14 @@ pub(super) fn can_derive_default(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
                 strukt.fields.types().all(|ty| can_derive_default(cx, ty))
             }
             Some(Item::Udt(udt)) => can_derive_default(cx, &udt.ty),
+            Some(Item::Error(error)) => error
+                .parameters
+                .types()
+                .all(|ty| can_derive_default(cx, ty)),
+            Some(Item::Event(event)) => event
+                .parameters
+                .iter()
+                .all(|p| can_derive_default(cx, &p.ty)),
             Some(_) => unreachable!(),
             None => false,
         },
@@ -231,6 +270,14 @@ pub(super) fn can_derive_builtin_traits(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
                 strukt.fields.types().all(|ty| can_derive_default(cx, ty))
             }
             Some(Item::Udt(udt)) => can_derive_default(cx, &udt.ty),
+            Some(Item::Error(error)) => error
+                .parameters
+                .types()
+                .all(|ty| can_derive_builtin_traits(cx, ty)),
+            Some(Item::Event(event)) => event
+                .parameters
+                .iter()
+                .all(|p| can_derive_builtin_traits(cx, &p.ty)),
             Some(_) => unreachable!(),
             None => false,
         },
@@ -239,48 +286,175 @@ pub(super) fn can_derive_builtin_traits(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
     }
 }
 
-/// Implements [`fmt::Display`] which formats a [`Type`] to its canonical
-/// representation. This is then used in function, error, and event selector
-/// generation.
+/// A string computed at compile time, which may contain the ABI or EIP-712
+/// types of the structs, enums and UDVTs declared in other `sol!` invocations.
+///
+/// Expands to a string literal if it does not contain any such type, and to a
+/// constant expression concatenating its parts otherwise.
+#[derive(Default)]
+pub(super) struct ConstStr {
+    /// The literal parts, around each of `types`.
+    parts: Vec<String>,
+    /// The expressions of the types, and their names for display.
+    types: Vec<(TokenStream, String)>,
+}
+
+impl fmt::Display for ConstStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, part) in self.parts.iter().enumerate() {
+            if i > 0 {
+                f.write_str(&self.types[i - 1].1)?;
+            }
+            f.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToTokens for ConstStr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(s) = self.literal() {
+            return s.to_tokens(tokens)
+        }
+        let mut parts = vec![self.parts[0].to_token_stream()];
+        for ((ty, _), part) in self.types.iter().zip(&self.parts[1..]) {
+            parts.push(ty.clone());
+            parts.push(part.to_token_stream());
+        }
+        tokens.extend(quote! {{
+            const PARTS: &[&str] = &[#(#parts),*];
+            const LEN: usize = ::alloy_sol_types::private::concat_len(PARTS);
+            const BYTES: [u8; LEN] = ::alloy_sol_types::private::concat_bytes(PARTS);
+            ::alloy_sol_types::private::concat_str(&BYTES)
+        }});
+    }
+}
+
+impl ConstStr {
+    pub(super) fn new(s: impl Into<String>) -> Self {
+        Self {
+            parts: vec![s.into()],
+            types: Vec::new(),
+        }
+    }
+
+    pub(super) fn push_str(&mut self, s: &str) {
+        match self.parts.last_mut() {
+            Some(last) => last.push_str(s),
+            None => self.parts.push(s.into()),
+        }
+    }
+
+    /// Pushes the `SolTypeInfo` constant `item` of the unresolved type `ty`.
+    pub(super) fn push_type_info(&mut self, ty: &Type, item: &str) {
+        if self.parts.is_empty() {
+            self.parts.push(String::new());
+        }
+        let item = Ident::new(item, Span::call_site());
+        let expanded = expand_type(ty);
+        self.types.push((
+            quote!(<#expanded as ::alloy_sol_types::private::SolTypeInfo>::#item),
+            ty.to_string(),
+        ));
+        self.parts.push(String::new());
+    }
+
+    /// Returns the string if it is known at expansion time.
+    pub(super) fn literal(&self) -> Option<String> {
+        self.types.is_empty().then(|| self.parts.concat())
+    }
+}
+
+/// Formats a [`Type`] to its canonical representation, as used in function,
+/// error, and event selector generation.
+///
+/// Unresolved types are written as their `SolTypeInfo::ABI_TYPE`, which is
+/// only known at compile time.
 pub(super) struct TypePrinter<'ast> {
     cx: &'ast ExpCtxt<'ast>,
     ty: &'ast Type,
+    /// Whether to format the type as in a library function signature.
+    library: bool,
 }
 
 impl<'ast> TypePrinter<'ast> {
     pub(super) fn new(cx: &'ast ExpCtxt<'ast>, ty: &'ast Type) -> Self {
-        Self { cx, ty }
+        Self {
+            cx,
+            ty,
+            library: false,
+        }
     }
-}
 
-impl fmt::Display for TypePrinter<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Formats structs and enums by their fully qualified name, as in library
+    /// function signatures.
+    pub(super) fn new_library(cx: &'ast ExpCtxt<'ast>, ty: &'ast Type) -> Self {
+        Self {
+            cx,
+            ty,
+            library: true,
+        }
+    }
+
+    fn with(&self, ty: &'ast Type) -> Self {
+        Self { ty, ..*self }
+    }
+
+    pub(super) fn to_const_str(&self) -> ConstStr {
+        let mut out = ConstStr::default();
+        self.write(&mut out);
+        out
+    }
+
+    pub(super) fn write(&self, out: &mut ConstStr) {
         match self.ty {
-            Type::Int(_, None) => f.write_str("int256"),
-            Type::Uint(_, None) => f.write_str("uint256"),
+            Type::Int(_, None) => out.push_str("int256"),
+            Type::Uint(_, None) => out.push_str("uint256"),
+            Type::Fixed(_, None) => out.push_str("fixed128x18"),
+            Type::Ufixed(_, None) => out.push_str("ufixed128x18"),
 
             Type::Array(array) => {
-                Self::new(self.cx, &array.ty).fmt(f)?;
-                f.write_str("[")?;
+                self.with(&array.ty).write(out);
+                out.push_str("[");
                 if let Some(size) = &array.size {
-                    size.fmt(f)?;
+                    out.push_str(size.base10_digits());
                 }
-                f.write_str("]")
+                out.push_str("]");
             }
             Type::Tuple(tuple) => {
-                f.write_str("(")?;
+                out.push_str("(");
                 for (i, ty) in tuple.types.iter().enumerate() {
                     if i > 0 {
-                        f.write_str(",")?;
+                        out.push_str(",");
                     }
-                    Self::new(self.cx, ty).fmt(f)?;
+                    self.with(ty).write(out);
                 }
-                f.write_str(")")
+                out.push_str(")");
             }
 
-            Type::Custom(name) => self.cx.custom_type(name).fmt(f),
+            Type::Function(_) => out.push_str("function"),
 
-            ty => ty.fmt(f),
+            Type::Custom(name) => match self.cx.custom_types.get(name.last_tmp()) {
+                Some(_)
+                    if self.library
+                        && matches!(
+                            self.cx.try_get_item(name),
+                            Some(Item::Struct(_) | Item::Enum(_))
+                        ) =>
+                {
+                    out.push_str(&self.cx.qualified_name(name))
+                }
+                Some(resolved) => self.with(resolved).write(out),
+                None => out.push_type_info(self.ty, "ABI_TYPE"),
+            },
+
+            ty => out.push_str(&ty.to_string()),
         }
     }
 }
+
+impl fmt::Display for TypePrinter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_const_str().fmt(f)
+    }
+}
@@ -1,6 +1,6 @@
 //! [`ItemUdt`] expansion.
 
-use super::{expand_type, ExpCtxt};
+use super::{arbitrary, expand_type, ty::TypePrinter, ExpCtxt};
 use ast::ItemUdt;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -12,15 +12,41 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
     } = udt;
 
     let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
-    cx.type_derives(&mut attrs, Some(ty), true);
+    // `define_udt!` already derives these
+    cx.type_derives_except(
+        &mut attrs,
+        Some(ty),
+        true,
+        &["Default", "Debug", "PartialEq", "Eq"],
+    );
 
+    let abi_type = TypePrinter::new(cx, ty).to_string();
     let ty = expand_type(ty);
+    let arbitrary = arbitrary::expand_struct(
+        cx,
+        &name.0,
+        [(
+            syn::Index::from(0),
+            quote!(<#ty as ::alloy_sol_types::SolType>::RustType),
+        )],
+    );
     let tokens = quote! {
         ::alloy_sol_types::define_udt! {
             #(#attrs)*
             #name,
             underlying: #ty,
         }
+
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            #arbitrary
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::private::SolTypeInfo for #name {
+                const ABI_TYPE: &'static str = #abi_type;
+                const EIP712_TYPE: &'static str = #abi_type;
+            }
+        };
     };
     Ok(tokens)
 }
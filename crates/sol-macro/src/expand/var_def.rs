@@ -0,0 +1,818 @@
+//! [`VariableDefinition`] expansion.
+
+use super::{expand_type, ExpCtxt};
+use ast::{Item, ItemContract, Type, VariableDefinition};
+use proc_macro2::{Delimiter, Literal, Spacing, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use ruint::{aliases::U256, Uint};
+use std::fmt::Write;
+use syn::{Error, Result};
+
+/// The maximum depth of nested constant references.
+const MAX_DEPTH: usize = 32;
+
+/// The magnitude of an integer in a constant expression. This is wider than
+/// any Solidity type, so that intermediate values such as `2**256` in
+/// `2**256 - 1` can be represented; the range is only checked when the value
+/// is converted to a type.
+type Wide = Uint<512, 8>;
+
+/// Expands a [`VariableDefinition`].
+///
+/// Constants are expanded as Rust `const` items. Their initializer is
+/// evaluated at expansion time, and constants whose initializer cannot be
+/// evaluated are skipped:
+///
+/// ```ignore (pseudo-code)
+/// pub const #name: <#ty as SolType>::RustType = #value;
+/// ```
+///
+/// Mappings expose their layout through [`expand_mapping`].
+///
+/// `scope` is the contract in which the variable is declared, if any.
+pub(super) fn expand(
+    cx: &ExpCtxt<'_>,
+    var: &VariableDefinition,
+    scope: Option<&ItemContract>,
+) -> Result<TokenStream> {
+    if !var.attributes.is_constant() {
+        // getters of public state variables are expanded with their contract
+        return match &var.ty {
+            Type::Mapping(_) => expand_mapping(cx, var),
+            _ => Ok(TokenStream::new()),
+        }
+    }
+
+    // constants which cannot be evaluated at expansion time, such as ones that
+    // call functions other than `keccak256`, are not expanded
+    let VariableDefinition { ty, name, .. } = var;
+    let value = Evaluator::new(cx, scope)
+        .eval_var(var)
+        .and_then(|value| cast(value, ty, name.span()))
+        .and_then(|value| expand_value(ty, value, name.span()));
+    let Ok((ty, value)) = value else {
+        return Ok(TokenStream::new())
+    };
+    let tokens = quote! {
+        #[allow(non_upper_case_globals)]
+        pub const #name: #ty = #value;
+    };
+    Ok(tokens)
+}
+
+/// Evaluates the initializer of a state variable at expansion time, and
+/// returns its value formatted as a Solidity literal.
+///
+/// Returns `None` if the variable is not initialized, or if the initializer
+/// is not a constant expression.
+pub(super) fn initializer_value(
+    cx: &ExpCtxt<'_>,
+    var: &VariableDefinition,
+    scope: &ItemContract,
+) -> Option<String> {
+    var.initializer.as_ref()?;
+    let value = Evaluator::new(cx, Some(scope)).eval_var(var).ok()?;
+    let value = cast(value, &var.ty, var.name.span()).ok()?;
+    let s = match (&var.ty, value) {
+        (Type::Address(..), Value::Int(x)) => hex(&x.to_be_bytes::<64>()[44..]),
+        (_, Value::Int(x)) => x.to_string(),
+        (_, Value::Neg(x)) => format!("-{x}"),
+        (_, Value::Bool(b)) => b.to_string(),
+        (_, Value::Str(s)) => format!("{s:?}"),
+        (_, Value::Bytes(bytes)) => hex(&bytes),
+    };
+    Some(s)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut s = String::from("0x");
+    for b in bytes {
+        write!(s, "{b:02x}").unwrap();
+    }
+    s
+}
+
+/// Expands a mapping state variable into a type which implements
+/// `SolMapping`:
+///
+/// ```ignore (pseudo-code)
+/// pub struct #{name}Mapping;
+///
+/// impl SolMapping for #{name}Mapping {
+///     ...
+/// }
+/// ```
+fn expand_mapping(cx: &ExpCtxt<'_>, var: &VariableDefinition) -> Result<TokenStream> {
+    let VariableDefinition { name, ty, .. } = var;
+
+    let mut keys = Vec::new();
+    let mut value = ty;
+    while let Type::Mapping(mapping) = value {
+        keys.push(&*mapping.key);
+        value = &mapping.value;
+    }
+
+    let mapping_name = format_ident!("{name}Mapping");
+    let name_s = name.as_string();
+    let key_types_s = keys.iter().map(|key| key.to_string());
+    let value_type_s = value.to_string();
+    let key_types: Vec<_> = keys
+        .iter()
+        .map(|key| match key {
+            // contracts are stored as addresses
+            Type::Custom(name) if matches!(cx.try_get_item(name), Some(Item::Contract(_))) => {
+                quote!(::alloy_sol_types::sol_data::Address)
+            }
+            key => expand_type(key),
+        })
+        .collect();
+
+    // `keccak256(h(k) . p)`, where `h` pads value types to 32 bytes
+    let hashes = keys
+        .iter()
+        .zip(&key_types)
+        .enumerate()
+        .map(|(i, (key, ty))| {
+            let i = syn::Index::from(i);
+            let preimage = if matches!(key, Type::String(_) | Type::Bytes(_)) {
+                quote!(::core::convert::AsRef::<[u8]>::as_ref(&keys.#i))
+            } else {
+                quote!(&<#ty as ::alloy_sol_types::SolType>::encode_single(&keys.#i))
+            };
+            quote! {
+                let slot = ::alloy_sol_types::private::keccak256(
+                    [#preimage, &slot[..]].concat(),
+                );
+            }
+        });
+
+    let doc = format!("The storage layout of the `{name_s}` mapping: `{ty}`.");
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct #mapping_name;
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolMapping for #mapping_name {
+            type Keys = (#(#key_types,)*);
+
+            const NAME: &'static str = #name_s;
+            const KEY_TYPES: &'static [&'static str] = &[#(#key_types_s),*];
+            const VALUE_TYPE: &'static str = #value_type_s;
+
+            #[inline]
+            fn slot(
+                base: ::alloy_sol_types::private::B256,
+                keys: &<Self::Keys as ::alloy_sol_types::SolType>::RustType,
+            ) -> ::alloy_sol_types::private::B256 {
+                let slot = base;
+                #(#hashes)*
+                slot
+            }
+        }
+    })
+}
+
+/// An evaluated constant expression.
+#[derive(Clone, Debug)]
+enum Value {
+    /// A non-negative integer.
+    Int(Wide),
+    /// A negative integer, stored as its magnitude.
+    Neg(Wide),
+    Bool(bool),
+    Str(String),
+    /// A fixed-size byte array.
+    Bytes(Vec<u8>),
+}
+
+impl Value {
+    fn into_uint(self, span: Span) -> Result<Wide> {
+        match self {
+            Self::Int(x) => Ok(x),
+            Self::Neg(_) => Err(Error::new(
+                span,
+                "negative values are only supported as the result of a constant expression",
+            )),
+            _ => Err(Error::new(span, "expected an integer")),
+        }
+    }
+
+    /// Returns the sign and magnitude of an integer.
+    fn into_int(self, span: Span) -> Result<(bool, Wide)> {
+        match self {
+            Self::Int(x) => Ok((false, x)),
+            Self::Neg(x) => Ok((true, x)),
+            _ => Err(Error::new(span, "expected an integer")),
+        }
+    }
+
+    /// Creates an integer from its sign and magnitude.
+    fn from_int(neg: bool, x: Wide) -> Self {
+        if neg && x != Wide::ZERO {
+            Self::Neg(x)
+        } else {
+            Self::Int(x)
+        }
+    }
+
+    fn into_bytes(self, span: Span) -> Result<Vec<u8>> {
+        match self {
+            Self::Str(s) => Ok(s.into_bytes()),
+            Self::Bytes(b) => Ok(b),
+            _ => Err(Error::new(span, "expected a string or bytes")),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Evaluator<'a, 'ast> {
+    cx: &'a ExpCtxt<'ast>,
+    depth: usize,
+    /// Whether integer arithmetic is signed. This is the signedness of the
+    /// type that the expression is converted to.
+    signed: bool,
+    /// The contract in which the constant being evaluated is declared.
+    scope: Option<&'a ItemContract>,
+}
+
+impl<'a, 'ast> Evaluator<'a, 'ast> {
+    fn new(cx: &'a ExpCtxt<'ast>, scope: Option<&'a ItemContract>) -> Self {
+        Self {
+            cx,
+            depth: 0,
+            signed: false,
+            scope,
+        }
+    }
+
+    /// Returns an evaluator for expressions that are converted to `ty`.
+    fn with_type(self, ty: &Type) -> Self {
+        Self {
+            signed: matches!(ty, Type::Int(..)),
+            ..self
+        }
+    }
+
+    fn eval_var(&self, var: &VariableDefinition) -> Result<Value> {
+        let Some((_, init)) = &var.initializer else {
+            return Err(Error::new(
+                var.name.span(),
+                "constant variable must be initialized",
+            ))
+        };
+        if self.depth > MAX_DEPTH {
+            return Err(Error::new(
+                var.name.span(),
+                "recursion limit reached while evaluating constant",
+            ))
+        }
+        self.with_type(&var.ty).eval(init.clone(), var.name.span())
+    }
+
+    fn eval(&self, stream: TokenStream, span: Span) -> Result<Value> {
+        let mut parser = Parser {
+            ev: self,
+            tokens: stream.into_iter().collect(),
+            pos: 0,
+            span,
+        };
+        if parser.tokens.is_empty() {
+            return Err(Error::new(span, "expected an expression"))
+        }
+        let value = parser.expr(0)?;
+        match parser.tokens.get(parser.pos) {
+            Some(token) => Err(Error::new(token.span(), "unexpected token")),
+            None => Ok(value),
+        }
+    }
+
+    /// Evaluates a reference to another constant.
+    ///
+    /// Names are looked up in the enclosing contract first, then at the top
+    /// level, and finally in any other contract.
+    fn constant(&self, name: &str, span: Span) -> Result<Value> {
+        let constant = |item: &'a Item| match item {
+            Item::Variable(var) if var.attributes.is_constant() && var.name == name => Some(var),
+            _ => None,
+        };
+        let in_contract = |contract: &'a ItemContract| {
+            let var = contract.body.iter().find_map(constant)?;
+            Some((var, Some(contract)))
+        };
+        let found = self
+            .scope
+            .and_then(in_contract)
+            .or_else(|| {
+                let var = self.cx.ast.items.iter().find_map(constant)?;
+                Some((var, None))
+            })
+            .or_else(|| {
+                self.cx.all_items.iter().find_map(|item| match item {
+                    Item::Contract(contract) => in_contract(contract),
+                    _ => None,
+                })
+            });
+        let Some((var, scope)) = found else {
+            return Err(Error::new(span, format!("unresolved constant `{name}`")))
+        };
+        let ev = Self {
+            depth: self.depth + 1,
+            scope,
+            ..*self
+        };
+        cast(ev.eval_var(var)?, &var.ty, span)
+    }
+}
+
+/// Operators which span more than one punctuation token.
+const MULTI_CHAR_OPS: &[&str] = &["<<", ">>", "**"];
+
+/// Binary operators, from lowest to highest precedence.
+const BINARY_OPS: &[&[&str]] = &[
+    &["|"],
+    &["^"],
+    &["&"],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+/// A recursive descent parser over the tokens of a constant expression.
+struct Parser<'a, 'b, 'ast> {
+    ev: &'a Evaluator<'b, 'ast>,
+    tokens: Vec<TokenTree>,
+    pos: usize,
+    span: Span,
+}
+
+impl Parser<'_, '_, '_> {
+    fn expr(&mut self, level: usize) -> Result<Value> {
+        let Some(ops) = BINARY_OPS.get(level) else {
+            return self.unary()
+        };
+        let mut lhs = self.expr(level + 1)?;
+        while let Some((op, len)) = self.peek_op() {
+            if !ops.contains(&op.as_str()) {
+                break
+            }
+            let span = self.tokens[self.pos].span();
+            self.pos += len;
+            let rhs = self.expr(level + 1)?;
+            lhs = binary(&op, lhs, rhs, self.ev.signed, span)?;
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Value> {
+        match self.peek_op() {
+            Some((op, 1)) if op == "-" => {
+                let span = self.tokens[self.pos].span();
+                self.pos += 1;
+                match self.unary()? {
+                    Value::Int(x) if x == Wide::ZERO => Ok(Value::Int(x)),
+                    Value::Int(x) => Ok(Value::Neg(x)),
+                    Value::Neg(x) => Ok(Value::Int(x)),
+                    _ => Err(Error::new(span, "cannot negate a non-integer value")),
+                }
+            }
+            _ => self.pow(),
+        }
+    }
+
+    fn pow(&mut self) -> Result<Value> {
+        let base = self.primary()?;
+        match self.peek_op() {
+            Some((op, len)) if op == "**" => {
+                let span = self.tokens[self.pos].span();
+                self.pos += len;
+                let exp = self.unary()?;
+                binary("**", base, exp, self.ev.signed, span)
+            }
+            _ => Ok(base),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Value> {
+        let Some(token) = self.next() else {
+            return Err(Error::new(self.span, "unexpected end of expression"))
+        };
+        let span = token.span();
+        match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                self.ev.eval(group.stream(), span)
+            }
+            TokenTree::Literal(lit) => {
+                let (value, decimals) = literal(lit)?;
+                self.unit(value, decimals, span)
+            }
+            TokenTree::Ident(ident) => {
+                let name = ident.to_string();
+                match name.as_str() {
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    "type" => {
+                        let ty = self.args()?;
+                        let ty = syn::parse2::<Type>(ty)?;
+                        self.expect_punct('.')?;
+                        match self.next() {
+                            Some(TokenTree::Ident(member)) => {
+                                type_member(&ty, &member.to_string(), member.span())
+                            }
+                            _ => Err(Error::new(span, "expected `min` or `max`")),
+                        }
+                    }
+                    "keccak256" => {
+                        let arg = self.ev.eval(self.args()?, span)?;
+                        let hash = crate::utils::keccak256(arg.into_bytes(span)?);
+                        Ok(Value::Bytes(hash.to_vec()))
+                    }
+                    _ => match self.peek_group() {
+                        // type conversion
+                        true => {
+                            let ty = syn::parse_str::<Type>(&name).map_err(|_| {
+                                Error::new(span, format!("unsupported function `{name}`"))
+                            })?;
+                            let arg = self.ev.with_type(&ty).eval(self.args()?, span)?;
+                            cast(arg, &ty, span)
+                        }
+                        false => self.ev.constant(&name, span),
+                    },
+                }
+            }
+            token => Err(Error::new(token.span(), "unexpected token")),
+        }
+    }
+
+    /// Applies an optional ether or time unit to a number literal with
+    /// `decimals` fractional digits, such as `0.5 ether`. The result must be
+    /// an integer.
+    fn unit(&mut self, value: Value, decimals: usize, span: Span) -> Result<Value> {
+        let multiplier: u64 = match self.tokens.get(self.pos) {
+            Some(TokenTree::Ident(ident)) => match ident.to_string().as_str() {
+                "wei" | "seconds" => 1,
+                "gwei" => 1_000_000_000,
+                "ether" => 1_000_000_000_000_000_000,
+                "minutes" => 60,
+                "hours" => 3_600,
+                "days" => 86_400,
+                "weeks" => 604_800,
+                _ => 1,
+            },
+            _ => 1,
+        };
+        if multiplier != 1 {
+            self.pos += 1;
+        } else if decimals == 0 {
+            return Ok(value)
+        }
+        let x = value.into_uint(span)?;
+        let scale = u32::try_from(decimals)
+            .ok()
+            .and_then(|decimals| Wide::from(10u64).checked_pow(Wide::from(decimals)));
+        let (Some(scale), Some(x)) = (scale, x.checked_mul(Wide::from(multiplier))) else {
+            return Err(Error::new(span, "number literal is too large"))
+        };
+        if x % scale != Wide::ZERO {
+            return Err(Error::new(span, "number literal is not an integer"))
+        }
+        Ok(Value::Int(x / scale))
+    }
+
+    fn next(&mut self) -> Option<TokenTree> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += token.is_some() as usize;
+        token
+    }
+
+    fn peek_group(&self) -> bool {
+        matches!(self.tokens.get(self.pos), Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis)
+    }
+
+    /// Returns the contents of a parenthesized argument list.
+    fn args(&mut self) -> Result<TokenStream> {
+        match self.next() {
+            Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => Ok(g.stream()),
+            _ => Err(Error::new(self.span, "expected parentheses")),
+        }
+    }
+
+    fn expect_punct(&mut self, ch: char) -> Result<()> {
+        match self.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ch => Ok(()),
+            _ => Err(Error::new(self.span, format!("expected `{ch}`"))),
+        }
+    }
+
+    /// Returns the operator at the current position and the number of tokens
+    /// it spans. Joint punctuation is only combined into one of
+    /// [`MULTI_CHAR_OPS`], so that `a*-b` is parsed as `a * (-b)`.
+    fn peek_op(&self) -> Option<(String, usize)> {
+        let punct = |i: usize| match self.tokens.get(self.pos + i) {
+            Some(TokenTree::Punct(punct)) => Some(punct),
+            _ => None,
+        };
+        let first = punct(0)?;
+        if first.spacing() == Spacing::Joint {
+            if let Some(second) = punct(1) {
+                let op = format!("{}{}", first.as_char(), second.as_char());
+                if MULTI_CHAR_OPS.contains(&op.as_str()) {
+                    return Some((op, 2))
+                }
+            }
+        }
+        Some((first.as_char().to_string(), 1))
+    }
+}
+
+/// Parses a literal. Number literals are returned with the number of their
+/// fractional digits, which are applied by [`Parser::unit`].
+fn literal(lit: Literal) -> Result<(Value, usize)> {
+    let span = lit.span();
+    match syn::Lit::new(lit) {
+        syn::Lit::Int(int) => Ok((Value::Int(parse_int(int.base10_digits(), span)?), 0)),
+        syn::Lit::Float(float) => {
+            // decimals and scientific notation, e.g. `0.5`, `1e18` or `2.5e-3`
+            let digits = float.base10_digits();
+            let (mantissa, exp) = digits.split_once(['e', 'E']).unwrap_or((digits, "0"));
+            let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+            let too_large = || Error::new(span, "number literal is too large");
+            let shift = exp
+                .parse::<isize>()
+                .ok()
+                .and_then(|exp| exp.checked_sub(frac.len() as isize))
+                .ok_or_else(too_large)?;
+            let x = parse_int(&format!("{int}{frac}"), span)?;
+            if shift < 0 {
+                return Ok((Value::Int(x), shift.unsigned_abs()))
+            }
+            u32::try_from(shift)
+                .ok()
+                .and_then(|shift| Wide::from(10u64).checked_pow(Wide::from(shift)))
+                .and_then(|m| x.checked_mul(m))
+                .map(|x| (Value::Int(x), 0))
+                .ok_or_else(too_large)
+        }
+        syn::Lit::Str(s) => Ok((Value::Str(s.value()), 0)),
+        _ => Err(Error::new(span, "unsupported literal")),
+    }
+}
+
+fn parse_int(digits: &str, span: Span) -> Result<Wide> {
+    Wide::from_str_radix(digits, 10).map_err(|_| Error::new(span, "number literal is too large"))
+}
+
+fn binary(op: &str, lhs: Value, rhs: Value, signed: bool, span: Span) -> Result<Value> {
+    if signed {
+        return binary_signed(op, lhs, rhs, span)
+    }
+    let (a, b) = (lhs.into_uint(span)?, rhs.into_uint(span)?);
+    let shift = || usize::try_from(b).ok().filter(|&b| b < Wide::BITS);
+    let result = match op {
+        "|" => Some(a | b),
+        "^" => Some(a ^ b),
+        "&" => Some(a & b),
+        "<<" => shift().and_then(|b| a.checked_shl(b)),
+        ">>" => Some(shift().map_or(Wide::ZERO, |b| a >> b)),
+        "+" => a.checked_add(b),
+        "-" => a.checked_sub(b),
+        "*" => a.checked_mul(b),
+        "/" => a.checked_div(b),
+        "%" => a.checked_rem(b),
+        "**" => a.checked_pow(b),
+        _ => return Err(Error::new(span, format!("unsupported operator `{op}`"))),
+    };
+    result.map(Value::Int).ok_or_else(|| {
+        Error::new(
+            span,
+            "arithmetic overflow or underflow in constant expression",
+        )
+    })
+}
+
+/// Applies a binary operator to signed integers.
+///
+/// Arithmetic is performed on the sign and magnitude, and bitwise operators on
+/// the 256-bit two's complement representation.
+fn binary_signed(op: &str, lhs: Value, rhs: Value, span: Span) -> Result<Value> {
+    let (a_neg, a) = lhs.into_int(span)?;
+    let (b_neg, b) = rhs.into_int(span)?;
+    let twos = |neg: bool, x: Wide| {
+        let x = narrow(x)?;
+        match neg {
+            false => (x.bit_len() < 256).then_some(x),
+            true => (x <= U256::from(1u64) << 255).then(|| x.wrapping_neg()),
+        }
+    };
+    let result = match op {
+        "|" | "^" | "&" => twos(a_neg, a).zip(twos(b_neg, b)).map(|(a, b)| {
+            let x = match op {
+                "|" => a | b,
+                "^" => a ^ b,
+                _ => a & b,
+            };
+            match x.bit(255) {
+                true => (true, widen(x.wrapping_neg())),
+                false => (false, widen(x)),
+            }
+        }),
+        "+" | "-" => {
+            let b_neg = b_neg ^ (op == "-");
+            if a_neg == b_neg {
+                a.checked_add(b).map(|x| (a_neg, x))
+            } else if a >= b {
+                Some((a_neg, a - b))
+            } else {
+                Some((b_neg, b - a))
+            }
+        }
+        "*" => a.checked_mul(b).map(|x| (a_neg ^ b_neg, x)),
+        "/" => a.checked_div(b).map(|x| (a_neg ^ b_neg, x)),
+        "%" => a.checked_rem(b).map(|x| (a_neg, x)),
+        "**" if !b_neg => a.checked_pow(b).map(|x| (a_neg && b.bit(0), x)),
+        "<<" | ">>" if !b_neg => {
+            let b = usize::try_from(b).ok().filter(|&b| b < Wide::BITS);
+            match op {
+                "<<" => b.and_then(|b| a.checked_shl(b)).map(|x| (a_neg, x)),
+                // rounds towards negative infinity, like Solidity
+                _ => {
+                    let x = b.map_or(Wide::ZERO, |b| a >> b);
+                    let rounded = a_neg && b.map_or(a != Wide::ZERO, |b| x << b != a);
+                    Some((a_neg, x + Wide::from(rounded as u64)))
+                }
+            }
+        }
+        "**" | "<<" | ">>" => {
+            return Err(Error::new(
+                span,
+                format!("the right-hand side of `{op}` must not be negative"),
+            ))
+        }
+        _ => return Err(Error::new(span, format!("unsupported operator `{op}`"))),
+    };
+    result
+        .map(|(neg, x)| Value::from_int(neg, x))
+        .ok_or_else(|| {
+            Error::new(
+                span,
+                "arithmetic overflow or underflow in constant expression",
+            )
+        })
+}
+
+/// Evaluates `type(T).min` and `type(T).max`.
+fn type_member(ty: &Type, member: &str, span: Span) -> Result<Value> {
+    let bits = match ty {
+        Type::Uint(_, size) | Type::Int(_, size) => size.map_or(256, |s| s.get() as usize),
+        _ => {
+            return Err(Error::new(
+                span,
+                "only integer types have a minimum and maximum",
+            ))
+        }
+    };
+    let signed = matches!(ty, Type::Int(..));
+    let max = widen(U256::MAX >> (256 - bits + signed as usize));
+    match (member, signed) {
+        ("max", _) => Ok(Value::Int(max)),
+        ("min", false) => Ok(Value::Int(Wide::ZERO)),
+        ("min", true) => Ok(Value::Neg(max + Wide::from(1u64))),
+        _ => Err(Error::new(span, "expected `min` or `max`")),
+    }
+}
+
+/// Converts a value to the given type, checking that it is in range.
+fn cast(value: Value, ty: &Type, span: Span) -> Result<Value> {
+    let out_of_range = || Error::new(span, format!("value is out of range for type `{ty}`"));
+    let mismatch = || Error::new(span, format!("cannot convert value to type `{ty}`"));
+    match (ty, value) {
+        (Type::Bool(_), value @ Value::Bool(_)) => Ok(value),
+        (Type::String(_) | Type::Bytes(_), value @ Value::Str(_)) => Ok(value),
+        (Type::Uint(_, size), Value::Int(x)) => {
+            let bits = size.map_or(256, |s| s.get() as usize);
+            if x.bit_len() > bits {
+                return Err(out_of_range())
+            }
+            Ok(Value::Int(x))
+        }
+        (Type::Uint(_, size), Value::Bytes(b))
+            if b.len() * 8 == size.map_or(256, |s| s.get() as usize) =>
+        {
+            Ok(Value::Int(Wide::try_from_be_slice(&b).unwrap()))
+        }
+        (Type::Int(_, size), value @ (Value::Int(_) | Value::Neg(_))) => {
+            let bits = size.map_or(256, |s| s.get() as usize);
+            let min = Wide::from(1u64) << (bits - 1);
+            match &value {
+                Value::Int(x) if *x >= min => Err(out_of_range()),
+                Value::Neg(x) if *x > min => Err(out_of_range()),
+                _ => Ok(value),
+            }
+        }
+        (Type::Address(..), Value::Int(x)) => {
+            if x.bit_len() > 160 {
+                return Err(out_of_range())
+            }
+            Ok(Value::Int(x))
+        }
+        (Type::Address(..), Value::Bytes(b)) if b.len() == 20 => {
+            Ok(Value::Int(Wide::try_from_be_slice(&b).unwrap()))
+        }
+        (Type::FixedBytes(_, size), Value::Int(x)) => {
+            let size = size.get() as usize;
+            if x.byte_len() > size {
+                return Err(out_of_range())
+            }
+            Ok(Value::Bytes(x.to_be_bytes::<64>()[64 - size..].to_vec()))
+        }
+        (Type::FixedBytes(_, size), Value::Str(s)) if s.len() <= size.get() as usize => {
+            let mut bytes = s.into_bytes();
+            bytes.resize(size.get() as usize, 0);
+            Ok(Value::Bytes(bytes))
+        }
+        (Type::FixedBytes(_, size), Value::Bytes(mut bytes)) => {
+            // truncates or pads on the right, like Solidity
+            bytes.resize(size.get() as usize, 0);
+            Ok(Value::Bytes(bytes))
+        }
+        _ => Err(mismatch()),
+    }
+}
+
+/// Returns the Rust type and value of a constant, after casting it with
+/// [`cast`].
+fn expand_value(ty: &Type, value: Value, span: Span) -> Result<(TokenStream, TokenStream)> {
+    let sol_ty = expand_type(ty);
+    let rust_ty = quote!(<#sol_ty as ::alloy_sol_types::SolType>::RustType);
+    let tokens = match (ty, value) {
+        (Type::Bool(_), Value::Bool(b)) => (rust_ty, quote!(#b)),
+        (Type::String(_), Value::Str(s)) => (quote!(&str), quote!(#s)),
+        (Type::Bytes(_), Value::Str(s)) => {
+            let bytes = s.as_bytes();
+            (quote!(&[u8]), quote!(&[#(#bytes),*]))
+        }
+        (Type::Uint(_, size), Value::Int(x)) => {
+            let value = match size {
+                Some(size) if size.get() <= 128 => {
+                    let lit = Literal::u128_unsuffixed(x.to::<u128>());
+                    quote!(#lit)
+                }
+                _ => expand_u256(narrow(x).unwrap()),
+            };
+            (rust_ty, value)
+        }
+        (Type::Int(_, size), value) => {
+            let (neg, x) = match value {
+                Value::Int(x) => (false, x),
+                Value::Neg(x) => (true, x),
+                _ => unreachable!(),
+            };
+            let x = narrow(x).unwrap();
+            let value = match size {
+                Some(size) if size.get() <= 128 => {
+                    let lit = Literal::u128_unsuffixed(x.to::<u128>());
+                    if neg {
+                        quote!(-#lit)
+                    } else {
+                        quote!(#lit)
+                    }
+                }
+                _ => {
+                    let raw = expand_u256(if neg { x.wrapping_neg() } else { x });
+                    quote!(::alloy_sol_types::private::I256::from_raw(#raw))
+                }
+            };
+            (rust_ty, value)
+        }
+        (Type::Address(..), Value::Int(x)) => {
+            let bytes = &x.to_be_bytes::<64>()[44..];
+            (
+                rust_ty,
+                quote!(::alloy_sol_types::private::Address::new([#(#bytes),*])),
+            )
+        }
+        (Type::FixedBytes(..), Value::Bytes(bytes)) => (rust_ty, quote!([#(#bytes),*])),
+        _ => {
+            return Err(Error::new(
+                span,
+                format!("unsupported constant type `{ty}`"),
+            ))
+        }
+    };
+    Ok(tokens)
+}
+
+/// Converts a value that was checked by [`cast`] to 256 bits.
+fn narrow(x: Wide) -> Option<U256> {
+    U256::checked_from_limbs_slice(x.as_limbs())
+}
+
+fn widen(x: U256) -> Wide {
+    Wide::checked_from_limbs_slice(x.as_limbs()).unwrap()
+}
+
+fn expand_u256(x: U256) -> TokenStream {
+    let limbs = x.as_limbs();
+    quote!(::alloy_sol_types::private::U256::from_limbs([#(#limbs),*]))
+}
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::path::PathBuf;
+use std::{borrow::Cow, path::PathBuf};
 use syn::{
     parse::{Parse, ParseStream},
     Error, Ident, LitStr, Result, Token,
@@ -19,12 +19,11 @@ impl Parse for SolInputKind {
         let start = input.fork();
         match input.parse() {
             Ok(file) => Ok(Self::Sol(file)),
+            // a single type expression must make up the whole input
             Err(e) => match start.parse() {
-                Ok(ast::Type::Custom(_)) | Err(_) => Err(e),
+                Ok(_) if !start.is_empty() => Err(e),
+                Err(_) => Err(e),
 
-                Ok(ast::Type::Function(f)) => {
-                    Err(Error::new(f.span(), "function types are not yet supported"))
-                }
                 Ok(ast::Type::Mapping(m)) => {
                     Err(Error::new(m.span(), "mapping types are not yet supported"))
                 }
@@ -117,7 +116,8 @@ impl SolInput {
                 let msg = "names are not allowed outside of JSON ABI";
                 return Err(Error::new(name.span(), msg))
             }
-            let kind = syn::parse_str(s).map_err(|e| {
+            let s = normalize_string_literals(s);
+            let kind = syn::parse_str(&s).map_err(|e| {
                 let msg = format!("expected a valid JSON ABI string or Solidity string: {e}");
                 Error::new(span, msg)
             })?;
@@ -144,3 +144,98 @@ impl SolInput {
         })
     }
 }
+
+/// Replaces single-quoted Solidity string literals with double-quoted ones, so
+/// that Solidity source files can be tokenized as Rust.
+///
+/// Comments and double-quoted string literals are left untouched.
+fn normalize_string_literals(s: &str) -> Cow<'_, str> {
+    if !s.contains('\'') {
+        return Cow::Borrowed(s)
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // line comment
+            '/' if chars.peek() == Some(&'/') => {
+                out.push(c);
+                for c in chars.by_ref() {
+                    out.push(c);
+                    if c == '\n' {
+                        break
+                    }
+                }
+            }
+            // block comment
+            '/' if chars.peek() == Some(&'*') => {
+                out.push(c);
+                out.push(chars.next().unwrap());
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    out.push(c);
+                    if prev == '*' && c == '/' {
+                        break
+                    }
+                    prev = c;
+                }
+            }
+            '"' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    match c {
+                        '\\' => out.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '\'' => {
+                out.push('"');
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => match chars.next() {
+                            Some('\'') => out.push('\''),
+                            Some(c) => {
+                                out.push('\\');
+                                out.push(c);
+                            }
+                            None => out.push('\\'),
+                        },
+                        '"' => out.push_str("\\\""),
+                        '\'' => break,
+                        c => out.push(c),
+                    }
+                }
+                out.push('"');
+            }
+            c => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_strings() {
+        let cases = [
+            ("", ""),
+            ("a = \"b\";", "a = \"b\";"),
+            ("a = 'b';", "a = \"b\";"),
+            ("a = 'it\\'s';", "a = \"it's\";"),
+            ("a = 'say \"hi\"';", "a = \"say \\\"hi\\\"\";"),
+            ("a = '\\n';", "a = \"\\n\";"),
+            ("a = \"it's\";", "a = \"it's\";"),
+            ("// it's\na = 'b';", "// it's\na = \"b\";"),
+            ("/* it's */ a = 'b';", "/* it's */ a = \"b\";"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(normalize_string_literals(input), expected, "{input:?}");
+        }
+    }
+}
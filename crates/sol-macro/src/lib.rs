@@ -52,7 +52,11 @@ mod utils;
 ///
 /// Note:
 /// - relative file system paths are rooted at the `CARGO_MANIFEST_DIR`
-///   environment variable
+///   environment variable, and the crate is recompiled whenever the file
+///   changes
+/// - Solidity files are tokenized as Rust code, so some Solidity syntax is not
+///   supported in them; single-quoted string literals are converted to
+///   double-quoted ones beforehand
 /// - no casing convention is enforced for any identifier,
 /// - unnamed arguments will be given a name based on their index in the list,
 ///   e.g. `_0`, `_1`...
@@ -70,7 +74,14 @@ mod utils;
 /// - a Solidity item, which is a [Solidity source unit][sol-item] which
 ///   generates one or more Rust items,
 /// - a [Solidity type name][sol-types], which simply expands to the
-///   corresponding Rust type.
+///   corresponding Rust type. Any type expression is accepted, including
+///   arrays, tuples and types generated by other `sol!` invocations, e.g.
+///   `sol!(uint256[3][])` or `sol!((address, MyStruct)[])`.
+///
+/// Full source files can be used as-is: function and modifier bodies are
+/// parsed as opaque token streams and ignored, and items which can only be
+/// used in storage, like structs containing mappings and internal functions
+/// with storage parameters, do not generate any code.
 ///
 /// [sol-item]: https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.sourceUnit
 /// [sol-types]: https://docs.soliditylang.org/en/latest/types.html
@@ -85,23 +96,95 @@ mod utils;
 /// structs, enums, etc. These can be any Rust attribute, and they will be added
 /// to every Rust item generated from the Solidity item.
 ///
+/// Doc comments (`///` and `/** */`), including NatSpec tags like `@notice`
+/// and `@param`, are kept as `#[doc]` attributes on the generated items, and on
+/// the struct fields generated from struct members and parameters.
+///
+/// `#[derive(...)]` attributes on a contract are additionally applied to every
+/// item inside of it, so that all of the generated types, like call and return
+/// structs, implement the same traits.
+///
 /// This macro provides the `sol` attribute, which can be used to customize the
 /// generated code. Note that unused attributes are currently silently ignored,
 /// but this may change in the future.
 ///
 /// List of all `#[sol(...)]` supported attributes:
 /// - `all_derives`: adds `#[derive(...)]` attributes to all generated types
+/// - `default`: derives `Default` on the generated structs, calls, errors and
+///   events whose fields can all be defaulted. This is implied by
+///   `all_derives`.
+/// - `serde`: derives `serde::Serialize` and `serde::Deserialize` on all
+///   generated types. `bytes` and `bytesN` fields are (de)serialized as hex
+///   strings. User-defined value types are (de)serialized like their underlying
+///   Rust type. Requires the `serde` feature of `alloy-sol-types`.
+/// - `arbitrary`: implements `arbitrary::Arbitrary` and
+///   `proptest::arbitrary::Arbitrary` for all generated types. The `proptest`
+///   implementations require `Debug`, so this is usually combined with
+///   `all_derives`. Requires the `arbitrary` feature of `alloy-sol-types`.
+/// - `abi`: on contracts, emits an `ABI` string constant containing the JSON
+///   ABI of the contract. Requires the `json` feature.
+/// - `handler`: on contracts, generates a `<name>Handler` trait with a method
+///   for each function, taking its arguments and returning its `<name>Return`
+///   struct, as well as `dispatch` and `dispatch_calldata` methods on the
+///   contract's calls enum that invoke the handler and ABI-encode the return
+///   values. This can be used to implement contract mocks in Rust.
+/// - `flatten`: on contracts, includes the functions, errors and events of the
+///   base contracts that are declared in the same macro invocation in the
+///   contract's generated enums, and re-exports the corresponding types from
+///   the contract's module. Items that are overridden in the derived contract
+///   are not duplicated, and items inherited through several bases are only
+///   re-exported once.
+/// - `rename = <string literal>`: on functions, overrides the name used for the
+///   generated `<name>Call` and `<name>Return` structs and the corresponding
+///   variant of the contract's calls enum. On errors and events, overrides the
+///   name of the generated struct and enum variant. On struct fields and
+///   function, error and event parameters, overrides the name of the generated
+///   field, which is needed when the Solidity name is a Rust keyword. The
+///   Solidity signature, EIP-712 type and JSON ABI are unaffected. Renamed
+///   items must not collide with other items in the same scope.
 /// - `bytecode = <hex string literal>`: specifies the creation/init bytecode of
 ///   a contract. This will emit a `static` item with the specified bytes.
 /// - `deployed_bytecode = <hex string literal>`: specifies the deployed
 ///   bytecode of a contract. This will emit a `static` item with the specified
 ///   bytes.
+/// - `crate = <string literal>`: as an inner attribute (`#![sol(...)]`) at the
+///   top of the input, specifies the path to the `alloy-sol-types` crate used
+///   in the generated code, instead of `::alloy_sol_types`. This is useful when
+///   the crate is re-exported or renamed, e.g. `#![sol(crate =
+///   "::my_crate::sol_types")]`.
+/// - `use <path>`: as an inner attribute for the entire input, or on any item,
+///   declares that the struct, enum or UDVT at `<path>`, generated by another
+///   `sol!` invocation, may be referred to by its name in the entire input. It
+///   must also be in scope under that name, including from the modules
+///   generated for contracts. May be repeated. See [Structs and
+///   enums](#structs-and-enums) for more information.
 ///
 /// ### Structs and enums
 ///
 /// Structs and enums generate their corresponding Rust types. Enums are
 /// additionally annotated with `#[repr(u8)]`, and as such can have a maximum of
 /// 256 variants.
+///
+/// Structs implement `SolStruct`, which provides [EIP-712] hashing: the
+/// encoded type of a struct includes all the structs it references, sorted by
+/// name, and `eip712_signing_hash` computes the hash to sign for a given
+/// domain. Structs also have an `abi_encode_packed` method, which reproduces
+/// Solidity's `abi.encodePacked` applied to all of their fields.
+///
+/// Struct fields may refer to types declared in another `sol!` invocation, as
+/// long as they are in scope. Functions, errors and events however need all of
+/// their parameter types to be known, as they are part of their signatures.
+///
+/// Structs, enums and UDVTs declared in another invocation can be given with
+/// `#[sol(use path::to::Type)]`, in which case they can also be used in
+/// function, error and event parameters, and in the structs used by those.
+/// Their signatures and selectors are then computed at compile time. Such
+/// structs are also included in the EIP-712 encoded type of the structs which
+/// refer to them. However, they cannot be used in indexed event parameters or
+/// in library functions, nor are they described by `#[sol(abi)]`.
+///
+/// Types can also be shared between invocations by declaring them in a
+/// Solidity file which is included in each.
 /// ```ignore
 #[doc = include_str!("../doctests/structs.rs")]
 /// ```
@@ -115,11 +198,32 @@ mod utils;
 #[doc = include_str!("../doctests/types.rs")]
 /// ```
 /// 
+/// ### Constants
+///
+/// Constant variables generate a Rust `const` item with the same name. The
+/// initializer is evaluated at compile time, and may contain literals with
+/// ether and time units, such as `0.5 ether`, arithmetic and bitwise
+/// operators, type conversions, `type(T).min` and `type(T).max`, `keccak256`
+/// of a string and references to other constants. Intermediate values may
+/// exceed the range of the type, as in `2**256 - 1`, as long as the result
+/// fits. Constants whose initializer cannot be evaluated, such as ones that
+/// call other functions, do not generate an item.
+///
+/// Strings and bytes generate `&str` and `&[u8]` constants respectively, and
+/// all other types use the Rust type of their `SolType`.
+/// ```ignore
+#[doc = include_str!("../doctests/constants.rs")]
+/// ```
+/// 
 /// ### Functions and errors
 ///
 /// Functions generate two structs that implement `SolCall`: `<name>Call` for
 /// the function arguments, and `<name>Return` for the return values.
 ///
+/// The fields of the `<name>Return` struct are named after the function's
+/// named return values, so the output of a call can be decoded into typed,
+/// named values with `<name>Call::decode_returns`.
+///
 /// In the case of overloaded functions, an underscore and the index of the
 /// function will be appended to `<name>` (like `foo_0`, `foo_1`...) for
 /// disambiguation, but the signature will remain the same.
@@ -127,6 +231,18 @@ mod utils;
 /// E.g. if there are two functions named `foo`, the generated types will be
 /// `foo_0Call` and `foo_1Call`, each of which will implement `SolCall`
 /// with their respective signatures.
+///
+/// These names can be customized with the `#[sol(rename = "...")]` attribute.
+///
+/// `<name>Call` structs and the contract calls enum also implement `Display`,
+/// which formats the call like `transfer(0x1111…1111, 1000)`, with every
+/// argument formatted with `SolType::fmt_value`. Addresses are only
+/// abbreviated with the alternate flag (`{:#}`).
+///
+/// The function's state mutability and visibility are available through the
+/// `SolCall::STATE_MUTABILITY` and `SolCall::VISIBILITY` constants, which can
+/// be used to decide whether to execute the function with a call or a
+/// transaction.
 /// ```ignore
 #[doc = include_str!("../doctests/function_like.rs")]
 /// ```
@@ -135,10 +251,17 @@ mod utils;
 ///
 /// Events generate a struct that implements `SolEvent`.
 ///
-/// Note that events have special encoding rules in Solidity. For example,
-/// `string indexed` will be encoded in the topics as its `bytes32` Keccak-256
-/// hash, and as such the generated field for this argument will be `bytes32`,
-/// and not `string`.
+/// Note that events have special encoding rules in Solidity. Indexed
+/// parameters that are not value types, i.e. `string`, `bytes`, arrays and
+/// structs, are encoded in the topics as the Keccak-256 hash of their
+/// encoding. As such, the generated field for such a parameter will be a
+/// `B256` hash, and not the Rust type of the parameter. Non-indexed parameters
+/// are always stored in the log data as-is.
+///
+/// Each event also generates a `<name>Filter` topic filter builder, returned by
+/// `<name>::filter()`, with a method for each indexed parameter. These accept
+/// the Rust type of the parameter and hash it if needed, and the resulting
+/// `[Option<B256>; 4]` topics can be used to filter logs with `eth_getLogs`.
 /// ```ignore
 #[doc = include_str!("../doctests/events.rs")]
 /// ```
@@ -151,6 +274,50 @@ mod utils;
 /// - functions: `<contract_name>Calls`
 /// - errors: `<contract_name>Errors`
 /// - events: `<contract_name>Events`
+///
+/// The calls and errors enums are generated as soon as the contract declares a
+/// function or custom error, respectively. They can be used to decode any
+/// calldata sent to the contract, or revert data returned by it, by matching on
+/// the 4-byte selector. Wrap the errors enum in `ContractError` to also decode
+/// the built-in `Error(string)` and `Panic(uint256)` reverts.
+///
+/// Similarly, the events enum has a `decode_log` method which decodes a log
+/// into one of the contract's events by matching on its first topic, so that a
+/// contract's whole event stream can be processed with a single call.
+///
+/// Internal and private functions are not part of the calls enum, as they
+/// cannot be called externally.
+///
+/// Public state variables generate the same types as their implicit getter
+/// function, so `mapping(address => uint256) public balanceOf;` is equivalent
+/// to `function balanceOf(address) external view returns (uint256);`.
+///
+/// Mapping state variables of any visibility also generate a `<name>Mapping`
+/// type that implements `SolMapping`, which exposes the key and value types
+/// of the mapping and computes the storage slot of a value from its keys.
+/// Structs containing mappings can only be used in storage, and as such do not
+/// generate any code.
+///
+/// Libraries are expanded in the same way, except that the signatures of their
+/// functions follow the [library rules][library-selectors]: structs and enums
+/// are referred to by their fully qualified name, like `Math.Point`, and
+/// storage pointers are suffixed with ` storage`.
+///
+/// [library-selectors]: https://docs.soliditylang.org/en/latest/contracts.html#function-signatures-and-selectors-in-libraries
+///
+/// A contract's constructor generates a `<contract_name>Constructor` struct
+/// that implements `SolConstructor`, which can encode the deployment data of
+/// the contract with `encode_deploy`: its creation code followed by the
+/// ABI-encoded constructor arguments.
+///
+/// `fallback` and `receive` functions are not part of the calls enum, as they
+/// have no selector. Their presence is instead exposed through the
+/// `HAS_FALLBACK` and `HAS_RECEIVE` constants of the contract module.
+///
+/// The `constant` and `immutable` state variables of a contract are listed in
+/// its module's `CONSTANTS` and `IMMUTABLES` arrays of `StateVariable`, with
+/// their name, type, and the value of their initializer if it is a constant
+/// expression.
 /// ```ignore
 #[doc = include_str!("../doctests/contracts.rs")]
 /// ```
@@ -163,6 +330,10 @@ mod utils;
 /// JSON objects containing the `abi`, `evm`, `bytecode`, `deployedBytecode`,
 /// and similar keys are also supported.
 ///
+/// The ABI is expanded into an interface with the given name, so the same
+/// call, return, error, event and constructor types are generated as for the
+/// equivalent [Solidity input](#solidity).
+///
 /// Note that only valid JSON is supported, and not the human-readable ABI
 /// format, also used by `abigen!`. This should instead be easily converted to
 /// [normal Solidity input](#solidity).
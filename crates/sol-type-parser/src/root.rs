@@ -76,7 +76,7 @@ impl<'a> RootType<'a> {
     #[inline]
     pub fn try_basic_solidity(self) -> Result<()> {
         match self.0 {
-            "address" | "bool" | "string" | "bytes" | "uint" | "int" => Ok(()),
+            "address" | "bool" | "string" | "bytes" | "uint" | "int" | "fixed" | "ufixed" => Ok(()),
             name => {
                 if let Some(sz) = name.strip_prefix("bytes") {
                     if let Ok(sz) = sz.parse::<usize>() {
@@ -87,7 +87,7 @@ impl<'a> RootType<'a> {
                     return Err(Error::invalid_size(name))
                 }
 
-                // fast path both integer types
+                // fast path both integer and both fixed-point types
                 let s = name.strip_prefix('u').unwrap_or(name);
 
                 if let Some(sz) = s.strip_prefix("int") {
@@ -97,6 +97,15 @@ impl<'a> RootType<'a> {
                         }
                     }
                     Err(Error::invalid_size(name))
+                } else if let Some(sz) = s.strip_prefix("fixed") {
+                    if let Some((m, n)) = sz.split_once('x') {
+                        if let (Ok(m), Ok(n)) = (m.parse::<usize>(), n.parse::<usize>()) {
+                            if m != 0 && m <= 256 && m % 8 == 0 && n <= 80 {
+                                return Ok(())
+                            }
+                        }
+                    }
+                    Err(Error::invalid_size(name))
                 } else {
                     Err(Error::invalid_type_string(name))
                 }
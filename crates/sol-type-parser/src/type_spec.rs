@@ -212,4 +212,17 @@ mod test {
     fn a_type_named_tuple() {
         TypeSpecifier::try_from("tuple").unwrap();
     }
+
+    #[test]
+    fn fixed_point() {
+        let basic = |s| TypeSpecifier::try_from(s).unwrap().try_basic_solidity();
+        basic("fixed").unwrap();
+        basic("ufixed").unwrap();
+        basic("fixed128x18").unwrap();
+        basic("ufixed8x0[]").unwrap();
+        basic("fixed256x80[2]").unwrap();
+        basic("fixed7x1").unwrap_err();
+        basic("ufixed128x81").unwrap_err();
+        basic("fixed128").unwrap_err();
+    }
 }
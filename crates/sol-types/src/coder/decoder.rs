@@ -12,6 +12,9 @@ use crate::{encode, token::TokenSeq, utils, Error, Result, TokenType, Word};
 use alloc::{borrow::Cow, vec::Vec};
 use core::{fmt, slice::SliceIndex};
 
+#[cfg(feature = "trace")]
+use super::trace::{DecodeTrace, TraceKind, TraceStep};
+
 /// The [`Decoder`] wraps a byte slice with necessary info to progressively
 /// deserialize the bytes into a sequence of tokens.
 ///
@@ -19,14 +22,130 @@ use core::{fmt, slice::SliceIndex};
 ///
 /// While the Decoder contains the necessary info, the actual deserialization
 /// is done in the [`crate::SolType`] trait.
+///
+/// # Validation
+///
+/// When `validate` is false, the decoder is lenient: dirty high-order bytes
+/// are silently truncated, and any non-zero `bool` word decodes as `true`.
+///
+/// When `validate` is true, the decoder operates in strict mode and only
+/// accepts canonical encodings. It rejects:
+/// - non-zero padding bytes in heads, e.g. in `address`, `bytesN` or pointers;
+/// - non-zero padding bytes after the contents of `bytes` and `string`;
+/// - `bool` words other than `0` or `1`;
+/// - `intN` and `uintN` words that are out of range for `N`;
+/// - any blob that does not re-encode to the exact same bytes.
+///
+/// # Limits
+///
+/// Untrusted input can be decoded with a restrictive [`DecoderConfig`], which
+/// bounds the nesting depth, declared sequence lengths, and pointer offsets
+/// that the decoder will follow.
 #[derive(Clone, Copy)]
 pub struct Decoder<'de> {
     // the underlying buffer
     buf: &'de [u8],
     // the current offset in the buffer
     offset: usize,
-    // true if we validate type correctness and blob re-encoding
-    validate: bool,
+    // the offset of `buf` in the original buffer
+    start: usize,
+    // the number of indirections followed to reach `buf`
+    depth: usize,
+    // validation flag and limits
+    config: DecoderConfig,
+    // the trace to record steps into, if any
+    #[cfg(feature = "trace")]
+    trace: Option<&'de DecodeTrace>,
+}
+
+/// Configuration for a [`Decoder`].
+///
+/// The default configuration does not validate, and imposes no limits other
+/// than the ones inherent to the ABI encoding.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::{sol_data, DecoderConfig, SolType};
+///
+/// let config = DecoderConfig::new(true)
+///     .with_max_depth(8)
+///     .with_max_seq_len(1024)
+///     .with_max_offset(1 << 16);
+///
+/// let encoded = sol_data::Array::<sol_data::Bool>::encode_single(&vec![true; 2048]);
+/// assert!(sol_data::Array::<sol_data::Bool>::decode_single_with_config(&encoded, config).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecoderConfig {
+    /// Whether to validate type correctness and blob re-encoding.
+    ///
+    /// See [`Decoder`] for more details.
+    pub validate: bool,
+    /// The maximum number of nested indirections (pointers to dynamic data)
+    /// that may be followed.
+    pub max_depth: usize,
+    /// The maximum declared length of a dynamic array, `bytes` or `string`.
+    pub max_seq_len: usize,
+    /// The maximum offset in the input buffer that a pointer may reference.
+    pub max_offset: usize,
+    /// Whether to allow trailing bytes after the encoded data.
+    ///
+    /// If false, decoding fails with [`Error::TrailingBytes`] when the input
+    /// is longer than the encoding of the decoded value.
+    pub allow_trailing: bool,
+}
+
+impl Default for DecoderConfig {
+    #[inline]
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl DecoderConfig {
+    /// Creates a new configuration with the given validation flag and no
+    /// limits.
+    ///
+    /// Trailing bytes are allowed only if `validate` is false.
+    #[inline]
+    pub const fn new(validate: bool) -> Self {
+        Self {
+            validate,
+            max_depth: usize::MAX,
+            max_seq_len: usize::MAX,
+            max_offset: usize::MAX,
+            allow_trailing: !validate,
+        }
+    }
+
+    /// Sets the maximum number of nested indirections.
+    #[inline]
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum declared sequence length.
+    #[inline]
+    pub const fn with_max_seq_len(mut self, max_seq_len: usize) -> Self {
+        self.max_seq_len = max_seq_len;
+        self
+    }
+
+    /// Sets the maximum pointer offset.
+    #[inline]
+    pub const fn with_max_offset(mut self, max_offset: usize) -> Self {
+        self.max_offset = max_offset;
+        self
+    }
+
+    /// Sets whether to allow trailing bytes after the encoded data.
+    #[inline]
+    pub const fn with_allow_trailing(mut self, allow_trailing: bool) -> Self {
+        self.allow_trailing = allow_trailing;
+        self
+    }
 }
 
 impl fmt::Debug for Decoder<'_> {
@@ -41,7 +160,8 @@ impl fmt::Debug for Decoder<'_> {
         f.debug_struct("Decoder")
             .field("buf", &body)
             .field("offset", &self.offset)
-            .field("validate", &self.validate)
+            .field("depth", &self.depth)
+            .field("config", &self.config)
             .finish()
     }
 }
@@ -75,24 +195,59 @@ impl<'de> Decoder<'de> {
     /// to an identical bytestring.
     #[inline]
     pub const fn new(buf: &'de [u8], validate: bool) -> Self {
+        Self::with_config(buf, DecoderConfig::new(validate))
+    }
+
+    /// Instantiate a new decoder from a byte slice and a configuration.
+    #[inline]
+    pub const fn with_config(buf: &'de [u8], config: DecoderConfig) -> Self {
         Self {
             buf,
             offset: 0,
-            validate,
+            start: 0,
+            depth: 0,
+            config,
+            #[cfg(feature = "trace")]
+            trace: None,
+        }
+    }
+
+    /// Records every step taken by this decoder, and the decoders derived from
+    /// it, into `trace`.
+    #[cfg(feature = "trace")]
+    #[inline]
+    pub const fn with_trace(mut self, trace: &'de DecodeTrace) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    #[cfg(feature = "trace")]
+    #[inline]
+    fn record(&self, position: usize, kind: TraceKind) {
+        if let Some(trace) = self.trace {
+            trace.record(TraceStep {
+                position,
+                depth: self.depth,
+                kind,
+            });
         }
     }
 
     /// Create a child decoder, starting at `offset` bytes from the current
-    /// decoder's offset. The child decoder shares the buffer and validation
-    /// flag.
+    /// decoder's offset. The child decoder shares the buffer and
+    /// configuration.
     #[inline]
-    fn child(&self, offset: usize) -> Result<Decoder<'de>, Error> {
+    fn child(&self, offset: usize, depth: usize) -> Result<Decoder<'de>, Error> {
         self.buf
             .get(offset..)
             .map(|buf| Self {
                 buf,
                 offset: 0,
-                validate: self.validate,
+                start: self.start + offset,
+                depth,
+                config: self.config,
+                #[cfg(feature = "trace")]
+                trace: self.trace,
             })
             .ok_or(Error::Overrun)
     }
@@ -100,7 +255,7 @@ impl<'de> Decoder<'de> {
     /// Get a child decoder at the current offset.
     #[inline]
     pub fn raw_child(&self) -> Decoder<'de> {
-        self.child(self.offset).unwrap()
+        self.child(self.offset, self.depth).unwrap()
     }
 
     /// Advance the offset by `len` bytes.
@@ -159,8 +314,13 @@ impl<'de> Decoder<'de> {
     /// Take a word from the buffer, advancing the offset.
     #[inline]
     pub fn take_word(&mut self) -> Result<Word, Error> {
-        let contents = self.peek_word()?;
+        let position = self.position();
+        let contents = self
+            .peek_word()
+            .map_err(|e| Error::decode_at(position, "word", e))?;
         self.increase_offset(Word::len_bytes());
+        #[cfg(feature = "trace")]
+        self.record(position, TraceKind::Word);
         Ok(contents)
     }
 
@@ -168,21 +328,77 @@ impl<'de> Decoder<'de> {
     /// pointer, and following it.
     #[inline]
     pub fn take_indirection(&mut self) -> Result<Decoder<'de>, Error> {
-        let ptr = self.take_u32()? as usize;
-        self.child(ptr)
+        let position = self.position();
+        self.take_indirection_inner()
+            .map_err(|e| Error::decode_at(position, "pointer", e))
+    }
+
+    fn take_indirection_inner(&mut self) -> Result<Decoder<'de>, Error> {
+        let ptr = self.take_u32_inner()? as usize;
+        let depth = self.depth + 1;
+        check_limit("depth", depth, self.config.max_depth)?;
+        check_limit("offset", self.start + ptr, self.config.max_offset)?;
+        let child = self.child(ptr, depth)?;
+        #[cfg(feature = "trace")]
+        self.record(
+            self.position() - Word::len_bytes(),
+            TraceKind::Pointer {
+                target: child.start,
+            },
+        );
+        Ok(child)
+    }
+
+    /// Take the declared length of a dynamic sequence by consuming a word.
+    #[inline]
+    pub fn take_seq_len(&mut self) -> Result<usize> {
+        let position = self.position();
+        let len = self
+            .take_u32_inner()
+            .and_then(|len| {
+                let len = len as usize;
+                check_limit("sequence length", len, self.config.max_seq_len).map(|()| len)
+            })
+            .map_err(|e| Error::decode_at(position, "sequence length", e))?;
+        #[cfg(feature = "trace")]
+        self.record(position, TraceKind::SeqLen { len });
+        Ok(len)
     }
 
     /// Take a u32 from the buffer by consuming a word.
     #[inline]
     pub fn take_u32(&mut self) -> Result<u32> {
-        let word = self.take_word()?;
-        utils::as_u32(word, true)
+        let position = self.position();
+        let res = self
+            .take_u32_inner()
+            .map_err(|e| Error::decode_at(position, "uint32", e))?;
+        #[cfg(feature = "trace")]
+        self.record(position, TraceKind::Word);
+        Ok(res)
+    }
+
+    #[inline]
+    fn take_u32_inner(&mut self) -> Result<u32> {
+        let word = self.peek_word()?;
+        let res = utils::as_u32(word, true)?;
+        self.increase_offset(Word::len_bytes());
+        Ok(res)
     }
 
     /// Takes a slice of bytes of the given length by consuming up to the next
     /// word boundary.
-    pub fn take_slice(&mut self, len: usize) -> Result<&[u8], Error> {
-        if self.validate {
+    pub fn take_slice(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        let position = self.position();
+        let res = self
+            .take_slice_inner(len)
+            .map_err(|e| Error::decode_at(position, "packed sequence", e))?;
+        #[cfg(feature = "trace")]
+        self.record(position, TraceKind::Bytes { len });
+        Ok(res)
+    }
+
+    fn take_slice_inner(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        if self.config.validate {
             let padded_len = utils::next_multiple_of_32(len);
             if self.offset + padded_len > self.buf.len() {
                 return Err(Error::Overrun)
@@ -201,7 +417,13 @@ impl<'de> Decoder<'de> {
     /// True if this decoder is validating type correctness.
     #[inline]
     pub const fn validate(&self) -> bool {
-        self.validate
+        self.config.validate
+    }
+
+    /// Returns the configuration of this decoder.
+    #[inline]
+    pub const fn config(&self) -> &DecoderConfig {
+        &self.config
     }
 
     /// Takes the offset from the child decoder and sets it as the current
@@ -223,6 +445,13 @@ impl<'de> Decoder<'de> {
         self.offset
     }
 
+    /// Returns the current offset relative to the start of the original
+    /// buffer, which is the position reported in [`Error::Decode`].
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.start + self.offset
+    }
+
     /// Decodes a single token from the underlying buffer.
     #[inline]
     pub fn decode<T: TokenType<'de>>(&mut self) -> Result<T> {
@@ -236,38 +465,118 @@ impl<'de> Decoder<'de> {
     }
 }
 
+#[inline]
+const fn check_limit(limit: &'static str, value: usize, max: usize) -> Result<()> {
+    if value > max {
+        Err(Error::LimitExceeded { limit, value, max })
+    } else {
+        Ok(())
+    }
+}
+
 /// Decodes ABI compliant vector of bytes into vector of tokens described by
 /// types param.
+#[inline]
 pub fn decode<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
-    let mut decoder = Decoder::new(data, validate);
-    let res = decoder.decode_sequence::<T>()?;
-    if validate && encode(&res) != data {
-        return Err(Error::ReserMismatch)
-    }
-    Ok(res)
+    decode_with_config(data, DecoderConfig::new(validate))
 }
 
 /// Decode a single token.
 #[inline]
 pub fn decode_single<'de, T: TokenType<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
-    decode::<(T,)>(data, validate).map(|(t,)| t)
+    decode_single_with_config(data, DecoderConfig::new(validate))
+}
+
+/// Decode top-level function args from calldata, after checking that it starts
+/// with the given function selector.
+///
+/// This is the inverse of
+/// [`encode_with_selector`](crate::encode_with_selector).
+#[inline]
+pub fn decode_checked_selector<'de, T: TokenSeq<'de>>(
+    selector: [u8; 4],
+    data: &'de [u8],
+    validate: bool,
+) -> Result<T> {
+    match data.strip_prefix(&selector) {
+        Some(data) => decode_params(data, validate),
+        None => Err(Error::selector_mismatch(selector, data)),
+    }
 }
 
 /// Decode top-level function args. Encodes as params if T is a tuple.
 /// Otherwise, wraps in a tuple and decodes.
 #[inline]
 pub fn decode_params<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
+    decode_params_with_config(data, DecoderConfig::new(validate))
+}
+
+/// Decodes ABI compliant vector of bytes into vector of tokens described by
+/// types param, using the given configuration.
+#[inline]
+pub fn decode_with_config<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    config: DecoderConfig,
+) -> Result<T> {
+    decode_prefix_with_config(data, config).map(|(res, _)| res)
+}
+
+/// Decodes ABI compliant vector of bytes into vector of tokens described by
+/// types param, using the given configuration. Also returns the number of
+/// bytes consumed, which is the length of the encoding of the decoded tokens.
+///
+/// Input past the consumed bytes is rejected with [`Error::TrailingBytes`],
+/// unless [`DecoderConfig::allow_trailing`] is set.
+pub fn decode_prefix_with_config<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    config: DecoderConfig,
+) -> Result<(T, usize)> {
+    let mut decoder = Decoder::with_config(data, config);
+    let res = decoder.decode_sequence::<T>()?;
+    let consumed = res.total_words() * Word::len_bytes();
+    if !config.allow_trailing && consumed < data.len() {
+        return Err(Error::TrailingBytes {
+            consumed,
+            len: data.len(),
+        })
+    }
+    if config.validate
+        && data
+            .get(..consumed)
+            .map_or(true, |data| encode(&res) != data)
+    {
+        return Err(Error::ReserMismatch)
+    }
+    Ok((res, consumed))
+}
+
+/// Decode a single token, using the given configuration.
+#[inline]
+pub fn decode_single_with_config<'de, T: TokenType<'de>>(
+    data: &'de [u8],
+    config: DecoderConfig,
+) -> Result<T> {
+    decode_with_config::<(T,)>(data, config).map(|(t,)| t)
+}
+
+/// Decode top-level function args, using the given configuration. Encodes as
+/// params if T is a tuple. Otherwise, wraps in a tuple and decodes.
+#[inline]
+pub fn decode_params_with_config<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    config: DecoderConfig,
+) -> Result<T> {
     if T::IS_TUPLE {
-        decode(data, validate)
+        decode_with_config(data, config)
     } else {
-        decode_single(data, validate)
+        decode_single_with_config(data, config)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{sol_data, utils::pad_u32, SolType};
-    use alloc::string::ToString;
+    use crate::{sol_data, utils::pad_u32, DecodePathSegment, DecoderConfig, Error, SolType};
+    use alloc::{boxed::Box, string::ToString};
     use alloy_primitives::{Address, B256, U256};
     use hex_literal::hex;
 
@@ -583,6 +892,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decode_borrowed_tokens() {
+        type MyTy = (sol_data::Bytes, sol_data::String);
+        let encoded = MyTy::encode_params(&(vec![1, 2, 3], "gavofyork".to_string()));
+        let range = encoded.as_ptr_range();
+
+        let (bytes, string) = MyTy::decode_params_token(&encoded, true).unwrap();
+        assert_eq!(bytes.as_slice(), [1, 2, 3]);
+        assert_eq!(string.as_str(), Ok("gavofyork"));
+        assert!(range.contains(&bytes.into_slice().as_ptr()));
+        assert!(range.contains(&string.into_slice().as_ptr()));
+    }
+
     #[test]
     fn decode_corrupted_dynamic_array() {
         type MyTy = sol_data::Array<sol_data::Uint<32>>;
@@ -601,6 +923,130 @@ mod tests {
         assert!(MyTy::decode(&encoded, true).is_err());
     }
 
+    #[test]
+    fn decode_with_limits() {
+        type MyTy = sol_data::Array<sol_data::Array<sol_data::Uint<8>>>;
+        let value = vec![vec![1u8, 2, 3], vec![4, 5]];
+        let encoded = MyTy::encode_single(&value);
+
+        let config = DecoderConfig::new(true);
+        assert_eq!(
+            MyTy::decode_single_with_config(&encoded, config),
+            Ok(value.clone())
+        );
+
+        let config = DecoderConfig::new(true).with_max_depth(2);
+        assert_eq!(MyTy::decode_single_with_config(&encoded, config), Ok(value));
+
+        let config = DecoderConfig::new(true).with_max_depth(1);
+        assert!(matches!(
+            MyTy::decode_single_with_config(&encoded, config)
+                .unwrap_err()
+                .root_cause(),
+            Error::LimitExceeded { limit: "depth", .. }
+        ));
+
+        let config = DecoderConfig::new(true).with_max_seq_len(2);
+        assert!(matches!(
+            MyTy::decode_single_with_config(&encoded, config)
+                .unwrap_err()
+                .root_cause(),
+            Error::LimitExceeded {
+                limit: "sequence length",
+                value: 3,
+                max: 2
+            }
+        ));
+
+        let config = DecoderConfig::new(true).with_max_offset(0x40);
+        assert!(matches!(
+            MyTy::decode_single_with_config(&encoded, config)
+                .unwrap_err()
+                .root_cause(),
+            Error::LimitExceeded {
+                limit: "offset",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn decode_huge_declared_length() {
+        type MyTy = sol_data::Array<sol_data::Uint<256>>;
+        let encoded = hex!(
+            "
+    	0000000000000000000000000000000000000000000000000000000000000020
+    	00000000000000000000000000000000000000000000000000000000ffffffff
+    	"
+        );
+        let config = DecoderConfig::new(false).with_max_seq_len(1 << 16);
+        assert!(matches!(
+            MyTy::decode_single_with_config(&encoded, config)
+                .unwrap_err()
+                .root_cause(),
+            Error::LimitExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn decode_error_context() {
+        type MyTy = (
+            sol_data::Bool,
+            (sol_data::Uint<8>, sol_data::Array<sol_data::Bytes>),
+        );
+        let mut encoded = MyTy::encode_params(&(true, (1, vec![vec![1], vec![2, 3]])));
+        // the second `bytes` length word is at 0x120
+        encoded.truncate(0x120);
+
+        let err = MyTy::decode_params(&encoded, false).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Decode {
+                offset: 0x120,
+                expected: "sequence length",
+                path: vec![
+                    DecodePathSegment::Field(1),
+                    DecodePathSegment::Field(1),
+                    DecodePathSegment::Index(1),
+                ],
+                source: Box::new(Error::Overrun),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Buffer overrun while deserializing, while decoding sequence length at offset 0x120 \
+             in `param[1].field[1][1]`"
+        );
+    }
+
+    #[test]
+    fn decode_trailing_bytes() {
+        type MyTy = (sol_data::Uint<8>, sol_data::Bytes);
+        let mut encoded = MyTy::encode_params(&(1, vec![1, 2, 3]));
+        let len = encoded.len();
+        encoded.extend_from_slice(&[0xff; 7]);
+
+        assert!(MyTy::decode_params(&encoded, false).is_ok());
+        assert_eq!(
+            MyTy::decode_params(&encoded, true),
+            Err(Error::TrailingBytes {
+                consumed: len,
+                len: len + 7
+            })
+        );
+
+        let config = DecoderConfig::new(true).with_allow_trailing(true);
+        assert!(MyTy::decode_params_with_config(&encoded, config).is_ok());
+        let config = DecoderConfig::new(false).with_allow_trailing(false);
+        assert!(MyTy::decode_params_with_config(&encoded, config).is_err());
+
+        let (decoded, consumed) =
+            MyTy::decode_prefix_with_config(&encoded, DecoderConfig::new(true)).unwrap();
+        assert_eq!(decoded, (1, vec![1, 2, 3]));
+        assert_eq!(consumed, len);
+        assert_eq!(&encoded[consumed..], &[0xff; 7]);
+    }
+
     #[test]
     fn decode_verify_addresses() {
         let input = hex!(
@@ -629,6 +1075,29 @@ mod tests {
         assert!(MyTy2::decode_params(&input, true).is_ok());
     }
 
+    #[test]
+    fn decode_verify_bool() {
+        let input = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+        assert_eq!(sol_data::Bool::decode_single(&input, false), Ok(true));
+        assert!(sol_data::Bool::decode_single(&input, true).is_err());
+    }
+
+    #[test]
+    fn decode_verify_bytes_padding() {
+        let input = hex!(
+            "
+    	0000000000000000000000000000000000000000000000000000000000000020
+    	0000000000000000000000000000000000000000000000000000000000000002
+    	1234560000000000000000000000000000000000000000000000000000000000
+    	"
+        );
+        assert_eq!(
+            sol_data::Bytes::decode_single(&input, false),
+            Ok(vec![0x12, 0x34])
+        );
+        assert!(sol_data::Bytes::decode_single(&input, true).is_err());
+    }
+
     #[test]
     fn signed_int_dirty_high_bytes() {
         type MyTy = sol_data::Int<8>;
@@ -9,20 +9,25 @@
 
 use crate::{
     token::TokenSeq,
-    utils::{pad_u32, words_for},
+    utils::{pad_u32, padded_len},
     TokenType, Word,
 };
 use alloc::vec::Vec;
-use core::mem;
 
 /// An ABI encoder.
 ///
-/// This is not intended for public consumption. It should be used only by the
-/// token types. If you have found yourself here, you probably want to use the
+/// The low-level `append_*` methods are meant to be used only by the token
+/// types. If you have found yourself here, you probably want to use the
 /// high-level [`crate::SolType`] interface (or its dynamic equivalent) instead.
+///
+/// An encoder can be reused across many encodings to avoid reallocating its
+/// buffer; see [`encode_into`].
+///
+/// The encoded data is written directly into a byte buffer, whose length is
+/// always a multiple of the word size.
 #[derive(Default, Clone, Debug)]
 pub struct Encoder {
-    buf: Vec<Word>,
+    buf: Vec<u8>,
     suffix_offset: Vec<u32>,
 }
 
@@ -40,29 +45,63 @@ impl Encoder {
     #[inline]
     pub fn with_capacity(size: usize) -> Self {
         Self {
-            buf: Vec::with_capacity(size),
+            buf: Vec::with_capacity(size * 32),
             suffix_offset: Vec::with_capacity(8),
         }
     }
 
+    /// Clears the encoder, removing all encoded words and suffix offsets.
+    ///
+    /// This retains the allocated capacity, so that the encoder can be reused
+    /// for multiple encodings without reallocating.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.buf.clear();
+        self.suffix_offset.clear();
+    }
+
+    /// Returns the number of words encoded so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buf.len() / 32
+    }
+
+    /// Returns `true` if no words have been encoded yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns the words encoded so far.
+    #[inline]
+    pub fn as_words(&self) -> &[Word] {
+        debug_assert_eq!(self.buf.len() % 32, 0);
+        // SAFETY: `#[repr(transparent)] FixedBytes<N>([u8; N])`, which has an
+        // alignment of 1, and the buffer length is a multiple of 32.
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast(), self.len()) }
+    }
+
+    /// Returns the bytes encoded so far.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
     /// Finish the encoding process, returning the encoded words.
     ///
-    /// Use `into_bytes` instead to flatten the words into bytes.
-    // https://github.com/rust-lang/rust-clippy/issues/4979
-    #[allow(clippy::missing_const_for_fn)]
+    /// This copies the encoded data. Use `into_bytes` instead to get the
+    /// underlying byte buffer.
     #[inline]
     pub fn finish(self) -> Vec<Word> {
-        self.buf
+        self.as_words().to_vec()
     }
 
     /// Finish the encoding process, returning the encoded bytes.
+    // https://github.com/rust-lang/rust-clippy/issues/4979
+    #[allow(clippy::missing_const_for_fn)]
     #[inline]
     pub fn into_bytes(self) -> Vec<u8> {
-        // TODO: remove once `Vec::into_flattened` is stabilized.
-        // unsafe { mem::transmute::<Vec<_>, Vec<[u8; 32]>>(self.buf).into_flattened() }
-
-        // SAFETY: `#[repr(transparent)] FixedBytes<N>([u8; N])`
-        unsafe { crate::impl_core::into_flattened::<u8, 32>(mem::transmute(self.buf)) }
+        self.buf
     }
 
     /// Determine the current suffix offset.
@@ -100,7 +139,7 @@ impl Encoder {
     /// Append a word to the encoder.
     #[inline]
     pub fn append_word(&mut self, word: Word) {
-        self.buf.push(word);
+        self.buf.extend_from_slice(word.as_slice());
     }
 
     /// Append a pointer to the current suffix offset.
@@ -123,22 +162,9 @@ impl Encoder {
     /// Append a sequence of bytes, padding to the next word.
     #[inline]
     fn append_bytes(&mut self, bytes: &[u8]) {
-        let len = words_for(bytes);
-        for i in 0..len {
-            let mut padded = Word::ZERO;
-
-            let to_copy = match i == len - 1 {
-                false => 32,
-                true => match bytes.len() % 32 {
-                    0 => 32,
-                    x => x,
-                },
-            };
-
-            let offset = 32 * i;
-            padded[..to_copy].copy_from_slice(&bytes[offset..offset + to_copy]);
-            self.append_word(padded);
-        }
+        let len = self.buf.len() + padded_len(bytes);
+        self.buf.extend_from_slice(bytes);
+        self.buf.resize(len, 0);
     }
 
     /// Append a sequence of bytes as a packed sequence with a length prefix.
@@ -157,17 +183,53 @@ impl Encoder {
 
 /// ABI-encode a token sequence.
 pub fn encode<'a, T: TokenSeq<'a>>(tokens: &T) -> Vec<u8> {
-    let mut enc = Encoder::with_capacity(tokens.total_words());
+    encode_sized(tokens, tokens.total_words() * 32)
+}
+
+/// ABI-encode a token sequence into a buffer preallocated to `size` bytes.
+///
+/// `size` should be the exact length of the encoding, as computed by
+/// [`SolType::encoded_size`](crate::SolType::encoded_size).
+#[inline]
+pub(crate) fn encode_sized<'a, T: TokenSeq<'a>>(tokens: &T, size: usize) -> Vec<u8> {
+    let mut enc = Encoder::with_capacity(size / 32);
     enc.append_head_tail(tokens);
     enc.into_bytes()
 }
 
+/// ABI-encode a token sequence into an existing encoder.
+///
+/// The encoder is [reset](Encoder::reset) first, so that its buffer can be
+/// reused across many encodings. The result is available through
+/// [`Encoder::as_bytes`].
+#[inline]
+pub fn encode_into<'a, T: TokenSeq<'a>>(tokens: &T, enc: &mut Encoder) {
+    enc.reset();
+    enc.buf.reserve(tokens.total_words() * 32);
+    enc.append_head_tail(tokens);
+}
+
 /// ABI-encode a single token.
 #[inline]
 pub fn encode_single<'a, T: TokenType<'a>>(token: &T) -> Vec<u8> {
-    // Same as [`core::array::from_ref`].
-    // SAFETY: Converting `&T` to `&(T,)` is sound.
-    encode::<(T,)>(unsafe { &*(token as *const T).cast::<(T,)>() })
+    encode::<(T,)>(as_tuple(token))
+}
+
+/// ABI-encode a single token into a buffer preallocated to `size` bytes.
+///
+/// `size` should be the exact length of the encoding, as computed by
+/// [`SolType::abi_encoded_size`](crate::SolType::abi_encoded_size).
+#[inline]
+pub(crate) fn encode_single_sized<'a, T: TokenType<'a>>(token: &T, size: usize) -> Vec<u8> {
+    encode_sized::<(T,)>(as_tuple(token), size)
+}
+
+/// ABI-encode a single token into an existing encoder.
+///
+/// See [`encode_into`] for more details.
+#[inline]
+pub fn encode_single_into<'a, T: TokenType<'a>>(token: &T, enc: &mut Encoder) {
+    encode_into::<(T,)>(as_tuple(token), enc)
 }
 
 /// Encode a tuple as ABI function params, suitable for passing to a function.
@@ -180,9 +242,58 @@ pub fn encode_params<'a, T: TokenSeq<'a>>(token: &T) -> Vec<u8> {
     }
 }
 
+/// Encode a tuple as ABI function params into a buffer preallocated to `size`
+/// bytes.
+///
+/// See [`encode_sized`] and [`encode_single_sized`].
+#[inline]
+pub(crate) fn encode_params_sized<'a, T: TokenSeq<'a>>(token: &T, size: usize) -> Vec<u8> {
+    if T::IS_TUPLE {
+        encode_sized(token, size)
+    } else {
+        encode_single_sized(token, size)
+    }
+}
+
+/// Encode a tuple as ABI function params into an existing encoder.
+///
+/// See [`encode_into`] for more details.
+#[inline]
+pub fn encode_params_into<'a, T: TokenSeq<'a>>(token: &T, enc: &mut Encoder) {
+    if T::IS_TUPLE {
+        encode_into(token, enc)
+    } else {
+        encode_single_into(token, enc)
+    }
+}
+
+/// Encode a tuple as ABI function params, prefixed with the given function
+/// selector. This is the format of calldata for a function call.
+#[inline]
+pub fn encode_with_selector<'a, T: TokenSeq<'a>>(selector: [u8; 4], token: &T) -> Vec<u8> {
+    let mut enc = Encoder::with_capacity(token.total_words() + 1);
+    // offsets are tracked separately from the buffer, so the selector does not
+    // affect them
+    enc.buf.extend_from_slice(&selector);
+    if T::IS_TUPLE {
+        enc.append_head_tail(token);
+    } else {
+        enc.append_head_tail(as_tuple(token));
+    }
+    enc.into_bytes()
+}
+
+/// Same as [`core::array::from_ref`].
+#[inline]
+const fn as_tuple<T>(token: &T) -> &(T,) {
+    // SAFETY: Converting `&T` to `&(T,)` is sound.
+    unsafe { &*(token as *const T).cast::<(T,)>() }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{sol_data, SolType};
+    use super::{encode_into, encode_params_into, encode_single_into, Encoder};
+    use crate::{sol_data, token::WordToken, SolType};
     use alloc::{borrow::ToOwned, string::ToString};
     use alloy_primitives::{Address, U256};
     use hex_literal::hex;
@@ -1111,4 +1222,78 @@ mod tests {
         assert_eq!(encoded_params.len() + 32, encoded.len());
         assert_eq!(encoded_params.len(), MyTy::encoded_size(&data));
     }
+
+    #[test]
+    fn encode_reuse_encoder() {
+        type MyTy = (sol_data::Uint<8>, sol_data::String);
+        let mut enc = Encoder::new();
+
+        let a = (1u8, "hello".to_string());
+        encode_into(&MyTy::tokenize(&a), &mut enc);
+        assert_eq!(enc.as_bytes(), MyTy::encode(&a));
+
+        let b = (
+            2u8,
+            "a somewhat longer string that spans multiple words".to_string(),
+        );
+        encode_params_into(&MyTy::tokenize(&b), &mut enc);
+        assert_eq!(enc.as_bytes(), MyTy::encode_params(&b));
+
+        encode_single_into(&MyTy::tokenize(&a), &mut enc);
+        assert_eq!(enc.as_bytes(), MyTy::encode_single(&a));
+        assert_eq!(enc.as_words().len(), enc.len());
+
+        enc.reset();
+        assert!(enc.is_empty());
+        assert!(enc.as_bytes().is_empty());
+    }
+
+    #[test]
+    fn encode_selector() {
+        type MyTy = (sol_data::Uint<8>, sol_data::String);
+        let selector = [0xde, 0xad, 0xbe, 0xef];
+        let value = (1u8, "hello".to_string());
+        let tokens = MyTy::tokenize(&value);
+
+        let encoded = crate::encode_with_selector(selector, &tokens);
+        assert_eq!(encoded[..4], selector);
+        assert_eq!(encoded[4..], MyTy::encode_params(&value));
+
+        let decoded = crate::decode_checked_selector(selector, &encoded, true).unwrap();
+        assert_eq!(MyTy::detokenize(decoded), value);
+
+        let err = crate::decode_checked_selector::<<MyTy as SolType>::TokenType<'_>>(
+            [0; 4], &encoded, true,
+        );
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Selector mismatch: expected 0x00000000, got 0xdeadbeef"
+        );
+        assert!(crate::decode_checked_selector::<(WordToken,)>(selector, &[0xde], false).is_err());
+
+        // non-tuple params are wrapped in a tuple
+        let array = sol_data::Array::<sol_data::Bool>::tokenize(&vec![true]);
+        let encoded = crate::encode_with_selector(selector, &array);
+        assert_eq!(
+            encoded[4..],
+            sol_data::Array::<sol_data::Bool>::encode_params(&vec![true])
+        );
+    }
+
+    #[test]
+    fn encode_nested_dynamic_sequences() {
+        type MyTy = (
+            sol_data::FixedArray<sol_data::String, 2>,
+            sol_data::Array<(sol_data::String, sol_data::Bool)>,
+            sol_data::String,
+        );
+        let value = (
+            ["a".to_string(), "b".to_string()],
+            vec![("c".to_string(), true)],
+            "d".to_string(),
+        );
+        let encoded = MyTy::encode(&value);
+        assert_eq!(encoded.len(), MyTy::encoded_size(&value));
+        assert_eq!(MyTy::decode(&encoded, true).unwrap(), value);
+    }
 }
@@ -28,9 +28,22 @@
 //! This is the least useful one. Most users will not need it.
 
 mod encoder;
-pub use encoder::{encode, encode_params, encode_single, Encoder};
+pub use encoder::{
+    encode, encode_into, encode_params, encode_params_into, encode_single, encode_single_into,
+    encode_with_selector, Encoder,
+};
+pub(crate) use encoder::{encode_params_sized, encode_single_sized, encode_sized};
 
 mod decoder;
-pub use decoder::{decode, decode_params, decode_single, Decoder};
+pub use decoder::{
+    decode, decode_checked_selector, decode_params, decode_params_with_config,
+    decode_prefix_with_config, decode_single, decode_single_with_config, decode_with_config,
+    Decoder, DecoderConfig,
+};
 
 pub mod token;
+
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "trace")]
+pub use trace::{decode_traced, DecodeTrace, TraceKind, TraceStep};
@@ -16,7 +16,7 @@
 //! - Tuples (T, U, V, ...)
 //! - Dynamic-length byte arrays `u8[]`
 
-use crate::{Decoder, Encoder, Result, Word};
+use crate::{DecodePathSegment, Decoder, Encoder, Result, Word};
 use alloc::vec::Vec;
 use alloy_primitives::{FixedBytes, I256, U256};
 use core::fmt;
@@ -240,7 +240,7 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
     #[inline]
     fn tail_words(&self) -> usize {
         if Self::DYNAMIC {
-            N
+            self.0.iter().map(|t| t.head_words() + t.tail_words()).sum()
         } else {
             0
         }
@@ -278,7 +278,10 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenSeq<'de> for FixedSeqToken<T,
     }
 
     fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
-        crate::impl_core::try_from_fn(|_| T::decode_from(dec)).map(Self)
+        crate::impl_core::try_from_fn(|i| {
+            T::decode_from(dec).map_err(|e| e.with_path_segment(DecodePathSegment::Index(i)))
+        })
+        .map(Self)
     }
 }
 
@@ -327,14 +330,17 @@ impl<'de, T: TokenType<'de>> TokenType<'de> for DynSeqToken<T> {
 
     fn decode_from(dec: &mut Decoder<'de>) -> Result<Self> {
         let mut child = dec.take_indirection()?;
-        let len = child.take_u32()? as usize;
+        let len = child.take_seq_len()?;
         // This appears to be an unclarity in the solidity spec. The spec
         // specifies that offsets are relative to the first word of
         // `enc(X)`. But known-good test vectors ha vrelative to the
         // word AFTER the array size
         let mut child = child.raw_child();
         (0..len)
-            .map(|_| T::decode_from(&mut child))
+            .map(|i| {
+                T::decode_from(&mut child)
+                    .map_err(|e| e.with_path_segment(DecodePathSegment::Index(i)))
+            })
             .collect::<Result<Vec<T>>>()
             .map(DynSeqToken)
     }
@@ -346,7 +352,11 @@ impl<'de, T: TokenType<'de>> TokenType<'de> for DynSeqToken<T> {
 
     #[inline]
     fn tail_words(&self) -> usize {
-        1 + self.0.iter().map(TokenType::total_words).sum::<usize>()
+        1 + self
+            .0
+            .iter()
+            .map(|t| t.head_words() + t.tail_words())
+            .sum::<usize>()
     }
 
     #[inline]
@@ -423,8 +433,9 @@ impl<'de: 'a, 'a> TokenType<'de> for PackedSeqToken<'a> {
     #[inline]
     fn decode_from(dec: &mut Decoder<'de>) -> Result<Self> {
         let mut child = dec.take_indirection()?;
-        let len = child.take_u32()? as usize;
-        let bytes = child.peek_len(len)?;
+        let len = child.take_seq_len()?;
+        // checks the padding when validating
+        let bytes = child.take_slice(len)?;
         Ok(PackedSeqToken(bytes))
     }
 
@@ -450,7 +461,7 @@ impl<'de: 'a, 'a> TokenType<'de> for PackedSeqToken<'a> {
     }
 }
 
-impl PackedSeqToken<'_> {
+impl<'a> PackedSeqToken<'a> {
     /// Consumes `self` to return the underlying vector.
     // https://github.com/rust-lang/rust-clippy/issues/4979
     #[allow(clippy::missing_const_for_fn)]
@@ -464,6 +475,20 @@ impl PackedSeqToken<'_> {
     pub const fn as_slice(&self) -> &[u8] {
         self.0
     }
+
+    /// Returns the underlying slice, with the lifetime of the original
+    /// buffer.
+    #[inline]
+    pub const fn into_slice(self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Returns the underlying slice as a string, with the lifetime of the
+    /// original buffer, if it is valid UTF-8.
+    #[inline]
+    pub fn as_str(&self) -> Result<&'a str> {
+        core::str::from_utf8(self.0).map_err(|_| crate::Error::type_check_fail(self.0, "string"))
+    }
 }
 
 macro_rules! tuple_impls {
@@ -515,7 +540,7 @@ macro_rules! tuple_impls {
             #[inline]
             fn total_words(&self) -> usize {
                 let ($($ty,)+) = self;
-                0 $( + $ty.total_words() )+
+                0 $( + $ty.head_words() + $ty.tail_words() )+
             }
 
             fn head_append(&self, enc: &mut Encoder) {
@@ -566,9 +591,12 @@ macro_rules! tuple_impls {
             }
 
             fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
-                Ok(($(
-                    <$ty as TokenType>::decode_from(dec)?,
-                )+))
+                let mut i = 0usize;
+                Ok(($({
+                    i += 1;
+                    <$ty as TokenType>::decode_from(dec)
+                        .map_err(|e| e.with_path_segment(DecodePathSegment::Field(i - 1)))?
+                },)+))
             }
         }
     };
@@ -0,0 +1,225 @@
+//! Decoder tracing.
+//!
+//! Only available with the `trace` feature enabled.
+
+use crate::{token::TokenSeq, Decoder, DecoderConfig, Result};
+use alloc::{string::String, vec::Vec};
+use core::{
+    cell::{Ref, RefCell},
+    fmt::Write,
+};
+
+/// The kind of a [`TraceStep`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceKind {
+    /// A word was read as a value.
+    Word,
+    /// A pointer word was followed to an absolute offset in the input.
+    Pointer {
+        /// The absolute offset the pointer resolved to.
+        target: usize,
+    },
+    /// A word was read as the length of a dynamic sequence.
+    SeqLen {
+        /// The declared length.
+        len: usize,
+    },
+    /// The contents of a `bytes` or `string` were read.
+    Bytes {
+        /// The number of bytes read, excluding padding.
+        len: usize,
+    },
+}
+
+/// A single step taken by a [`Decoder`] while decoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceStep {
+    /// The absolute offset in the input at which the step was taken.
+    pub position: usize,
+    /// The number of indirections followed to reach this step.
+    pub depth: usize,
+    /// What was read.
+    pub kind: TraceKind,
+}
+
+/// Records how a [`Decoder`] resolved each head and tail while decoding.
+///
+/// Attach it to a decoder with [`Decoder::with_trace`], or use
+/// [`decode_traced`].
+#[derive(Debug, Default)]
+pub struct DecodeTrace {
+    steps: RefCell<Vec<TraceStep>>,
+}
+
+impl DecodeTrace {
+    /// Creates a new, empty trace.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            steps: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Records a step.
+    #[inline]
+    pub(crate) fn record(&self, step: TraceStep) {
+        self.steps.borrow_mut().push(step);
+    }
+
+    /// Returns the recorded steps, in the order they were taken.
+    #[inline]
+    pub fn steps(&self) -> Ref<'_, [TraceStep]> {
+        Ref::map(self.steps.borrow(), Vec::as_slice)
+    }
+
+    /// Consumes the trace, returning the recorded steps.
+    #[inline]
+    pub fn into_steps(self) -> Vec<TraceStep> {
+        self.steps.into_inner()
+    }
+
+    /// Renders an annotated hex dump of `data`, one word per line, alongside
+    /// the steps that touched each word.
+    ///
+    /// Words that were never read are annotated with `(unused)`.
+    pub fn annotate(&self, data: &[u8]) -> String {
+        let steps = self.steps();
+        let mut out = String::new();
+        for (i, chunk) in data.chunks(32).enumerate() {
+            let start = i * 32;
+            let end = start + chunk.len();
+            write!(out, "0x{start:04x}: {:<64}", hex::encode(chunk)).unwrap();
+
+            let mut any = false;
+            for step in steps.iter() {
+                let len = match step.kind {
+                    TraceKind::Bytes { len } => len,
+                    _ => 32,
+                };
+                if step.position >= end || step.position + len.max(1) <= start {
+                    continue
+                }
+                out.push_str(if any { ", " } else { "  " });
+                any = true;
+                for _ in 0..step.depth {
+                    out.push('>');
+                }
+                if step.depth > 0 {
+                    out.push(' ');
+                }
+                match step.kind {
+                    TraceKind::Word => out.push_str("word"),
+                    TraceKind::Pointer { target } => {
+                        write!(out, "pointer -> 0x{target:04x}").unwrap()
+                    }
+                    TraceKind::SeqLen { len } => write!(out, "length {len}").unwrap(),
+                    TraceKind::Bytes { len } if step.position >= start => {
+                        write!(out, "bytes ({len})").unwrap()
+                    }
+                    TraceKind::Bytes { .. } => out.push_str("bytes (cont.)"),
+                }
+            }
+            if !any {
+                out.push_str("  (unused)");
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Decodes ABI compliant vector of bytes into vector of tokens described by
+/// types param, recording the steps taken in `trace`.
+///
+/// Steps are recorded even if decoding fails, which makes it possible to see
+/// how far the decoder got.
+pub fn decode_traced<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    config: DecoderConfig,
+    trace: &'de DecodeTrace,
+) -> Result<T> {
+    Decoder::with_config(data, config)
+        .with_trace(trace)
+        .decode_sequence::<T>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sol_data, SolType};
+
+    #[test]
+    fn trace_dynamic() {
+        type MyTy = (sol_data::Uint<8>, sol_data::Array<sol_data::String>);
+        let value = (1, vec!["hello".into()]);
+        let encoded = MyTy::encode(&value);
+
+        let trace = DecodeTrace::new();
+        let decoded = decode_traced::<<MyTy as SolType>::TokenType<'_>>(
+            &encoded,
+            DecoderConfig::default(),
+            &trace,
+        )
+        .unwrap();
+        assert_eq!(MyTy::detokenize(decoded), value);
+
+        assert_eq!(
+            trace.steps()[..],
+            [
+                TraceStep {
+                    position: 0x00,
+                    depth: 0,
+                    kind: TraceKind::Word
+                },
+                TraceStep {
+                    position: 0x20,
+                    depth: 0,
+                    kind: TraceKind::Pointer { target: 0x40 }
+                },
+                TraceStep {
+                    position: 0x40,
+                    depth: 1,
+                    kind: TraceKind::SeqLen { len: 1 }
+                },
+                TraceStep {
+                    position: 0x60,
+                    depth: 1,
+                    kind: TraceKind::Pointer { target: 0x80 }
+                },
+                TraceStep {
+                    position: 0x80,
+                    depth: 2,
+                    kind: TraceKind::SeqLen { len: 5 }
+                },
+                TraceStep {
+                    position: 0xa0,
+                    depth: 2,
+                    kind: TraceKind::Bytes { len: 5 }
+                },
+            ]
+        );
+
+        let annotated = trace.annotate(&encoded);
+        let lines = annotated.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].ends_with("  pointer -> 0x0040"), "{annotated}");
+        assert!(lines[4].ends_with("  >> length 5"), "{annotated}");
+        assert!(lines[5].ends_with("  >> bytes (5)"), "{annotated}");
+    }
+
+    #[test]
+    fn trace_error() {
+        type MyTy = (sol_data::Bool, sol_data::String);
+        let encoded = MyTy::encode(&(true, "hello".into()));
+
+        let trace = DecodeTrace::new();
+        let res = decode_traced::<<MyTy as SolType>::TokenType<'_>>(
+            &encoded[..0x40],
+            DecoderConfig::default(),
+            &trace,
+        );
+        assert!(res.is_err());
+        assert_eq!(trace.steps().len(), 2);
+        assert!(trace.annotate(&encoded).contains("(unused)"));
+    }
+}
@@ -7,7 +7,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use alloc::{borrow::Cow, string::String};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::fmt;
 
 /// ABI result type.
@@ -27,9 +32,64 @@ pub enum Error {
     /// Overran deserialization buffer.
     Overrun,
 
+    /// An error occurred while decoding an ABI blob.
+    ///
+    /// Wraps the underlying error with the location at which it occurred.
+    Decode {
+        /// The byte offset in the input at which the error occurred.
+        offset: usize,
+        /// The kind of token that was being decoded, e.g. `"word"` or
+        /// `"pointer"`.
+        expected: &'static str,
+        /// The path within the decoded type, from the outermost value inwards.
+        path: Vec<DecodePathSegment>,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+
     /// Validation reserialization did not match input.
     ReserMismatch,
 
+    /// A Rust value does not fit in the Solidity type it is being encoded as.
+    OutOfRange {
+        /// The Solidity type the value was being encoded as.
+        expected_type: Cow<'static, str>,
+        /// The value, formatted as a string.
+        value: String,
+    },
+
+    /// The calldata did not start with the expected function selector.
+    SelectorMismatch {
+        /// The expected selector.
+        expected: [u8; 4],
+        /// Hex-encoded first (up to) 4 bytes of the calldata.
+        data: String,
+    },
+
+    /// The first topic of a log did not match the event's signature hash.
+    EventSignatureMismatch {
+        /// The expected signature hash.
+        expected: alloy_primitives::B256,
+        /// The first topic of the log.
+        got: alloy_primitives::B256,
+    },
+
+    /// The number of topics of a log did not match the event's.
+    TopicLengthMismatch {
+        /// The expected number of topics.
+        expected: usize,
+        /// The actual number of topics.
+        actual: usize,
+    },
+
+    /// The input contained bytes past the end of the encoded data.
+    TrailingBytes {
+        /// The number of bytes consumed by the encoded data.
+        consumed: usize,
+        /// The length of the input.
+        len: usize,
+    },
+
     /// Invalid enum value.
     InvalidEnumValue {
         /// The name of the enum.
@@ -48,6 +108,30 @@ pub enum Error {
         selector: alloy_primitives::FixedBytes<4>,
     },
 
+    /// Unknown event signature hash.
+    UnknownEventSignature {
+        /// The type name.
+        name: &'static str,
+        /// The unknown signature hash, i.e. the first topic of the log.
+        hash: alloy_primitives::B256,
+    },
+
+    /// The packed encoding of a type cannot be decoded unambiguously.
+    AmbiguousPacked {
+        /// The Solidity type we failed to decode.
+        expected_type: Cow<'static, str>,
+    },
+
+    /// A [`DecoderConfig`](crate::DecoderConfig) limit was exceeded.
+    LimitExceeded {
+        /// The name of the limit.
+        limit: &'static str,
+        /// The value that exceeded the limit.
+        value: usize,
+        /// The maximum allowed value.
+        max: usize,
+    },
+
     /// Hex error.
     FromHexError(hex::FromHexError),
 
@@ -59,6 +143,7 @@ pub enum Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            Self::Decode { source, .. } => Some(source.as_ref()),
             Self::FromHexError(e) => Some(e),
             _ => None,
         }
@@ -76,7 +161,51 @@ impl fmt::Display for Error {
                 "Type check failed for \"{expected_type}\" with data: {data}",
             ),
             Self::Overrun => f.write_str("Buffer overrun while deserializing"),
+            Self::Decode {
+                offset,
+                expected,
+                path,
+                source,
+            } => {
+                write!(
+                    f,
+                    "{source}, while decoding {expected} at offset {offset:#x}"
+                )?;
+                if !path.is_empty() {
+                    f.write_str(" in `")?;
+                    for (i, segment) in path.iter().enumerate() {
+                        match segment {
+                            DecodePathSegment::Field(n) if i == 0 => write!(f, "param[{n}]")?,
+                            DecodePathSegment::Field(n) => write!(f, ".field[{n}]")?,
+                            DecodePathSegment::Index(n) => write!(f, "[{n}]")?,
+                        }
+                    }
+                    f.write_str("`")?;
+                }
+                Ok(())
+            }
             Self::ReserMismatch => f.write_str("Reserialization did not match original"),
+            Self::OutOfRange {
+                expected_type,
+                value,
+            } => write!(f, "Value {value} is out of range for \"{expected_type}\""),
+            Self::SelectorMismatch { expected, data } => write!(
+                f,
+                "Selector mismatch: expected 0x{}, got 0x{data}",
+                hex::encode(expected)
+            ),
+            Self::EventSignatureMismatch { expected, got } => write!(
+                f,
+                "Event signature mismatch: expected {expected}, got {got}"
+            ),
+            Self::TopicLengthMismatch { expected, actual } => write!(
+                f,
+                "Topic list length mismatch: expected `{expected}` topics, got `{actual}`"
+            ),
+            Self::TrailingBytes { consumed, len } => write!(
+                f,
+                "Trailing bytes after encoded data: consumed `{consumed}` of `{len}` bytes"
+            ),
             Self::InvalidEnumValue { name, value, max } => write!(
                 f,
                 "`{value}` is not a valid {name} enum value (max: `{max}`)"
@@ -84,6 +213,16 @@ impl fmt::Display for Error {
             Self::UnknownSelector { name, selector } => {
                 write!(f, "Unknown selector `{selector}` for {name}")
             }
+            Self::UnknownEventSignature { name, hash } => {
+                write!(f, "Unknown event signature `{hash}` for {name}")
+            }
+            Self::AmbiguousPacked { expected_type } => write!(
+                f,
+                "Packed encoding of \"{expected_type}\" is ambiguous and cannot be decoded"
+            ),
+            Self::LimitExceeded { limit, value, max } => {
+                write!(f, "Decoder {limit} limit exceeded: `{value}` > `{max}`")
+            }
             Self::FromHexError(e) => e.fmt(f),
             Self::Other(e) => f.write_str(e),
         }
@@ -116,6 +255,73 @@ impl Error {
         }
     }
 
+    /// Instantiates an [`Error::SelectorMismatch`] with the expected selector
+    /// and the first (up to) 4 bytes of `data`.
+    #[inline]
+    pub fn selector_mismatch(expected: [u8; 4], data: &[u8]) -> Self {
+        Self::SelectorMismatch {
+            expected,
+            data: hex::encode(&data[..data.len().min(4)]),
+        }
+    }
+
+    /// Instantiates an [`Error::OutOfRange`] with the provided value.
+    #[inline]
+    pub fn out_of_range(
+        value: impl fmt::Display,
+        expected_type: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self::OutOfRange {
+            expected_type: expected_type.into(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Instantiates a [`Error::Decode`] at the given offset, wrapping `source`.
+    ///
+    /// If `source` is already a [`Error::Decode`], it is returned unchanged,
+    /// as it carries the more precise location.
+    #[inline]
+    pub fn decode_at(offset: usize, expected: &'static str, source: Self) -> Self {
+        match source {
+            Self::Decode { .. } => source,
+            source => Self::Decode {
+                offset,
+                expected,
+                path: Vec::new(),
+                source: Box::new(source),
+            },
+        }
+    }
+
+    /// Prepends a segment to the path of a [`Error::Decode`]. Other errors are
+    /// returned unchanged.
+    #[doc(hidden)]
+    #[inline]
+    pub fn with_path_segment(mut self, segment: DecodePathSegment) -> Self {
+        if let Self::Decode { path, .. } = &mut self {
+            path.insert(0, segment);
+        }
+        self
+    }
+
+    /// Returns the innermost error, unwrapping any [`Error::Decode`] context.
+    #[inline]
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            Self::Decode { source, .. } => source.root_cause(),
+            e => e,
+        }
+    }
+
+    /// Instantiates a [`Error::AmbiguousPacked`] with the provided type name.
+    #[inline]
+    pub fn ambiguous_packed(expected_type: impl Into<Cow<'static, str>>) -> Self {
+        Self::AmbiguousPacked {
+            expected_type: expected_type.into(),
+        }
+    }
+
     /// Instantiates a [`Error::UnknownSelector`] with the provided data.
     #[inline]
     pub fn unknown_selector(name: &'static str, selector: [u8; 4]) -> Self {
@@ -131,3 +337,15 @@ impl From<hex::FromHexError> for Error {
         Self::FromHexError(value)
     }
 }
+
+/// A segment of the path to a value within a decoded type.
+///
+/// See [`Error::Decode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodePathSegment {
+    /// The n-th member of a tuple or struct. At the top level, this is the n-th
+    /// parameter.
+    Field(usize),
+    /// The n-th element of an array.
+    Index(usize),
+}
@@ -0,0 +1,158 @@
+//! Fuzzing and differential testing utilities.
+//!
+//! This module is only available with the `arbitrary` feature enabled. It
+//! provides generators for random values of any [`SolType`] and helpers that
+//! assert that encoding and decoding round-trip.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_sol_types::{fuzz::{self, arbitrary::Unstructured}, sol_data::*};
+//!
+//! type MyTy = (Uint<24>, Array<String>, FixedBytes<4>);
+//!
+//! let mut u = Unstructured::new(&[0x42; 1024]);
+//! let value = fuzz::arbitrary_value::<MyTy>(&mut u).unwrap();
+//! fuzz::assert_roundtrip::<MyTy>(&value);
+//! ```
+
+use crate::SolType;
+use arbitrary::{Arbitrary, Unstructured};
+use core::fmt::Debug;
+
+#[doc(no_inline)]
+pub use arbitrary;
+
+/// Generates a random value of the given Solidity type.
+///
+/// The value is normalized so that it can be represented by `T`. For example,
+/// a `uint24` is represented by a `u32`, whose upper 8 bits are cleared.
+#[inline]
+pub fn arbitrary_value<'a, T>(u: &mut Unstructured<'a>) -> arbitrary::Result<T::RustType>
+where
+    T: SolType,
+    T::RustType: Arbitrary<'a>,
+{
+    let value = T::RustType::arbitrary(u)?;
+    Ok(T::detokenize(T::tokenize(&value)))
+}
+
+/// Generates a random token sequence for the given Solidity type.
+///
+/// The tokens borrow from the generated value, which is stored in `value`.
+#[inline]
+pub fn arbitrary_tokens<'a, 'v, T>(
+    u: &mut Unstructured<'a>,
+    value: &'v mut Option<T::RustType>,
+) -> arbitrary::Result<T::TokenType<'v>>
+where
+    T: SolType,
+    T::RustType: Arbitrary<'a>,
+{
+    let value: &'v T::RustType = value.insert(arbitrary_value::<T>(u)?);
+    Ok(T::tokenize(value))
+}
+
+/// Asserts that `value` round-trips through ABI encoding and decoding, in both
+/// single and params mode.
+///
+/// Also asserts that the encoded length matches
+/// [`abi_encoded_size`](SolType::abi_encoded_size) and that
+/// [`value_check`](SolType::value_check) accepts the value.
+///
+/// # Panics
+///
+/// Panics if any of the above do not hold.
+#[track_caller]
+pub fn assert_roundtrip<T>(value: &T::RustType)
+where
+    T: SolType,
+    T::RustType: PartialEq + Debug,
+{
+    let name = T::sol_type_name();
+    T::value_check(value).unwrap_or_else(|e| panic!("value check failed for {name}: {e}"));
+
+    let encoded = T::encode_single(value);
+    assert_eq!(
+        encoded.len(),
+        T::abi_encoded_size(value),
+        "encoded size mismatch for {name}"
+    );
+    let decoded = T::decode_single(&encoded, true)
+        .unwrap_or_else(|e| panic!("failed to decode {name}: {e}\n{}", hex::encode(&encoded)));
+    assert_eq!(decoded, *value, "single round-trip mismatch for {name}");
+
+    let encoded = crate::encode_params(&(T::tokenize(value),));
+    let (decoded,) = crate::decode_params::<(T::TokenType<'_>,)>(&encoded, true)
+        .unwrap_or_else(|e| panic!("failed to decode {name} params: {e}"));
+    assert_eq!(
+        T::detokenize(decoded),
+        *value,
+        "params round-trip mismatch for {name}"
+    );
+}
+
+/// Asserts that if `data` decodes as `T`, then re-encoding and decoding again
+/// yields the same value. Useful as the body of a fuzz target that is fed
+/// arbitrary bytes.
+///
+/// Returns the decoded value, if any.
+///
+/// # Panics
+///
+/// Panics if the decoded value does not round-trip.
+#[track_caller]
+pub fn assert_decode_roundtrip<T>(data: &[u8]) -> Option<T::RustType>
+where
+    T: SolType,
+    T::RustType: PartialEq + Debug,
+{
+    let token = crate::decode_single::<T::TokenType<'_>>(data, false).ok()?;
+    let value = T::detokenize(token);
+    assert_roundtrip::<T>(&value);
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sol_data::*;
+
+    #[test]
+    fn roundtrip_arbitrary() {
+        type MyTy = (
+            Bool,
+            Int<24>,
+            Uint<256>,
+            Ufixed<128, 18>,
+            Address,
+            Function,
+            Array<(String, Bytes)>,
+            FixedArray<FixedBytes<3>, 2>,
+        );
+
+        let data = (0..4096u32)
+            .map(|i| (i * 7919 % 251) as u8)
+            .collect::<alloc::vec::Vec<_>>();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let value = arbitrary_value::<MyTy>(&mut u).unwrap();
+            assert_roundtrip::<MyTy>(&value);
+        }
+
+        let mut value = None;
+        let tokens = arbitrary_tokens::<Array<Uint<8>>>(&mut Unstructured::new(&data), &mut value);
+        assert!(tokens.is_ok());
+    }
+
+    #[test]
+    fn roundtrip_decode() {
+        type MyTy = (Uint<8>, String);
+        let encoded = MyTy::encode_single(&(1, "hello".into()));
+        assert_eq!(
+            assert_decode_roundtrip::<MyTy>(&encoded),
+            Some((1, "hello".into()))
+        );
+        assert_eq!(assert_decode_roundtrip::<MyTy>(&encoded[..40]), None);
+    }
+}
@@ -168,22 +168,32 @@ extern crate alloc;
 mod macros;
 
 mod coder;
+#[doc(hidden)]
+pub use coder::Decoder;
 pub use coder::{
-    decode, decode_params, decode_single, encode, encode_params, encode_single,
+    decode, decode_checked_selector, decode_params, decode_params_with_config,
+    decode_prefix_with_config, decode_single, decode_single_with_config, decode_with_config,
+    encode, encode_into, encode_params, encode_params_into, encode_single, encode_single_into,
+    encode_with_selector,
     token::{self, TokenType},
+    DecoderConfig, Encoder,
 };
-#[doc(hidden)]
-pub use coder::{Decoder, Encoder};
+#[cfg(feature = "trace")]
+pub use coder::{decode_traced, DecodeTrace, TraceKind, TraceStep};
+
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
 
 mod errors;
-pub use errors::{Error, Result};
+pub use errors::{DecodePathSegment, Error, Result};
 
 mod impl_core;
 
 mod types;
 pub use types::{
-    data_type as sol_data, ContractError, Encodable, EventTopic, Panic, PanicKind, Revert,
-    Selectors, SolCall, SolEnum, SolError, SolEvent, SolInterface, SolStruct, SolType, TopicList,
+    data_type as sol_data, ContractError, Encodable, EventTopic, FixedPoint, Panic, PanicKind,
+    Revert, Selectors, SolCall, SolConstructor, SolEnum, SolError, SolEvent, SolInterface,
+    SolMapping, SolStruct, SolType, StateMutability, StateVariable, TopicList, Visibility,
 };
 
 pub mod utils;
@@ -200,20 +210,51 @@ pub use alloy_sol_macro::sol;
 // Not public API.
 #[doc(hidden)]
 pub mod private {
-    pub use super::utils::{just_ok, next_multiple_of_32, words_for, words_for_len};
+    pub use super::utils::{
+        concat_bytes, concat_len, concat_str, const_selector, just_ok, next_multiple_of_32,
+        sort_selectors, words_for, words_for_len,
+    };
     pub use alloc::{
         borrow::{Borrow, Cow, ToOwned},
         string::{String, ToString},
         vec::Vec,
     };
-    pub use alloy_primitives::{bytes, keccak256, Bytes, FixedBytes, B256, U256};
+    pub use alloy_primitives::{
+        bytes, keccak256, private::const_keccak256, Address, Bytes, FixedBytes, B256, I256, U256,
+    };
     pub use core::{convert::From, default::Default, option::Option, result::Result};
 
     pub use Option::{None, Some};
     pub use Result::{Err, Ok};
 
+    #[cfg(feature = "serde")]
+    pub use {alloy_primitives::serde_hex, serde};
+
+    #[cfg(feature = "arbitrary")]
+    pub use {alloy_primitives::private::proptest, arbitrary};
+
     #[inline(always)]
     pub const fn u256(n: u64) -> U256 {
         U256::from_limbs([n, 0, 0, 0])
     }
+
+    /// Compile-time information about the structs, enums and UDVTs generated
+    /// by [`sol!`](crate::sol!), for other invocations which refer to them
+    /// with `#[sol(use ...)]`.
+    pub trait SolTypeInfo {
+        /// The type in function, error and event signatures.
+        const ABI_TYPE: &'static str;
+
+        /// The type in the EIP-712 encoded type of the structs which contain
+        /// it: the name of a struct, or the underlying type of an enum or
+        /// UDVT.
+        const EIP712_TYPE: &'static str;
+
+        /// The EIP-712 root types of this type, if it is a struct, and of all
+        /// the structs it references.
+        #[inline]
+        fn eip712_components() -> Vec<Cow<'static, str>> {
+            Vec::new()
+        }
+    }
 }
@@ -6,10 +6,13 @@
 
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
-use crate::{token::*, utils, Encodable, Result, SolType, Word};
+use crate::{
+    token::*, utils, DecodePathSegment, Decoder, DecoderConfig, Encodable, FixedPoint, Result,
+    SolType, Word,
+};
 use alloc::{borrow::Cow, string::String as RustString, vec::Vec};
-use alloy_primitives::{keccak256, Address as RustAddress, I256, U256};
-use core::{borrow::Borrow, fmt::*, hash::Hash, marker::PhantomData, ops::*};
+use alloy_primitives::{keccak256, Address as RustAddress, Function as RustFunction, I256, U256};
+use core::{borrow::Borrow, fmt::*, hash::Hash, iter::FusedIterator, marker::PhantomData, ops::*};
 
 /// Bool - `bool`
 pub struct Bool;
@@ -25,11 +28,18 @@ impl SolType for Bool {
     type RustType = bool;
     type TokenType<'a> = WordToken;
 
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(1);
+
     #[inline]
     fn sol_type_name() -> Cow<'static, str> {
         "bool".into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(rust, f)
+    }
+
     #[inline]
     fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
         if utils::check_bool(token.0) {
@@ -53,6 +63,16 @@ impl SolType for Bool {
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         out.push(*rust as u8);
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)?;
+        match data[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Self::type_check_fail(data)),
+        }
+    }
 }
 
 /// Int - `intX`
@@ -76,11 +96,18 @@ where
     type RustType = <IntBitCount<BITS> as SupportedInt>::Int;
     type TokenType<'a> = WordToken;
 
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(BITS / 8);
+
     #[inline]
     fn sol_type_name() -> Cow<'static, str> {
         IntBitCount::<BITS>::INT_NAME.into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(rust, f)
+    }
+
     #[inline]
     fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
         if BITS == 256 {
@@ -106,6 +133,16 @@ where
         IntBitCount::<BITS>::detokenize_int(token)
     }
 
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> Result<()> {
+        // truncation is lossless iff the value fits
+        if Self::detokenize(Encodable::<Self>::to_tokens(rust)) == *rust {
+            Ok(())
+        } else {
+            Err(crate::Error::out_of_range(rust, Self::sol_type_name()))
+        }
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         Encodable::<Self>::to_tokens(rust).0
@@ -115,6 +152,15 @@ where
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         IntBitCount::<BITS>::encode_packed_to_int(*rust, out)
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)?;
+        // sign extend to a full word
+        let mut word = [(data[0] & 0x80 == 0x80) as u8 * 0xff; 32];
+        word[IntBitCount::<BITS>::WORD_MSB..].copy_from_slice(data);
+        Ok(Self::detokenize(WordToken::new(word)))
+    }
 }
 
 /// Uint - `uintX`
@@ -138,11 +184,18 @@ where
     type RustType = <IntBitCount<BITS> as SupportedInt>::Uint;
     type TokenType<'a> = WordToken;
 
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(BITS / 8);
+
     #[inline]
     fn sol_type_name() -> Cow<'static, str> {
         IntBitCount::<BITS>::UINT_NAME.into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(rust, f)
+    }
+
     #[inline]
     fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
         let sli = &token.0[..<IntBitCount<BITS> as SupportedInt>::WORD_MSB];
@@ -158,6 +211,16 @@ where
         IntBitCount::<BITS>::detokenize_uint(token)
     }
 
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> Result<()> {
+        // truncation is lossless iff the value fits
+        if Self::detokenize(Encodable::<Self>::to_tokens(rust)) == *rust {
+            Ok(())
+        } else {
+            Err(crate::Error::out_of_range(rust, Self::sol_type_name()))
+        }
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         Encodable::<Self>::to_tokens(rust).0
@@ -167,6 +230,148 @@ where
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         IntBitCount::<BITS>::encode_packed_to_uint(*rust, out)
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)?;
+        let mut word = Word::ZERO;
+        word[IntBitCount::<BITS>::WORD_MSB..].copy_from_slice(data);
+        Ok(Self::detokenize(WordToken(word)))
+    }
+}
+
+/// Fixed - `fixedMxN`
+pub struct Fixed<const BITS: usize, const DECIMALS: usize>;
+
+impl<T, const BITS: usize, const DECIMALS: usize> Encodable<Fixed<BITS, DECIMALS>> for T
+where
+    T: Borrow<FixedPoint<<IntBitCount<BITS> as SupportedInt>::Int, DECIMALS>>,
+    IntBitCount<BITS>: SupportedInt,
+    DecimalCount<DECIMALS>: SupportedDecimals,
+{
+    #[inline]
+    fn to_tokens(&self) -> WordToken {
+        IntBitCount::<BITS>::tokenize_int(self.borrow().0)
+    }
+}
+
+impl<const BITS: usize, const DECIMALS: usize> SolType for Fixed<BITS, DECIMALS>
+where
+    IntBitCount<BITS>: SupportedInt,
+    DecimalCount<DECIMALS>: SupportedDecimals,
+{
+    type RustType = FixedPoint<<IntBitCount<BITS> as SupportedInt>::Int, DECIMALS>;
+    type TokenType<'a> = WordToken;
+
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(BITS / 8);
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        format!("fixed{BITS}x{DECIMALS}").into()
+    }
+
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(rust, f)
+    }
+
+    #[inline]
+    fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
+        Int::<BITS>::type_check(token)
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        FixedPoint(Int::<BITS>::detokenize(token))
+    }
+
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> Result<()> {
+        Int::<BITS>::value_check(&rust.0)
+            .map_err(|_| crate::Error::out_of_range(rust, Self::sol_type_name()))
+    }
+
+    #[inline]
+    fn eip712_data_word(rust: &Self::RustType) -> Word {
+        Encodable::<Self>::to_tokens(rust).0
+    }
+
+    #[inline]
+    fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
+        Int::<BITS>::encode_packed_to(&rust.0, out)
+    }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        Int::<BITS>::decode_packed(data).map(FixedPoint)
+    }
+}
+
+/// Ufixed - `ufixedMxN`
+pub struct Ufixed<const BITS: usize, const DECIMALS: usize>;
+
+impl<T, const BITS: usize, const DECIMALS: usize> Encodable<Ufixed<BITS, DECIMALS>> for T
+where
+    T: Borrow<FixedPoint<<IntBitCount<BITS> as SupportedInt>::Uint, DECIMALS>>,
+    IntBitCount<BITS>: SupportedInt,
+    DecimalCount<DECIMALS>: SupportedDecimals,
+{
+    #[inline]
+    fn to_tokens(&self) -> WordToken {
+        IntBitCount::<BITS>::tokenize_uint(self.borrow().0)
+    }
+}
+
+impl<const BITS: usize, const DECIMALS: usize> SolType for Ufixed<BITS, DECIMALS>
+where
+    IntBitCount<BITS>: SupportedInt,
+    DecimalCount<DECIMALS>: SupportedDecimals,
+{
+    type RustType = FixedPoint<<IntBitCount<BITS> as SupportedInt>::Uint, DECIMALS>;
+    type TokenType<'a> = WordToken;
+
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(BITS / 8);
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        format!("ufixed{BITS}x{DECIMALS}").into()
+    }
+
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(rust, f)
+    }
+
+    #[inline]
+    fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
+        Uint::<BITS>::type_check(token)
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        FixedPoint(Uint::<BITS>::detokenize(token))
+    }
+
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> Result<()> {
+        Uint::<BITS>::value_check(&rust.0)
+            .map_err(|_| crate::Error::out_of_range(rust, Self::sol_type_name()))
+    }
+
+    #[inline]
+    fn eip712_data_word(rust: &Self::RustType) -> Word {
+        Encodable::<Self>::to_tokens(rust).0
+    }
+
+    #[inline]
+    fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
+        Uint::<BITS>::encode_packed_to(&rust.0, out)
+    }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        Uint::<BITS>::decode_packed(data).map(FixedPoint)
+    }
 }
 
 /// Address - `address`
@@ -183,11 +388,18 @@ impl SolType for Address {
     type RustType = RustAddress;
     type TokenType<'a> = WordToken;
 
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(20);
+
     #[inline]
     fn sol_type_name() -> Cow<'static, str> {
         "address".into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(rust, f)
+    }
+
     #[inline]
     fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
         RustAddress::from_word(token.0)
@@ -211,6 +423,69 @@ impl SolType for Address {
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         out.extend_from_slice(rust.as_ref());
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)?;
+        Ok(RustAddress::from_slice(data))
+    }
+}
+
+/// Function - `function`
+pub struct Function;
+
+impl<T: Borrow<[u8; 24]>> Encodable<Function> for T {
+    #[inline]
+    fn to_tokens(&self) -> WordToken {
+        WordToken(RustFunction::new(*self.borrow()).into_word())
+    }
+}
+
+impl SolType for Function {
+    type RustType = RustFunction;
+    type TokenType<'a> = WordToken;
+
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(24);
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        "function".into()
+    }
+
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(rust, f)
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        RustFunction::from_word(token.0)
+    }
+
+    #[inline]
+    fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
+        if utils::check_zeroes(&token.0[24..]) {
+            Ok(())
+        } else {
+            Err(Self::type_check_fail(token.as_slice()))
+        }
+    }
+
+    #[inline]
+    fn eip712_data_word(rust: &Self::RustType) -> Word {
+        rust.into_word()
+    }
+
+    #[inline]
+    fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
+        out.extend_from_slice(rust.as_ref());
+    }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)?;
+        Ok(RustFunction::from_slice(data))
+    }
 }
 
 /// Bytes - `bytes`
@@ -234,6 +509,11 @@ impl SolType for Bytes {
         "bytes".into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "0x{}", hex::encode(rust))
+    }
+
     #[inline]
     fn encoded_size(_data: &Self::RustType) -> usize {
         32 + utils::padded_len(_data.borrow())
@@ -258,6 +538,11 @@ impl SolType for Bytes {
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         out.extend_from_slice(rust);
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        Ok(data.to_vec())
+    }
 }
 
 /// Array - `T[]`
@@ -296,6 +581,10 @@ impl<T: SolType> SolType for Array<T> {
         format!("{}[]", T::sol_type_name()).into()
     }
 
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        utils::fmt_seq::<T>("[", rust.iter(), "]", f)
+    }
+
     #[inline]
     fn encoded_size(rust: &Self::RustType) -> usize {
         let data = rust;
@@ -313,6 +602,11 @@ impl<T: SolType> SolType for Array<T> {
         token.0.into_iter().map(T::detokenize).collect()
     }
 
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> Result<()> {
+        rust.iter().try_for_each(T::value_check)
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         let mut encoded = Vec::new();
@@ -328,8 +622,141 @@ impl<T: SolType> SolType for Array<T> {
             T::encode_packed_to(item, out);
         }
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        match T::PACKED_ENCODED_SIZE {
+            Some(size) if size > 0 && data.len() % size == 0 => {
+                data.chunks_exact(size).map(T::decode_packed).collect()
+            }
+            Some(_) => Err(Self::type_check_fail(data)),
+            None => Err(crate::Error::ambiguous_packed(Self::sol_type_name())),
+        }
+    }
 }
 
+impl<T: SolType> Array<T> {
+    /// Tokenize the elements of an iterator as a `T[]`.
+    ///
+    /// Unlike [`tokenize`][SolType::tokenize], this does not require the
+    /// elements to be collected into a `Vec<T::RustType>` first, so it can be
+    /// used to encode data that already lives in another structure without
+    /// cloning it. Slices can be passed directly, as `&[T::RustType]`
+    /// implements [`IntoIterator`].
+    #[inline]
+    pub fn tokenize_iter<'a, I, E>(iter: I) -> DynSeqToken<T::TokenType<'a>>
+    where
+        I: IntoIterator<Item = &'a E>,
+        I::IntoIter: ExactSizeIterator,
+        E: ?Sized + Encodable<T> + 'a,
+    {
+        let iter = iter.into_iter();
+        let mut tokens = Vec::with_capacity(iter.len());
+        tokens.extend(iter.map(E::to_tokens));
+        DynSeqToken(tokens)
+    }
+
+    /// Encode the elements of an iterator as a single `T[]` token.
+    ///
+    /// This is equivalent to collecting the elements and calling
+    /// [`encode_single`][SolType::encode_single]. See
+    /// [`tokenize_iter`][Array::tokenize_iter] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_sol_types::{sol_data::*, SolType};
+    ///
+    /// struct Account {
+    ///     balance: u64,
+    /// }
+    ///
+    /// let accounts = [Account { balance: 1 }, Account { balance: 2 }];
+    /// let encoded = Array::<Uint<64>>::encode_single_iter(accounts.iter().map(|a| &a.balance));
+    /// assert_eq!(encoded, Array::<Uint<64>>::encode_single(&vec![1, 2]));
+    /// ```
+    #[inline]
+    pub fn encode_single_iter<'a, I, E>(iter: I) -> Vec<u8>
+    where
+        I: IntoIterator<Item = &'a E>,
+        I::IntoIter: ExactSizeIterator,
+        E: ?Sized + Encodable<T> + 'a,
+    {
+        crate::encode_single(&Self::tokenize_iter(iter))
+    }
+
+    /// Lazily decode an ABI-encoded `T[]`, as returned by
+    /// [`encode_single`][SolType::encode_single].
+    ///
+    /// The array pointer and length are decoded eagerly, while the elements
+    /// are decoded one at a time as the returned iterator is advanced. This
+    /// avoids materializing the whole array when only a single pass over the
+    /// elements is needed.
+    ///
+    /// If `validate` is true, each element is type checked as it is decoded.
+    /// Unlike [`decode_single`][SolType::decode_single], the input is not
+    /// checked to be the canonical encoding of the array.
+    #[inline]
+    pub fn decode_iter(data: &[u8], validate: bool) -> Result<ArrayIter<'_, T>> {
+        Self::decode_iter_with_config(data, DecoderConfig::new(validate))
+    }
+
+    /// Lazily decode an ABI-encoded `T[]`, using the given decoder
+    /// configuration.
+    ///
+    /// See [`decode_iter`][Array::decode_iter] for more details.
+    pub fn decode_iter_with_config(data: &[u8], config: DecoderConfig) -> Result<ArrayIter<'_, T>> {
+        let mut decoder = Decoder::with_config(data, config);
+        let mut child = decoder.take_indirection()?;
+        let len = child.take_seq_len()?;
+        Ok(ArrayIter {
+            decoder: child.raw_child(),
+            index: 0,
+            len,
+            _ty: PhantomData,
+        })
+    }
+}
+
+/// A lazy iterator over the elements of an ABI-encoded `T[]`.
+///
+/// Created by [`Array::decode_iter`]. Iteration stops after the first error.
+pub struct ArrayIter<'de, T: SolType> {
+    decoder: Decoder<'de>,
+    index: usize,
+    len: usize,
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<'de, T: SolType> Iterator for ArrayIter<'de, T> {
+    type Item = Result<T::RustType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None
+        }
+        let index = self.index;
+        let res = T::TokenType::decode_from(&mut self.decoder)
+            .and_then(|token| {
+                if self.decoder.config().validate {
+                    T::type_check(&token)?;
+                }
+                Ok(T::detokenize(token))
+            })
+            .map_err(|e| e.with_path_segment(DecodePathSegment::Index(index)));
+        self.index = if res.is_ok() { index + 1 } else { self.len };
+        Some(res)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // may stop early on error
+        (0, Some(self.len - self.index))
+    }
+}
+
+impl<'de, T: SolType> FusedIterator for ArrayIter<'de, T> {}
+
 /// String - `string`
 pub struct String;
 
@@ -351,6 +778,11 @@ impl SolType for String {
         "string".into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(rust, f)
+    }
+
     #[inline]
     fn encoded_size(rust: &Self::RustType) -> usize {
         32 + utils::padded_len(rust.as_bytes())
@@ -371,7 +803,7 @@ impl SolType for String {
         // prevent invalid strings written into contracts by either users or
         // Solidity bugs from causing graph-node to fail decoding event
         // data.
-        RustString::from_utf8_lossy(&Bytes::detokenize(token)).into_owned()
+        RustString::from_utf8_lossy(token.as_slice()).into_owned()
     }
 
     #[inline]
@@ -383,6 +815,12 @@ impl SolType for String {
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         out.extend_from_slice(rust.as_bytes());
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        // NOTE: lossy, same as `detokenize`
+        Ok(RustString::from_utf8_lossy(data).into_owned())
+    }
 }
 
 /// FixedBytes - `bytesX`
@@ -409,11 +847,18 @@ where
     type RustType = [u8; N];
     type TokenType<'a> = WordToken;
 
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(N);
+
     #[inline]
     fn sol_type_name() -> Cow<'static, str> {
         <ByteCount<N>>::NAME.into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "0x{}", hex::encode(rust))
+    }
+
     #[inline]
     fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
         if utils::check_zeroes(&token.0[N..]) {
@@ -438,6 +883,12 @@ where
         // write only the first n bytes
         out.extend_from_slice(rust);
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)?;
+        Ok(data.try_into().unwrap())
+    }
 }
 
 /// FixedArray - `T[M]`
@@ -467,6 +918,13 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
         }
     };
 
+    const PACKED_ENCODED_SIZE: Option<usize> = {
+        match T::PACKED_ENCODED_SIZE {
+            Some(size) => Some(size * N),
+            None => None,
+        }
+    };
+
     #[inline]
     fn encoded_size(rust: &Self::RustType) -> usize {
         if let Some(size) = Self::ENCODED_SIZE {
@@ -481,6 +939,10 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
         format!("{}[{}]", T::sol_type_name(), N).into()
     }
 
+    fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        utils::fmt_seq::<T>("[", rust.iter(), "]", f)
+    }
+
     #[inline]
     fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
         for token in token.as_array().iter() {
@@ -494,6 +956,11 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
         token.0.map(T::detokenize)
     }
 
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> Result<()> {
+        rust.iter().try_for_each(T::value_check)
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         let rust = rust;
@@ -510,6 +977,15 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
             T::encode_packed_to(item, out);
         }
     }
+
+    #[inline]
+    fn decode_packed(mut data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)?;
+        let res = crate::impl_core::try_from_fn(|i| {
+            utils::split_packed::<T>(&mut data, i == N - 1).and_then(T::decode_packed)
+        })?;
+        Ok(res)
+    }
 }
 
 macro_rules! tuple_encodable_impls {
@@ -562,6 +1038,18 @@ macro_rules! tuple_impls {
                 acc
             };
 
+            const PACKED_ENCODED_SIZE: Option<usize> = {
+                let mut acc = Some(0);
+                $(
+                    match (acc, <$ty as SolType>::PACKED_ENCODED_SIZE) {
+                        (Some(i), Some(size)) => acc = Some(i + size),
+                        (Some(_), None) => acc = None,
+                        (None, _) => {}
+                    }
+                )+
+                acc
+            };
+
             fn sol_type_name() -> Cow<'static, str> {
                 format!(
                     concat!(
@@ -573,6 +1061,19 @@ macro_rules! tuple_impls {
                 ).into()
             }
 
+            fn fmt_value(rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+                let ($($ty,)+) = rust;
+                f.write_str("(")?;
+                let mut first = true;
+                $(
+                    if !core::mem::take(&mut first) {
+                        f.write_str(", ")?;
+                    }
+                    <$ty as SolType>::fmt_value($ty, f)?;
+                )+
+                f.write_str(")")
+            }
+
             fn encoded_size(rust: &Self::RustType) -> usize {
                 if let Some(size) = Self::ENCODED_SIZE {
                     return size
@@ -602,6 +1103,14 @@ macro_rules! tuple_impls {
                 )+)
             }
 
+            fn value_check(rust: &Self::RustType) -> Result<()> {
+                let ($($ty,)+) = rust;
+                $(
+                    <$ty as SolType>::value_check($ty)?;
+                )+
+                Ok(())
+            }
+
             fn eip712_data_word(rust: &Self::RustType) -> Word {
                 const COUNT: usize = 0usize $(+ tuple_impls!(@one $ty))+;
                 let ($($ty,)+) = rust;
@@ -622,6 +1131,17 @@ macro_rules! tuple_impls {
                     <$ty as SolType>::encode_packed_to($ty, out);
                 )+
             }
+
+            fn decode_packed(mut data: &[u8]) -> Result<Self::RustType> {
+                const COUNT: usize = 0usize $(+ tuple_impls!(@one $ty))+;
+                utils::check_packed_len::<Self>(data)?;
+                let mut i = 0usize;
+                Ok(($({
+                    i += 1;
+                    let member = utils::split_packed::<$ty>(&mut data, i == COUNT)?;
+                    <$ty as SolType>::decode_packed(member)?
+                },)+))
+            }
         }
     };
 }
@@ -638,12 +1158,18 @@ impl SolType for () {
     type TokenType<'a> = ();
 
     const ENCODED_SIZE: Option<usize> = Some(0);
+    const PACKED_ENCODED_SIZE: Option<usize> = Some(0);
 
     #[inline]
     fn sol_type_name() -> Cow<'static, str> {
         "()".into()
     }
 
+    #[inline]
+    fn fmt_value(_rust: &Self::RustType, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("()")
+    }
+
     #[inline]
     fn type_check(_token: &Self::TokenType<'_>) -> Result<()> {
         Ok(())
@@ -659,6 +1185,11 @@ impl SolType for () {
 
     #[inline]
     fn encode_packed_to(_rust: &Self::RustType, _out: &mut Vec<u8>) {}
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        utils::check_packed_len::<Self>(data)
+    }
 }
 
 all_the_tuples!(tuple_impls);
@@ -697,6 +1228,33 @@ supported_fixed_bytes!(
     27, 28, 29, 30, 31, 32
 );
 
+/// Specifies the number of decimal places in a [`Fixed`] or [`Ufixed`] as a
+/// type.
+pub struct DecimalCount<const N: usize>;
+
+impl<const N: usize> Sealed for DecimalCount<N> {}
+
+/// Statically guarantees that a [`Fixed`] or [`Ufixed`] decimal count is
+/// marked as supported.
+///
+/// This trait is *sealed*: the list of implementors below is total.
+///
+/// Solidity allows between 0 and 80 decimal places.
+pub trait SupportedDecimals: Sealed {}
+
+macro_rules! supported_decimals {
+    ($($n:literal),+) => {$(
+        impl SupportedDecimals for DecimalCount<$n> {}
+    )+};
+}
+
+supported_decimals!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+    50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73,
+    74, 75, 76, 77, 78, 79, 80
+);
+
 /// Specifies the number of bits in an [`Int`] or [`Uint`] as a type.
 pub struct IntBitCount<const N: usize>;
 
@@ -941,6 +1499,7 @@ supported_int!(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::{borrow::ToOwned, string::ToString};
 
     #[test]
     fn tuple_of_refs() {
@@ -987,6 +1546,257 @@ mod tests {
         roundtrip_i256(Int<256>: I256);
     }
 
+    macro_rules! roundtrip_packed {
+        ($($name:ident($st:ty : $t:ty);)+) => {
+            proptest::proptest! {$(
+                #[test]
+                fn $name(i: $t) {
+                    let encoded = <$st>::encode_packed(&i);
+                    proptest::prop_assert_eq!(<$st>::decode_packed(&encoded).unwrap(), i);
+                }
+            )+}
+        };
+    }
+
+    roundtrip_packed! {
+        roundtrip_packed_address(Address: RustAddress);
+        roundtrip_packed_bool(Bool: bool);
+        roundtrip_packed_bytes(Bytes: Vec<u8>);
+        roundtrip_packed_string(String: RustString);
+        roundtrip_packed_fixed_bytes_16(FixedBytes<16>: [u8; 16]);
+        roundtrip_packed_u8(Uint<8>: u8);
+        roundtrip_packed_i16(Int<16>: i16);
+        roundtrip_packed_i64(Int<64>: i64);
+        roundtrip_packed_u256(Uint<256>: U256);
+        roundtrip_packed_i256(Int<256>: I256);
+        roundtrip_packed_array(Array<Uint<32>>: Vec<u32>);
+        roundtrip_packed_fixed_array(FixedArray<Int<8>, 3>: [i8; 3]);
+        roundtrip_packed_tuple((Address, Int<16>, Bool, Bytes): (RustAddress, i16, bool, Vec<u8>));
+    }
+
+    macro_rules! abi_encoded_size {
+        ($($name:ident($st:ty : $t:ty);)+) => {
+            proptest::proptest! {$(
+                #[test]
+                fn $name(i: $t) {
+                    let encoded = <$st>::encode_single(&i);
+                    proptest::prop_assert_eq!(<$st>::abi_encoded_size(&i), encoded.len());
+                    // the buffer is allocated exactly once, with the exact size
+                    proptest::prop_assert_eq!(encoded.capacity(), encoded.len());
+                }
+            )+}
+        };
+    }
+
+    abi_encoded_size! {
+        abi_encoded_size_address(Address: RustAddress);
+        abi_encoded_size_bytes(Bytes: Vec<u8>);
+        abi_encoded_size_string(String: RustString);
+        abi_encoded_size_array(Array<Uint<64>>: Vec<u64>);
+        abi_encoded_size_nested_array(Array<Array<Uint<8>>>: Vec<Vec<u8>>);
+        abi_encoded_size_fixed_array(FixedArray<String, 2>: [RustString; 2]);
+        abi_encoded_size_tuple((Bool, Bytes, (Uint<8>, String)): (bool, Vec<u8>, (u8, RustString)));
+    }
+
+    #[test]
+    fn decode_packed_ints() {
+        assert_eq!(<Int<24>>::decode_packed(&[0xff, 0xff, 0xfe]), Ok(-2));
+        assert_eq!(<Int<24>>::decode_packed(&[0x7f, 0xff, 0xff]), Ok(0x7fffff));
+        assert_eq!(<Uint<24>>::decode_packed(&[0xff, 0xff, 0xfe]), Ok(0xfffffe));
+    }
+
+    #[test]
+    fn value_check() {
+        assert_eq!(<Uint<24>>::value_check(&0xffffff), Ok(()));
+        assert_eq!(
+            <Uint<24>>::encode_single_checked(&0x1000000),
+            Err(crate::Error::out_of_range(0x1000000, "uint24"))
+        );
+        assert_eq!(
+            <Uint<24>>::encode_single(&0x1000000),
+            <Uint<24>>::encode_single(&0)
+        );
+
+        assert!(<Int<24>>::value_check(&-0x800000).is_ok());
+        assert!(<Int<24>>::value_check(&0x7fffff).is_ok());
+        assert!(<Int<24>>::value_check(&0x800000).is_err());
+        assert!(<Int<24>>::value_check(&-0x800001).is_err());
+        assert!(<Int<256>>::value_check(&I256::MIN).is_ok());
+        assert!(<Uint<160>>::value_check(&(U256::from(1) << 160)).is_err());
+        assert!(<Ufixed<8, 2>>::value_check(&FixedPoint(255)).is_ok());
+        assert!(<Fixed<8, 2>>::value_check(&FixedPoint(-128)).is_ok());
+
+        type MyTy = (Bool, Array<Uint<40>>, FixedArray<Int<48>, 2>);
+        let ok = (true, vec![0, 1 << 39], [-(1 << 47), (1 << 47) - 1]);
+        assert_eq!(
+            MyTy::encode_params_checked(&ok).unwrap(),
+            MyTy::encode_params(&ok)
+        );
+        assert!(MyTy::encode_checked(&(true, vec![1 << 40], [0, 0])).is_err());
+        assert!(MyTy::encode_checked(&(true, vec![], [1 << 47, 0])).is_err());
+    }
+
+    #[test]
+    fn function() {
+        let address = RustAddress::repeat_byte(0x11);
+        let f = RustFunction::from_address_and_selector(address, [1, 2, 3, 4]);
+        let encoded = Function::encode_single(&f);
+        assert_eq!(encoded, <FixedBytes<24>>::encode_single(&f.0 .0));
+        assert_eq!(Function::decode_single(&encoded, true).unwrap(), f);
+        assert_eq!(Function::encode_packed(&f), f.as_slice());
+        assert_eq!(Function::decode_packed(f.as_slice()).unwrap(), f);
+        assert!(Function::decode_single(&<Uint<256>>::encode_single(&U256::MAX), true).is_err());
+        assert_eq!(<(Function, Address)>::sol_type_name(), "(function,address)");
+    }
+
+    #[test]
+    fn fmt_value() {
+        struct Show<T: SolType>(T::RustType);
+        impl<T: SolType> Display for Show<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                T::fmt_value(&self.0, f)
+            }
+        }
+        fn show<T: SolType>(value: T::RustType) -> RustString {
+            Show::<T>(value).to_string()
+        }
+
+        assert_eq!(show::<Bool>(true), "true");
+        assert_eq!(show::<Int<8>>(-5), "-5");
+        assert_eq!(
+            show::<Uint<256>>(U256::from(10).pow(U256::from(18))),
+            "1000000000000000000"
+        );
+        assert_eq!(show::<Ufixed<64, 2>>(FixedPoint(1050)), "10.50");
+        assert_eq!(
+            show::<Address>(RustAddress::repeat_byte(0x11)),
+            "0x1111111111111111111111111111111111111111"
+        );
+        assert_eq!(show::<FixedBytes<2>>([0xab, 0xcd]), "0xabcd");
+        assert_eq!(show::<Bytes>(vec![]), "0x");
+        assert_eq!(show::<String>("gm \"ser\"".into()), r#""gm \"ser\"""#);
+        assert_eq!(show::<Array<Uint<8>>>(vec![1, 2, 3]), "[1, 2, 3]");
+        assert_eq!(show::<FixedArray<Bool, 0>>([]), "[]");
+        assert_eq!(
+            show::<(Uint<8>, (Bool,), String)>((1, (false,), "a".into())),
+            r#"(1, (false), "a")"#
+        );
+        assert_eq!(show::<()>(()), "()");
+    }
+
+    #[test]
+    fn fixed_point() {
+        type F = Fixed<24, 2>;
+        type U = Ufixed<128, 18>;
+        assert_eq!(F::sol_type_name(), "fixed24x2");
+        assert_eq!(U::sol_type_name(), "ufixed128x18");
+        assert_eq!(
+            <(F, Array<U>)>::sol_type_name(),
+            "(fixed24x2,ufixed128x18[])"
+        );
+
+        let f = FixedPoint(-150);
+        let encoded = F::encode_single(&f);
+        assert_eq!(encoded, <Int<24>>::encode_single(&-150));
+        assert_eq!(F::decode_single(&encoded, true).unwrap(), f);
+        assert_eq!(F::encode_packed(&f), <Int<24>>::encode_packed(&-150));
+        assert_eq!(F::decode_packed(&F::encode_packed(&f)).unwrap(), f);
+
+        let u = FixedPoint(1_500_000_000_000_000_000);
+        let encoded = U::encode_single(&u);
+        assert_eq!(U::decode_single(&encoded, true).unwrap(), u);
+        assert!(U::decode_single(&<Uint<256>>::encode_single(&U256::MAX), true).is_err());
+    }
+
+    #[test]
+    fn decode_array_iter() {
+        type MyTy = Array<String>;
+        let data = vec!["a".to_owned(), "bc".repeat(20), RustString::new()];
+        let encoded = MyTy::encode_single(&data);
+
+        let iter = MyTy::decode_iter(&encoded, true).unwrap();
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+        assert_eq!(iter.collect::<Result<Vec<_>>>().unwrap(), data);
+
+        let ints = <Array<Uint<64>>>::encode_single(&vec![1, 2, 3]);
+        let sum = <Array<Uint<64>>>::decode_iter(&ints, true)
+            .unwrap()
+            .try_fold(0, |acc, x| x.map(|x| acc + x))
+            .unwrap();
+        assert_eq!(sum, 6);
+
+        // corrupt the pointer to the second element
+        let mut encoded = encoded;
+        encoded[0x60 + 28] = 0xff;
+        let mut iter = MyTy::decode_iter(&encoded, true).unwrap();
+        assert_eq!(iter.next().unwrap().unwrap(), "a");
+        match iter.next().unwrap().unwrap_err() {
+            crate::Error::Decode { path, .. } => assert_eq!(path, [DecodePathSegment::Index(1)]),
+            e => panic!("unexpected error: {e}"),
+        }
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn encode_array_iter() {
+        struct Item {
+            name: RustString,
+            value: u64,
+        }
+        let items = [
+            Item {
+                name: "a".into(),
+                value: 1,
+            },
+            Item {
+                name: "bc".repeat(20),
+                value: 2,
+            },
+        ];
+
+        let names = items.iter().map(|i| &i.name);
+        let encoded = <Array<String>>::encode_single_iter(names);
+        let expected = vec![items[0].name.clone(), items[1].name.clone()];
+        assert_eq!(encoded, <Array<String>>::encode_single(&expected));
+
+        let values = [items[0].value, items[1].value];
+        assert_eq!(
+            <Array<Uint<64>>>::encode_single_iter(&values[..]),
+            <Array<Uint<64>>>::encode_single(&values.to_vec())
+        );
+        assert_eq!(
+            <Array<Uint<64>>>::tokenize_iter(&values),
+            <Array<Uint<64>>>::tokenize(&values.to_vec())
+        );
+
+        let empty: [&str; 0] = [];
+        assert_eq!(
+            <Array<String>>::encode_single_iter(&empty),
+            <Array<String>>::encode_single(&vec![])
+        );
+    }
+
+    #[test]
+    fn decode_packed_errors() {
+        assert_eq!(Bool::decode_packed(&[]), Err(crate::Error::Overrun));
+        assert!(Bool::decode_packed(&[2]).is_err());
+        assert!(<Uint<16>>::decode_packed(&[0; 3]).is_err());
+        assert!(<Array<Uint<16>>>::decode_packed(&[0; 3]).is_err());
+
+        assert_eq!(
+            <(String, Bool)>::decode_packed(b"abc\x01"),
+            Err(crate::Error::ambiguous_packed("string"))
+        );
+        assert_eq!(
+            <Array<String>>::decode_packed(b"abc"),
+            Err(crate::Error::ambiguous_packed("string[]"))
+        );
+        assert_eq!(
+            <(Bool, String)>::decode_packed(b"\x01abc"),
+            Ok((true, "abc".into()))
+        );
+    }
+
     #[test]
     fn tokenize_uint() {
         macro_rules! test {
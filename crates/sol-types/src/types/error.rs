@@ -54,11 +54,15 @@ pub trait SolError: Sized {
 
     /// ABI decode this error's arguments from the given slice, **with** the
     /// selector.
+    ///
+    /// This can be used to decode revert data into a typed error. Returns
+    /// [`Error::SelectorMismatch`](crate::Error::SelectorMismatch) if the data
+    /// does not start with [`SELECTOR`](SolError::SELECTOR).
     #[inline]
     fn decode(data: &[u8], validate: bool) -> Result<Self> {
         let data = data
             .strip_prefix(&Self::SELECTOR)
-            .ok_or_else(|| crate::Error::type_check_fail_sig(data, Self::SIGNATURE))?;
+            .ok_or_else(|| crate::Error::selector_mismatch(Self::SELECTOR, data))?;
         Self::decode_raw(data, validate)
     }
 
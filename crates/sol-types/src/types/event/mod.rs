@@ -1,9 +1,9 @@
 use crate::{
     token::{TokenSeq, WordToken},
-    Result, SolType, TokenType, Word,
+    Error, Result, SolType, TokenType, Word,
 };
 use alloc::vec::Vec;
-use alloy_primitives::{FixedBytes, B256};
+use alloy_primitives::{FixedBytes, LogData, B256};
 
 mod topic;
 pub use topic::EventTopic;
@@ -122,6 +122,14 @@ pub trait SolEvent: Sized {
         out
     }
 
+    /// Encode this event into a [`LogData`], containing its topics and
+    /// ABI-encoded data.
+    #[inline]
+    fn encode_log_data(&self) -> LogData {
+        let topics = self.encode_topics().into_iter().map(|t| t.0).collect();
+        LogData::new_unchecked(topics, self.encode_data().into())
+    }
+
     /// Decode the topics of this event from the given data.
     #[inline]
     fn decode_topics<I, D>(topics: I) -> Result<<Self::TopicList as SolType>::RustType>
@@ -151,4 +159,48 @@ pub trait SolEvent: Sized {
         let body = Self::decode_data(data, validate)?;
         Ok(Self::new(topics, body))
     }
+
+    /// Decode the event from the raw topics and data of a log, checking that
+    /// they belong to this event.
+    ///
+    /// Unlike [`decode_log`](SolEvent::decode_log), this checks that the first
+    /// topic is [`SIGNATURE_HASH`](SolEvent::SIGNATURE_HASH) for non-anonymous
+    /// events, and that the number of topics is exactly
+    /// [`TopicList::COUNT`].
+    fn decode_raw_log<I, D>(topics: I, data: &[u8], validate: bool) -> Result<Self>
+    where
+        I: IntoIterator<Item = D>,
+        D: Into<WordToken>,
+    {
+        let mut topics = topics.into_iter().map(Into::<WordToken>::into).peekable();
+        if !Self::ANONYMOUS {
+            match topics.peek() {
+                Some(WordToken(topic0)) if *topic0 != Self::SIGNATURE_HASH => {
+                    return Err(Error::EventSignatureMismatch {
+                        expected: Self::SIGNATURE_HASH,
+                        got: *topic0,
+                    })
+                }
+                _ => {}
+            }
+        }
+        let decoded_topics = Self::decode_topics(&mut topics)?;
+        if topics.next().is_some() {
+            return Err(Error::TopicLengthMismatch {
+                expected: Self::TopicList::COUNT,
+                actual: Self::TopicList::COUNT + 1 + topics.count(),
+            })
+        }
+        let body = Self::decode_data(data, validate)?;
+        Ok(Self::new(decoded_topics, body))
+    }
+
+    /// Decode the event from a [`LogData`], checking that it belongs to this
+    /// event.
+    ///
+    /// See [`decode_raw_log`](SolEvent::decode_raw_log) for more details.
+    #[inline]
+    fn decode_log_data(log: &LogData, validate: bool) -> Result<Self> {
+        Self::decode_raw_log(log.topics().iter().copied(), &log.data, validate)
+    }
 }
@@ -80,6 +80,22 @@ where
     word_impl!(Uint<BITS>);
 }
 
+impl<const BITS: usize, const DECIMALS: usize> EventTopic for Fixed<BITS, DECIMALS>
+where
+    IntBitCount<BITS>: SupportedInt,
+    DecimalCount<DECIMALS>: SupportedDecimals,
+{
+    word_impl!(Fixed<BITS, DECIMALS>);
+}
+
+impl<const BITS: usize, const DECIMALS: usize> EventTopic for Ufixed<BITS, DECIMALS>
+where
+    IntBitCount<BITS>: SupportedInt,
+    DecimalCount<DECIMALS>: SupportedDecimals,
+{
+    word_impl!(Ufixed<BITS, DECIMALS>);
+}
+
 impl<const N: usize> EventTopic for FixedBytes<N>
 where
     ByteCount<N>: SupportedFixedBytes,
@@ -0,0 +1,110 @@
+use alloc::string::ToString;
+use core::fmt;
+
+/// A fixed-point decimal number, the Rust representation of the Solidity
+/// `fixedMxN` and `ufixedMxN` types.
+///
+/// The number is stored as the underlying integer `T` scaled by
+/// `10^DECIMALS`, exactly as it is ABI-encoded. For example, `1.5` as a
+/// `ufixed128x18` is `FixedPoint::<u128, 18>(1_500_000_000_000_000_000)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedPoint<T, const DECIMALS: usize>(pub T);
+
+impl<T, const DECIMALS: usize> From<T> for FixedPoint<T, DECIMALS> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T, const DECIMALS: usize> FixedPoint<T, DECIMALS> {
+    /// The number of decimal places.
+    pub const DECIMALS: usize = DECIMALS;
+
+    /// Creates a new fixed-point number from its scaled integer
+    /// representation.
+    #[inline]
+    pub const fn from_raw(raw: T) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the scaled integer representation, consuming the number.
+    // https://github.com/rust-lang/rust-clippy/issues/4979
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_raw(self) -> T {
+        self.0
+    }
+
+    /// Returns a reference to the scaled integer representation.
+    #[inline]
+    pub const fn raw(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, const DECIMALS: usize> arbitrary::Arbitrary<'a>
+    for FixedPoint<T, DECIMALS>
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+impl<T: fmt::Display, const DECIMALS: usize> fmt::Display for FixedPoint<T, DECIMALS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self.0.to_string();
+        let (sign, digits) = match s.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", s.as_str()),
+        };
+        if DECIMALS == 0 {
+            return write!(f, "{sign}{digits}")
+        }
+
+        // left-pad so that there is at least one integer digit
+        let zeros = (DECIMALS + 1).saturating_sub(digits.len());
+        f.write_str(sign)?;
+        for _ in 0..zeros.min(1) {
+            f.write_str("0")?;
+        }
+        let (int, frac) = if zeros > 0 {
+            ("", digits)
+        } else {
+            digits.split_at(digits.len() - DECIMALS)
+        };
+        f.write_str(int)?;
+        f.write_str(".")?;
+        for _ in 1..zeros {
+            f.write_str("0")?;
+        }
+        f.write_str(frac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::I256;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            FixedPoint::<u128, 18>(1_500_000_000_000_000_000).to_string(),
+            "1.500000000000000000"
+        );
+        assert_eq!(FixedPoint::<i32, 2>(-5).to_string(), "-0.05");
+        assert_eq!(FixedPoint::<i32, 2>(-105).to_string(), "-1.05");
+        assert_eq!(FixedPoint::<u8, 2>(0).to_string(), "0.00");
+        assert_eq!(FixedPoint::<u8, 2>(100).to_string(), "1.00");
+        assert_eq!(FixedPoint::<u8, 0>(42).to_string(), "42");
+        assert_eq!(FixedPoint::<I256, 1>(I256::MINUS_ONE).to_string(), "-0.1");
+    }
+}
@@ -34,6 +34,18 @@ pub trait SolCall: Sized {
     /// The function selector: `keccak256(SIGNATURE)[0..4]`
     const SELECTOR: [u8; 4];
 
+    /// The function's state mutability.
+    ///
+    /// Defaults to [`NonPayable`](StateMutability::NonPayable) when the
+    /// function does not declare one.
+    const STATE_MUTABILITY: StateMutability = StateMutability::NonPayable;
+
+    /// The function's visibility.
+    ///
+    /// Defaults to [`External`](Visibility::External) when the function does
+    /// not declare one.
+    const VISIBILITY: Visibility = Visibility::External;
+
     /// Convert from the tuple type used for ABI encoding and decoding.
     fn new(tuple: <Self::Arguments<'_> as SolType>::RustType) -> Self;
 
@@ -95,3 +107,129 @@ pub trait SolCall: Sized {
         crate::encode(&e.to_tokens())
     }
 }
+
+/// The state mutability of a Solidity function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StateMutability {
+    /// `pure`: does not read from or modify the state.
+    Pure,
+    /// `view`: does not modify the state.
+    View,
+    /// Does not accept Ether. This is the default.
+    #[default]
+    NonPayable,
+    /// `payable`: may accept Ether.
+    Payable,
+}
+
+impl StateMutability {
+    /// Returns the Solidity keyword of the state mutability.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pure => "pure",
+            Self::View => "view",
+            Self::NonPayable => "nonpayable",
+            Self::Payable => "payable",
+        }
+    }
+
+    /// Returns `true` if the function does not modify the state, meaning that
+    /// it can be executed with a call instead of a transaction.
+    #[inline]
+    pub const fn is_read_only(self) -> bool {
+        matches!(self, Self::Pure | Self::View)
+    }
+}
+
+/// The visibility of a Solidity function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// `external`: can only be called from other contracts and transactions.
+    /// This is the default.
+    #[default]
+    External,
+    /// `public`: can be called both internally and externally.
+    Public,
+    /// `internal`: can only be called from the contract and its children.
+    Internal,
+    /// `private`: can only be called from the contract itself.
+    Private,
+}
+
+impl Visibility {
+    /// Returns the Solidity keyword of the visibility.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::External => "external",
+            Self::Public => "public",
+            Self::Internal => "internal",
+            Self::Private => "private",
+        }
+    }
+
+    /// Returns `true` if the function is part of the contract's ABI.
+    #[inline]
+    pub const fn is_external(self) -> bool {
+        matches!(self, Self::External | Self::Public)
+    }
+}
+
+/// Solidity constructor (a tuple of arguments which is appended to the
+/// contract's creation code when deploying it).
+///
+/// ### Implementer's Guide
+///
+/// We do not recommend implementing this trait directly. Instead, we recommend
+/// using the [`sol`][crate::sol] proc macro to parse a Solidity contract
+/// containing a constructor definition.
+pub trait SolConstructor: Sized {
+    /// The underlying tuple type which represents this type's arguments.
+    ///
+    /// If this type has no arguments, this will be the unit type `()`.
+    type Arguments<'a>: SolType<TokenType<'a> = Self::Token<'a>>;
+
+    /// The arguments' corresponding [TokenSeq] type.
+    type Token<'a>: TokenSeq<'a>;
+
+    /// Convert from the tuple type used for ABI encoding and decoding.
+    fn new(tuple: <Self::Arguments<'_> as SolType>::RustType) -> Self;
+
+    /// Tokenize the constructor's arguments.
+    fn tokenize(&self) -> Self::Token<'_>;
+
+    /// The size of the encoded arguments in bytes.
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        if let Some(size) = <Self::Arguments<'_> as SolType>::ENCODED_SIZE {
+            return size
+        }
+
+        self.tokenize().total_words() * Word::len_bytes()
+    }
+
+    /// ABI decode this constructor's arguments from the given slice.
+    ///
+    /// Note that the slice must not contain the creation code.
+    #[inline]
+    fn decode(data: &[u8], validate: bool) -> Result<Self> {
+        <Self::Arguments<'_> as SolType>::decode(data, validate).map(Self::new)
+    }
+
+    /// ABI encode the constructor's arguments.
+    #[inline]
+    fn encode(&self) -> Vec<u8> {
+        crate::encode(&self.tokenize())
+    }
+
+    /// ABI encode the deployment data of a contract: its creation code,
+    /// followed by the encoded constructor arguments.
+    #[inline]
+    fn encode_deploy(&self, bytecode: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(bytecode.len() + self.encoded_size());
+        out.extend_from_slice(bytecode);
+        out.extend(crate::encode(&self.tokenize()));
+        out
+    }
+}
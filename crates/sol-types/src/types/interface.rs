@@ -137,7 +137,7 @@ impl<T: SolInterface> SolInterface for ContractError<T> {
         match selector {
             Revert::SELECTOR => Revert::decode_raw(data, validate).map(Self::Revert),
             Panic::SELECTOR => Panic::decode_raw(data, validate).map(Self::Panic),
-            _ => T::decode(data, validate).map(Self::CustomError),
+            _ => T::decode_raw(selector, data, validate).map(Self::CustomError),
         }
     }
 
@@ -0,0 +1,40 @@
+use crate::SolType;
+use alloy_primitives::B256;
+
+/// Solidity mapping state variable.
+///
+/// Mappings are not part of a contract's ABI, only their getters are, if they
+/// are public. This trait instead exposes their storage layout: the key and
+/// value types, and the computation of the storage slot of a value.
+///
+/// ### Implementer's Guide
+///
+/// We do not recommend implementing this trait directly. Instead, we recommend
+/// using the [`sol`][crate::sol] proc macro to parse a Solidity contract
+/// containing mapping state variables.
+pub trait SolMapping {
+    /// The underlying tuple type which represents this mapping's keys, from the
+    /// outermost mapping to the innermost one.
+    type Keys: SolType;
+
+    /// The name of the state variable.
+    const NAME: &'static str;
+
+    /// The Solidity key types, from the outermost mapping to the innermost one.
+    const KEY_TYPES: &'static [&'static str];
+
+    /// The Solidity type of the values of the innermost mapping.
+    const VALUE_TYPE: &'static str;
+
+    /// Computes the storage slot of the value at the given keys, given the
+    /// slot of the state variable itself.
+    ///
+    /// Each key `k` is hashed with the current slot `p` as `keccak256(h(k) .
+    /// p)`, where `h(k)` is the key padded to 32 bytes for value types, and
+    /// the unpadded bytes for `string` and `bytes`.
+    ///
+    /// See the [Solidity reference][ref] for more details.
+    ///
+    /// [ref]: https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html#mappings-and-dynamic-arrays
+    fn slot(base: B256, keys: &<Self::Keys as SolType>::RustType) -> B256;
+}
@@ -9,18 +9,27 @@ pub use error::{Panic, PanicKind, Revert, SolError};
 mod event;
 pub use event::{EventTopic, SolEvent, TopicList};
 
+mod fixed_point;
+pub use fixed_point::FixedPoint;
+
 mod function;
-pub use function::SolCall;
+pub use function::{SolCall, SolConstructor, StateMutability, Visibility};
 
 mod interface;
 pub use interface::{ContractError, Selectors, SolInterface};
 
+mod mapping;
+pub use mapping::SolMapping;
+
 mod r#struct;
 pub use r#struct::SolStruct;
 
 mod ty;
 pub use ty::{Encodable, SolType};
 
+mod variable;
+pub use variable::StateVariable;
+
 // Solidity user-defined value types.
 // No exports are needed as the only item is a macro.
 mod udt;
@@ -5,6 +5,7 @@ use super::{Encodable, SolType};
 use crate::{token::TokenSeq, Eip712Domain, TokenType, Word};
 use alloc::{borrow::Cow, string::String, vec::Vec};
 use alloy_primitives::{keccak256, B256};
+use core::fmt;
 
 type TupleFor<'a, T> = <T as SolStruct>::Tuple<'a>;
 type TupleTokenTypeFor<'a, T> = <TupleFor<'a, T> as SolType>::TokenType<'a>;
@@ -25,11 +26,12 @@ type TupleTokenTypeFor<'a, T> = <TupleFor<'a, T> as SolType>::TokenType<'a>;
 /// Solidity types. Nested Solidity structs **must** properly encode their type.
 ///
 /// To be clear, a struct with a nested struct must encode the nested struct's
-/// type as well.
+/// type as well, by returning it from [`eip712_components`].
 ///
 /// See [EIP-712#definition-of-encodetype][ref] for more details.
 ///
 /// [`eip712_encode_type`]: SolStruct::eip712_encode_type
+/// [`eip712_components`]: SolStruct::eip712_components
 /// [ref]: https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype
 pub trait SolStruct: 'static {
     /// The corresponding Tuple type, used for encoding/decoding.
@@ -40,14 +42,14 @@ pub trait SolStruct: 'static {
 
     /// The struct name.
     ///
-    /// Used in [`eip712_encode_type`][SolStruct::eip712_encode_type].
+    /// Used in [`eip712_root_type`][SolStruct::eip712_root_type].
     const NAME: &'static str;
 
     /// The field types and names. Type is a Solidity string, and must conform
     /// to the name of the Solidty type at the same index in the associated
     /// tuple.
     ///
-    /// Used in [`eip712_encode_type`][SolStruct::eip712_encode_type].
+    /// Used in [`eip712_root_type`][SolStruct::eip712_root_type].
     const FIELDS: &'static [(&'static str, &'static str)];
 
     // TODO: avoid clones here
@@ -70,9 +72,9 @@ pub trait SolStruct: 'static {
         self.tokenize().total_words() * Word::len_bytes()
     }
 
-    /// EIP-712 `encodeType`
-    /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype>
-    fn eip712_encode_type() -> Cow<'static, str> {
+    /// Returns the struct's own EIP-712 type, without the types it depends on:
+    /// `Name(type1 field1,type2 field2,...)`.
+    fn eip712_root_type() -> Cow<'static, str> {
         let capacity = Self::FIELDS
             .iter()
             .map(|(ty, name)| ty.len() + name.len() + 1)
@@ -94,6 +96,36 @@ pub trait SolStruct: 'static {
         out.into()
     }
 
+    /// Returns the root types of all the structs that this struct references,
+    /// directly or transitively, deduplicated and sorted by name.
+    ///
+    /// Defaults to no components, which is correct for structs that only
+    /// contain non-struct fields.
+    fn eip712_components() -> Vec<Cow<'static, str>> {
+        Vec::new()
+    }
+
+    /// EIP-712 `encodeType`
+    /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype>
+    ///
+    /// This is the [root type](SolStruct::eip712_root_type) followed by the
+    /// root types of its [components](SolStruct::eip712_components).
+    fn eip712_encode_type() -> Cow<'static, str> {
+        let root = Self::eip712_root_type();
+        let components = Self::eip712_components();
+        if components.is_empty() {
+            return root
+        }
+
+        let capacity = root.len() + components.iter().map(|c| c.len()).sum::<usize>();
+        let mut out = String::with_capacity(capacity);
+        out.push_str(&root);
+        for component in components {
+            out.push_str(&component);
+        }
+        out.into()
+    }
+
     /// EIP-712 `typeHash`
     /// <https://eips.ethereum.org/EIPS/eip-712#rationale-for-typehash>
     #[inline]
@@ -144,6 +176,7 @@ impl<T: SolStruct> SolType for T {
     type TokenType<'a> = TupleTokenTypeFor<'a, T>;
 
     const DYNAMIC: bool = TupleFor::<T>::DYNAMIC;
+    const PACKED_ENCODED_SIZE: Option<usize> = TupleFor::<T>::PACKED_ENCODED_SIZE;
 
     #[inline]
     fn type_check(token: &Self::TokenType<'_>) -> crate::Result<()> {
@@ -160,12 +193,23 @@ impl<T: SolStruct> SolType for T {
         Self::NAME.into()
     }
 
+    #[inline]
+    fn fmt_value(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::NAME)?;
+        TupleFor::<T>::fmt_value(&rust.to_rust(), f)
+    }
+
     #[inline]
     fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
         let tuple = TupleFor::<T>::detokenize(token);
         T::new(tuple)
     }
 
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> crate::Result<()> {
+        TupleFor::<T>::value_check(&rust.to_rust())
+    }
+
     #[inline]
     fn eip712_encode_type() -> Option<Cow<'static, str>> {
         Some(<Self as SolStruct>::eip712_encode_type())
@@ -173,7 +217,7 @@ impl<T: SolStruct> SolType for T {
 
     #[inline]
     fn eip712_data_word<'a>(rust: &Self::RustType) -> Word {
-        keccak256(rust.eip712_hash_struct())
+        rust.eip712_hash_struct()
     }
 
     #[inline]
@@ -181,4 +225,9 @@ impl<T: SolStruct> SolType for T {
         let tuple = rust.to_rust();
         TupleFor::<T>::encode_packed_to(&tuple, out)
     }
+
+    #[inline]
+    fn decode_packed(data: &[u8]) -> crate::Result<Self::RustType> {
+        TupleFor::<T>::decode_packed(data).map(T::new)
+    }
 }
@@ -1,5 +1,6 @@
-use crate::{token::TokenSeq, Result, TokenType, Word};
+use crate::{token::TokenSeq, DecoderConfig, Result, TokenType, Word};
 use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::fmt;
 
 /// An encodable is any type that may be encoded via a given `SolType`.
 pub trait Encodable<T: ?Sized + SolType> {
@@ -63,9 +64,23 @@ pub trait SolType {
     /// Whether the encoded size is dynamic.
     const DYNAMIC: bool = Self::ENCODED_SIZE.is_none();
 
+    /// The packed encoded size of the type, if known at compile time.
+    ///
+    /// `None` if the packed encoding has a dynamic length.
+    const PACKED_ENCODED_SIZE: Option<usize> = None;
+
     /// The name of the type in Solidity.
     fn sol_type_name() -> Cow<'static, str>;
 
+    /// Formats a value of this type the way it would be written in Solidity:
+    /// integers in decimal, addresses and bytes in hex, arrays as `[a, b]`.
+    ///
+    /// The alternate flag (`{:#}`) is passed through, which abbreviates
+    /// addresses. Defaults to the hex-encoded ABI encoding of the value.
+    fn fmt_value(rust: &Self::RustType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(Self::encode_single(rust)))
+    }
+
     /// Calculate the encoded size of the data, counting both head and tail
     /// words. For a single-word type this will always be 32.
     #[inline]
@@ -73,6 +88,16 @@ pub trait SolType {
         Self::ENCODED_SIZE.unwrap()
     }
 
+    /// Calculate the exact length of the ABI encoding of the data, as returned
+    /// by [`encode_single`][SolType::encode_single].
+    ///
+    /// This includes the head pointer of dynamic types, in addition to
+    /// [`encoded_size`][SolType::encoded_size]. Useful to preallocate buffers.
+    #[inline]
+    fn abi_encoded_size(rust: &Self::RustType) -> usize {
+        Self::DYNAMIC as usize * 32 + Self::encoded_size(rust)
+    }
+
     /// Check a token to see if it can be detokenized with this type.
     fn type_check(token: &Self::TokenType<'_>) -> Result<()>;
 
@@ -89,6 +114,26 @@ pub trait SolType {
         rust.to_tokens()
     }
 
+    /// Check that a Rust value can be represented by this Solidity type.
+    ///
+    /// Tokenization silently truncates values that do not fit in the declared
+    /// bit width, e.g. a `u32` above `2^24 - 1` encoded as a `uint24`. This
+    /// method returns an [`Error::OutOfRange`][crate::Error::OutOfRange] for
+    /// such values instead.
+    #[inline]
+    fn value_check(rust: &Self::RustType) -> Result<()> {
+        let _ = rust;
+        Ok(())
+    }
+
+    /// Tokenize, checking that the value fits in this Solidity type first.
+    ///
+    /// See [`value_check`][SolType::value_check].
+    #[inline]
+    fn tokenize_checked(rust: &Self::RustType) -> Result<Self::TokenType<'_>> {
+        Self::value_check(rust).map(|()| rust.to_tokens())
+    }
+
     /// The encoded struct type (as EIP-712), if any. None for non-structs.
     #[inline]
     fn eip712_encode_type() -> Option<Cow<'static, str>> {
@@ -126,12 +171,48 @@ pub trait SolType {
         out
     }
 
+    /// Non-standard Packed Mode ABI decoding.
+    ///
+    /// This is the reverse of [`encode_packed`][SolType::encode_packed], and
+    /// consumes the entire input.
+    ///
+    /// Packed encodings are not self-describing, so decoding is only possible
+    /// when the layout is unambiguous: every member must have a fixed packed
+    /// size, except for at most one dynamically-sized trailing member, which
+    /// consumes the remainder of the input. Ambiguous layouts return
+    /// [`Error::AmbiguousPacked`][crate::Error::AmbiguousPacked].
+    ///
+    /// The default implementation returns an error, so that types implemented
+    /// outside of this crate keep compiling. Override it to support packed
+    /// decoding.
+    #[inline]
+    fn decode_packed(data: &[u8]) -> Result<Self::RustType> {
+        let _ = data;
+        Err(crate::Error::Other(
+            format!(
+                "packed decoding is not supported for \"{}\"",
+                Self::sol_type_name()
+            )
+            .into(),
+        ))
+    }
+
+    /// Non-standard Packed Mode ABI decoding from a hex string.
+    ///
+    /// See [`decode_packed`][SolType::decode_packed] for more details.
+    #[inline]
+    fn hex_decode_packed(data: &str) -> Result<Self::RustType> {
+        hex::decode(data)
+            .map_err(Into::into)
+            .and_then(|buf| Self::decode_packed(&buf))
+    }
+
     /* BOILERPLATE BELOW */
 
     /// Encode a single ABI token by wrapping it in a 1-length sequence.
     #[inline]
     fn encode_single(rust: &Self::RustType) -> Vec<u8> {
-        crate::encode_single(&rust.to_tokens())
+        crate::coder::encode_single_sized(&rust.to_tokens(), Self::abi_encoded_size(rust))
     }
 
     /// Encode an ABI sequence.
@@ -140,7 +221,7 @@ pub trait SolType {
     where
         Self::TokenType<'a>: TokenSeq<'a>,
     {
-        crate::encode(&rust.to_tokens())
+        crate::coder::encode_sized(&rust.to_tokens(), Self::encoded_size(rust))
     }
 
     /// Encode an ABI sequence suitable for function parameters.
@@ -149,7 +230,52 @@ pub trait SolType {
     where
         Self::TokenType<'a>: TokenSeq<'a>,
     {
-        crate::encode_params(&rust.to_tokens())
+        let size = if <Self::TokenType<'a> as TokenSeq<'a>>::IS_TUPLE {
+            Self::encoded_size(rust)
+        } else {
+            Self::abi_encoded_size(rust)
+        };
+        crate::coder::encode_params_sized(&rust.to_tokens(), size)
+    }
+
+    /// Encode a single ABI token, checking that the value fits in this
+    /// Solidity type first.
+    ///
+    /// See [`value_check`][SolType::value_check].
+    #[inline]
+    fn encode_single_checked(rust: &Self::RustType) -> Result<Vec<u8>> {
+        let size = Self::abi_encoded_size(rust);
+        Self::tokenize_checked(rust).map(|token| crate::coder::encode_single_sized(&token, size))
+    }
+
+    /// Encode an ABI sequence, checking that the value fits in this Solidity
+    /// type first.
+    ///
+    /// See [`value_check`][SolType::value_check].
+    #[inline]
+    fn encode_checked<'a>(rust: &'a Self::RustType) -> Result<Vec<u8>>
+    where
+        Self::TokenType<'a>: TokenSeq<'a>,
+    {
+        let size = Self::encoded_size(rust);
+        Self::tokenize_checked(rust).map(|token| crate::coder::encode_sized(&token, size))
+    }
+
+    /// Encode an ABI sequence suitable for function parameters, checking that
+    /// the value fits in this Solidity type first.
+    ///
+    /// See [`value_check`][SolType::value_check].
+    #[inline]
+    fn encode_params_checked<'a>(rust: &'a Self::RustType) -> Result<Vec<u8>>
+    where
+        Self::TokenType<'a>: TokenSeq<'a>,
+    {
+        let size = if <Self::TokenType<'a> as TokenSeq<'a>>::IS_TUPLE {
+            Self::encoded_size(rust)
+        } else {
+            Self::abi_encoded_size(rust)
+        };
+        Self::tokenize_checked(rust).map(|token| crate::coder::encode_params_sized(&token, size))
     }
 
     /// Hex output of [`encode`][SolType::encode].
@@ -176,17 +302,59 @@ pub trait SolType {
         hex::encode_prefixed(Self::encode_params(rust))
     }
 
-    /// Decode a Rust type from an ABI blob.
+    /// Decode the ABI tokens of a sequence from an ABI blob, without
+    /// detokenizing them.
+    ///
+    /// The returned tokens borrow `bytes` and `string` contents directly from
+    /// `data`, so no copies are performed. See
+    /// [`decode`][SolType::decode] for the owned variant.
     #[inline]
-    fn decode<'de>(data: &'de [u8], validate: bool) -> Result<Self::RustType>
+    fn decode_token<'de>(data: &'de [u8], validate: bool) -> Result<Self::TokenType<'de>>
     where
         Self::TokenType<'de>: TokenSeq<'de>,
     {
-        let decoded = crate::decode::<Self::TokenType<'_>>(data, validate)?;
+        let decoded = crate::decode::<Self::TokenType<'de>>(data, validate)?;
         if validate {
             Self::type_check(&decoded)?;
         }
-        Ok(Self::detokenize(decoded))
+        Ok(decoded)
+    }
+
+    /// Decode the ABI tokens of function parameters from an ABI blob, without
+    /// detokenizing them.
+    ///
+    /// See [`decode_token`][SolType::decode_token] for more details.
+    #[inline]
+    fn decode_params_token<'de>(data: &'de [u8], validate: bool) -> Result<Self::TokenType<'de>>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        let decoded = crate::decode_params::<Self::TokenType<'de>>(data, validate)?;
+        if validate {
+            Self::type_check(&decoded)?;
+        }
+        Ok(decoded)
+    }
+
+    /// Decode a single ABI token from an ABI blob, without detokenizing it.
+    ///
+    /// See [`decode_token`][SolType::decode_token] for more details.
+    #[inline]
+    fn decode_single_token<'de>(data: &'de [u8], validate: bool) -> Result<Self::TokenType<'de>> {
+        let decoded = crate::decode_single::<Self::TokenType<'de>>(data, validate)?;
+        if validate {
+            Self::type_check(&decoded)?;
+        }
+        Ok(decoded)
+    }
+
+    /// Decode a Rust type from an ABI blob.
+    #[inline]
+    fn decode<'de>(data: &'de [u8], validate: bool) -> Result<Self::RustType>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        Self::decode_token(data, validate).map(Self::detokenize)
     }
 
     /// Decode a Rust type from an ABI blob.
@@ -195,18 +363,80 @@ pub trait SolType {
     where
         for<'de> Self::TokenType<'de>: TokenSeq<'de>,
     {
-        let decoded = crate::decode_params::<Self::TokenType<'_>>(data, validate)?;
-        if validate {
+        Self::decode_params_token(data, validate).map(Self::detokenize)
+    }
+
+    /// Decode a Rust type from an ABI blob.
+    #[inline]
+    fn decode_single(data: &[u8], validate: bool) -> Result<Self::RustType> {
+        Self::decode_single_token(data, validate).map(Self::detokenize)
+    }
+
+    /// Decode a Rust type from an ABI blob, using the given decoder
+    /// configuration.
+    ///
+    /// Use a restrictive [`DecoderConfig`] when decoding untrusted input.
+    #[inline]
+    fn decode_with_config<'de>(data: &'de [u8], config: DecoderConfig) -> Result<Self::RustType>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        let decoded = crate::decode_with_config::<Self::TokenType<'de>>(data, config)?;
+        if config.validate {
             Self::type_check(&decoded)?;
         }
         Ok(Self::detokenize(decoded))
     }
 
-    /// Decode a Rust type from an ABI blob.
+    /// Decode a Rust type from the start of an ABI blob, using the given
+    /// decoder configuration. Trailing bytes are always allowed.
+    ///
+    /// Returns the decoded value along with the number of bytes it occupies,
+    /// so that any trailing data can be inspected by the caller.
     #[inline]
-    fn decode_single(data: &[u8], validate: bool) -> Result<Self::RustType> {
-        let decoded = crate::decode_single::<Self::TokenType<'_>>(data, validate)?;
-        if validate {
+    fn decode_prefix_with_config<'de>(
+        data: &'de [u8],
+        config: DecoderConfig,
+    ) -> Result<(Self::RustType, usize)>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        let config = config.with_allow_trailing(true);
+        let (decoded, consumed) =
+            crate::decode_prefix_with_config::<Self::TokenType<'de>>(data, config)?;
+        if config.validate {
+            Self::type_check(&decoded)?;
+        }
+        Ok((Self::detokenize(decoded), consumed))
+    }
+
+    /// Decode a Rust type from an ABI blob, using the given decoder
+    /// configuration.
+    ///
+    /// Use a restrictive [`DecoderConfig`] when decoding untrusted input.
+    #[inline]
+    fn decode_params_with_config<'de>(
+        data: &'de [u8],
+        config: DecoderConfig,
+    ) -> Result<Self::RustType>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        let decoded = crate::decode_params_with_config::<Self::TokenType<'de>>(data, config)?;
+        if config.validate {
+            Self::type_check(&decoded)?;
+        }
+        Ok(Self::detokenize(decoded))
+    }
+
+    /// Decode a Rust type from an ABI blob, using the given decoder
+    /// configuration.
+    ///
+    /// Use a restrictive [`DecoderConfig`] when decoding untrusted input.
+    #[inline]
+    fn decode_single_with_config(data: &[u8], config: DecoderConfig) -> Result<Self::RustType> {
+        let decoded = crate::decode_single_with_config::<Self::TokenType<'_>>(data, config)?;
+        if config.validate {
             Self::type_check(&decoded)?;
         }
         Ok(Self::detokenize(decoded))
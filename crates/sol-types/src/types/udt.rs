@@ -63,12 +63,22 @@ macro_rules! define_udt {
             type TokenType<'a> = <$underlying as $crate::SolType>::TokenType<'a>;
 
             const DYNAMIC: bool = false;
+            const PACKED_ENCODED_SIZE: Option<usize> =
+                <$underlying as $crate::SolType>::PACKED_ENCODED_SIZE;
 
             #[inline]
             fn sol_type_name() -> $crate::private::Cow<'static, str> {
                 Self::NAME.into()
             }
 
+            #[inline]
+            fn fmt_value(
+                rust: &Self::RustType,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                <$underlying as $crate::SolType>::fmt_value(rust, f)
+            }
+
             #[inline]
             fn type_check(token: &Self::TokenType<'_>) -> $crate::Result<()> {
                 <$underlying as $crate::SolType>::type_check(token)?;
@@ -80,6 +90,11 @@ macro_rules! define_udt {
                 <$underlying as $crate::SolType>::detokenize(token)
             }
 
+            #[inline]
+            fn value_check(rust: &Self::RustType) -> $crate::Result<()> {
+                <$underlying as $crate::SolType>::value_check(rust)
+            }
+
             #[inline]
             fn eip712_data_word(rust: &Self::RustType) -> $crate::Word
             {
@@ -91,6 +106,30 @@ macro_rules! define_udt {
             {
                 <$underlying as $crate::SolType>::encode_packed_to(rust, out)
             }
+
+            #[inline]
+            fn decode_packed(data: &[u8]) -> $crate::Result<Self::RustType> {
+                let rust = <$underlying as $crate::SolType>::decode_packed(data)?;
+                <Self as $crate::SolType>::type_check(&$crate::Encodable::<$underlying>::to_tokens(&rust))?;
+                Ok(rust)
+            }
+        }
+
+        impl $crate::EventTopic for $name {
+            #[inline]
+            fn topic_preimage_length(rust: &Self::RustType) -> usize {
+                <$underlying as $crate::EventTopic>::topic_preimage_length(rust)
+            }
+
+            #[inline]
+            fn encode_topic_preimage(rust: &Self::RustType, out: &mut $crate::private::Vec<u8>) {
+                <$underlying as $crate::EventTopic>::encode_topic_preimage(rust, out)
+            }
+
+            #[inline]
+            fn encode_topic(rust: &Self::RustType) -> $crate::token::WordToken {
+                <$underlying as $crate::EventTopic>::encode_topic(rust)
+            }
         }
     };
 
@@ -0,0 +1,15 @@
+/// A `constant` or `immutable` state variable of a contract.
+///
+/// These variables are not stored in the contract's storage, but are instead
+/// inlined in its bytecode. Immutables are assigned in the constructor, which
+/// makes their metadata useful to reconstruct the constructor's arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StateVariable {
+    /// The name of the variable.
+    pub name: &'static str,
+    /// The Solidity type of the variable.
+    pub ty: &'static str,
+    /// The value of the variable's initializer formatted as a Solidity literal,
+    /// if it is a constant expression.
+    pub value: Option<&'static str>,
+}
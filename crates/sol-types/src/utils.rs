@@ -9,7 +9,8 @@
 
 //! Utilities used by different modules.
 
-use crate::{Error, Result, Word};
+use crate::{Error, Result, SolType, Word};
+use core::fmt;
 
 /// Calculates the padded length of a slice by rounding its length to the next
 /// word.
@@ -55,6 +56,92 @@ pub const fn just_ok<T>(_: &T) -> crate::Result<()> {
     Ok(())
 }
 
+/// Returns the total length of `parts`. Exists for the `sol` macro, see
+/// [`concat_bytes`].
+#[doc(hidden)]
+pub const fn concat_len(parts: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Concatenates `parts` at compile time. `N` must be [`concat_len(parts)`].
+///
+/// Exists for the `sol` macro, which builds the signatures of items that
+/// refer to types declared in other invocations out of their ABI types.
+///
+/// [`concat_len(parts)`]: concat_len
+#[doc(hidden)]
+pub const fn concat_bytes<const N: usize>(parts: &[&str]) -> [u8; N] {
+    let mut out = [0u8; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let part = parts[i].as_bytes();
+        let mut j = 0;
+        while j < part.len() {
+            out[len] = part[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(len == N, "length mismatch");
+    out
+}
+
+/// Converts the output of [`concat_bytes`] back to a string.
+#[doc(hidden)]
+pub const fn concat_str(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("invalid UTF-8"),
+    }
+}
+
+/// Returns the selector of a function or error signature at compile time.
+#[doc(hidden)]
+pub const fn const_selector(signature: &str) -> [u8; 4] {
+    let hash = alloy_primitives::private::const_keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Sorts selectors at compile time, for the `SELECTORS` of the interface
+/// enums generated by the `sol` macro.
+#[doc(hidden)]
+pub const fn sort_selectors<const N: usize, const M: usize>(
+    mut selectors: [[u8; M]; N],
+) -> [[u8; M]; N] {
+    // insertion sort
+    let mut i = 1;
+    while i < N {
+        let mut j = i;
+        while j > 0 && selector_lt(&selectors[j], &selectors[j - 1]) {
+            let tmp = selectors[j];
+            selectors[j] = selectors[j - 1];
+            selectors[j - 1] = tmp;
+            j -= 1;
+        }
+        i += 1;
+    }
+    selectors
+}
+
+const fn selector_lt<const M: usize>(a: &[u8; M], b: &[u8; M]) -> bool {
+    let mut i = 0;
+    while i < M {
+        if a[i] != b[i] {
+            return a[i] < b[i]
+        }
+        i += 1;
+    }
+    false
+}
+
 #[inline]
 pub(crate) fn check_zeroes(data: &[u8]) -> bool {
     data.iter().all(|b| *b == 0)
@@ -77,9 +164,60 @@ pub(crate) fn as_u32(word: Word, type_check: bool) -> Result<u32> {
     Ok(result)
 }
 
+/// Checks that a word is a canonical `bool` encoding, i.e. `0` or `1`.
 #[inline]
 pub(crate) fn check_bool(slice: Word) -> bool {
-    check_zeroes(&slice[..31])
+    check_zeroes(&slice[..31]) && slice[31] <= 1
+}
+
+/// Checks that `data` is exactly as long as the packed encoding of `T`.
+#[inline]
+pub(crate) fn check_packed_len<T: SolType + ?Sized>(data: &[u8]) -> Result<()> {
+    match T::PACKED_ENCODED_SIZE {
+        Some(len) if data.len() < len => Err(Error::Overrun),
+        Some(len) if data.len() > len => Err(T::type_check_fail(data)),
+        _ => Ok(()),
+    }
+}
+
+/// Splits off the packed encoding of the next member of a sequence.
+///
+/// Dynamically-sized members may only appear at the end of the sequence, in
+/// which case they consume the remainder of the data.
+#[inline]
+pub(crate) fn split_packed<'a, T: SolType + ?Sized>(
+    data: &mut &'a [u8],
+    is_last: bool,
+) -> Result<&'a [u8]> {
+    let len = match T::PACKED_ENCODED_SIZE {
+        Some(len) => len,
+        None if is_last => data.len(),
+        None => return Err(Error::ambiguous_packed(T::sol_type_name())),
+    };
+    if data.len() < len {
+        return Err(Error::Overrun)
+    }
+    let (member, rest) = data.split_at(len);
+    *data = rest;
+    Ok(member)
+}
+
+/// Formats a sequence of values with [`SolType::fmt_value`], separated by
+/// commas and surrounded by `open` and `close`.
+pub(crate) fn fmt_seq<'a, T: SolType + ?Sized>(
+    open: &str,
+    values: impl IntoIterator<Item = &'a T::RustType>,
+    close: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    f.write_str(open)?;
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        T::fmt_value(value, f)?;
+    }
+    f.write_str(close)
 }
 
 #[cfg(test)]
@@ -95,6 +233,18 @@ mod tests {
         assert_eq!(words_for(&[0; 33]), 2);
     }
 
+    #[test]
+    fn const_signatures() {
+        const PARTS: &[&str] = &["transfer(", "address", ",uint256)"];
+        const BYTES: [u8; concat_len(PARTS)] = concat_bytes(PARTS);
+        const SIGNATURE: &str = concat_str(&BYTES);
+        assert_eq!(SIGNATURE, "transfer(address,uint256)");
+        assert_eq!(const_selector(SIGNATURE), [0xa9, 0x05, 0x9c, 0xbb]);
+
+        const SORTED: [[u8; 2]; 4] = sort_selectors([[2, 0], [0, 1], [1, 0], [0, 0]]);
+        assert_eq!(SORTED, [[0, 0], [0, 1], [1, 0], [2, 0]]);
+    }
+
     #[test]
     fn test_pad_u32() {
         // this will fail if endianness is not supported
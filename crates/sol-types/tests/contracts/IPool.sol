@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+/// @title A minimal liquidity pool interface.
+interface IPool {
+    struct Reserves {
+        uint112 reserve0;
+        uint112 reserve1;
+    }
+
+    event Swap(address indexed sender, uint256 amountIn, uint256 amountOut);
+
+    error InsufficientLiquidity(uint256 available);
+
+    function getReserves() external view returns (Reserves memory reserves);
+    function swap(uint256 amountIn, address to) external returns (uint256 amountOut);
+    function swap(uint256 amountIn, address to, bytes calldata data) external returns (uint256 amountOut);
+}
+
+contract Pool {
+    uint256 internal _fee;
+
+    constructor(uint256 fee) {
+        _fee = fee;
+    }
+
+    function swap(uint256 amountIn, address to) external returns (uint256 amountOut) {
+        require(amountIn > 0, 'Pool: INSUFFICIENT_INPUT_AMOUNT');
+        amountOut = amountIn - _fee;
+    }
+
+    function swap(uint256 amountIn, address to, bytes calldata data) external returns (uint256 amountOut) {
+        require(data.length > 0, "Pool: EMPTY_DATA");
+        amountOut = amountIn - _fee;
+    }
+}
@@ -1,4 +1,5 @@
 mod doctests {
+    mod constants;
     mod contracts;
     mod events;
     mod function_like;
@@ -0,0 +1,95 @@
+use alloy_primitives::{keccak256, Address, I256, U256};
+use alloy_sol_types::sol;
+
+// Constants generate Rust `const` items, evaluated at compile time
+sol! {
+    uint256 constant MAX_SUPPLY = 10_000;
+    uint256 constant ONE_ETHER = 1 ether;
+    uint64 constant DELAY = 2 days;
+    int256 constant MIN_PRICE = -1e18;
+    int8 constant MIN_I8 = type(int8).min;
+    uint256 constant HALF = MAX_SUPPLY / 2 + 1;
+    bool constant PAUSED = false;
+    address constant DEAD = 0x000000000000000000000000000000000000dEaD;
+    bytes32 constant MINTER_ROLE = keccak256("MINTER_ROLE");
+    bytes4 constant TRANSFER = bytes4(keccak256("transfer(address,uint256)"));
+    string constant NAME = "Token";
+
+    // Arithmetic is signed if the declared type is
+    int256 constant NEG_ONE = 1 - 2;
+    int16 constant NEG_PRODUCT = 3*-2;
+    int256 constant NEG_HALF = -3 >> 1;
+    uint256 constant BPS = 1_000;
+
+    // Fractional literals are allowed if the result is an integer
+    uint256 constant HALF_ETHER = 0.5 ether;
+    uint64 constant HALF_GWEI = 1.5 gwei;
+    uint256 constant MILLI = 2.5e-3 ether;
+    // Intermediate values may exceed 256 bits
+    uint256 constant UINT_MAX = 2**256 - 1;
+
+    // Initializers that cannot be evaluated at compile time are skipped
+    bytes32 constant PACKED = keccak256(abi.encodePacked("a"));
+    uint256 constant NOT_AN_INTEGER = 0.5;
+    uint256 constant TOO_LARGE = 2**256;
+}
+
+// Constants can be used in const contexts
+const _: () = assert!(!PAUSED);
+
+// Constants declared inside of a contract are part of its module
+sol! {
+    contract Fees {
+        uint16 constant BPS = 100_00;
+        uint16 public constant FEE = BPS / 200;
+    }
+
+    // Names are resolved in the enclosing contract first
+    contract Shares {
+        uint16 constant BPS = 100;
+        uint16 constant QUARTER = BPS / 4;
+    }
+
+    interface IToken {}
+
+    contract Registry {
+        IToken constant TOKEN = IToken(0x000000000000000000000000000000000000dEaD);
+        uint256 constant VERSION = 2;
+    }
+}
+
+#[test]
+fn constants() {
+    assert_eq!(MAX_SUPPLY, U256::from(10_000));
+    assert_eq!(ONE_ETHER, U256::from(10u64.pow(18)));
+    assert_eq!(DELAY, 2 * 24 * 60 * 60);
+    assert_eq!(MIN_PRICE, I256::try_from(-10i64.pow(18)).unwrap());
+    assert_eq!(MIN_I8, i8::MIN);
+    assert_eq!(HALF, U256::from(5_001));
+    assert_eq!(
+        DEAD,
+        "0x000000000000000000000000000000000000dEaD"
+            .parse::<Address>()
+            .unwrap()
+    );
+    assert_eq!(MINTER_ROLE, keccak256("MINTER_ROLE").0);
+    assert_eq!(TRANSFER, [0xa9, 0x05, 0x9c, 0xbb]);
+    assert_eq!(NAME, "Token");
+    assert_eq!(NEG_ONE, I256::MINUS_ONE);
+    assert_eq!(NEG_PRODUCT, -6);
+    assert_eq!(NEG_HALF, I256::try_from(-2).unwrap());
+    assert_eq!(HALF_ETHER, U256::from(5 * 10u64.pow(17)));
+    assert_eq!(HALF_GWEI, 1_500_000_000);
+    assert_eq!(MILLI, U256::from(25 * 10u64.pow(14)));
+    assert_eq!(UINT_MAX, U256::MAX);
+
+    assert_eq!(Fees::BPS, 10_000);
+    assert_eq!(Fees::FEE, 50);
+    assert_eq!(Shares::QUARTER, 25);
+    assert_eq!(Registry::VERSION, U256::from(2));
+    // public constants also have a getter
+    assert_eq!(
+        <Fees::FEECall as alloy_sol_types::SolCall>::SIGNATURE,
+        "FEE()"
+    );
+}
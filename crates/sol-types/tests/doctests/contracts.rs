@@ -1,5 +1,7 @@
-use alloy_primitives::{address, U256};
-use alloy_sol_types::{sol, SolCall, SolInterface};
+use alloy_primitives::{address, Address, U256};
+use alloy_sol_types::{
+    sol, ContractError, Revert, SolCall, SolConstructor, SolError, SolInterface,
+};
 use hex_literal::hex;
 
 sol! {
@@ -18,6 +20,61 @@ sol! {
         function approve(address spender, uint256 amount) external returns (bool);
         function transferFrom(address from, address to, uint256 amount) external returns (bool);
     }
+
+    /// A calls enum is generated even for a single function.
+    #[derive(Debug, PartialEq)]
+    interface IPing {
+        function ping(uint256 nonce) external;
+
+        error Unauthorized(address caller);
+    }
+
+    /// Libraries are expanded like contracts, but their function signatures
+    /// refer to structs and enums by their fully qualified name.
+    library Math {
+        struct Point {
+            uint256 x;
+            uint256 y;
+        }
+
+        enum Rounding {
+            Down,
+            Up
+        }
+
+        error DivisionByZero();
+
+        function mulDiv(uint256 x, uint256 y, uint256 d, Rounding r) external pure returns (uint256);
+        function norm(Point memory p) public pure returns (uint256);
+        function scale(Point storage p, uint256 k) external;
+        // internal functions are not part of the ABI
+        function half(uint256 x) internal pure returns (uint256);
+    }
+
+    /// Public state variables generate their getter functions.
+    contract Registry {
+        struct Entry {
+            address owner;
+            uint64 expiry;
+            bytes data;
+            uint256[] history;
+        }
+
+        uint256 public totalSupply;
+        mapping(address => uint256) public balanceOf;
+        mapping(address owner => mapping(address spender => uint256)) public allowance;
+        address[] public members;
+        mapping(bytes32 => Entry) public entries;
+        uint256 internal secret;
+    }
+
+    /// Constructors generate a `<contract_name>Constructor` struct.
+    #[derive(Debug, PartialEq)]
+    contract Token {
+        constructor(address owner, uint256 cap);
+
+        function cap() external view returns (uint256);
+    }
 }
 
 #[test]
@@ -38,4 +95,154 @@ fn contracts() {
     let decoded = IERC20::IERC20Calls::decode(&data, true).unwrap();
     assert_eq!(decoded, IERC20::IERC20Calls::transfer(expected));
     assert_eq!(decoded.encode(), data);
+
+    // the selector table is sorted
+    assert_eq!(IERC20::IERC20Calls::SELECTORS.len(), 6);
+    assert!(IERC20::IERC20Calls::SELECTORS
+        .windows(2)
+        .all(|w| w[0] < w[1]));
+    assert!(IERC20::IERC20Calls::SELECTORS.contains(&IERC20::transferCall::SELECTOR));
+
+    let unknown = hex!("deadbeef");
+    assert_eq!(
+        IERC20::IERC20Calls::decode_raw(unknown, &data[4..], true),
+        Err(alloy_sol_types::Error::unknown_selector(
+            "IERC20Calls",
+            unknown
+        ))
+    );
+}
+
+#[test]
+fn single_function_interface() {
+    let call = IPing::pingCall {
+        nonce: U256::from(1),
+    };
+    let data = call.encode();
+    let decoded = IPing::IPingCalls::decode(&data, true).unwrap();
+    assert_eq!(decoded, IPing::IPingCalls::ping(call));
+    assert_eq!(IPing::IPingCalls::SELECTORS, [IPing::pingCall::SELECTOR]);
+}
+
+#[test]
+fn decode_revert_data() {
+    let error = IPing::Unauthorized {
+        caller: address!("8bc47be1e3abbaba182069c89d08a61fa6c2b292"),
+    };
+    let revert_data = error.encode();
+    let decoded = IPing::IPingErrors::decode(&revert_data, true).unwrap();
+    assert_eq!(decoded, IPing::IPingErrors::Unauthorized(error.clone()));
+
+    // `ContractError` also covers the built-in reverts
+    type Errors = ContractError<IPing::IPingErrors>;
+    assert_eq!(
+        Errors::decode(&revert_data, true).unwrap(),
+        Errors::CustomError(IPing::IPingErrors::Unauthorized(error))
+    );
+    let revert = Revert::from("not allowed");
+    assert_eq!(
+        Errors::decode(&revert.encode(), true).unwrap(),
+        Errors::Revert(revert)
+    );
+}
+
+#[test]
+fn constructor() {
+    let args = Token::TokenConstructor {
+        owner: address!("8bc47be1e3abbaba182069c89d08a61fa6c2b292"),
+        cap: U256::from(1000),
+    };
+    let encoded = args.encode();
+    assert_eq!(
+        encoded,
+        hex!(
+            "0000000000000000000000008bc47be1e3abbaba182069c89d08a61fa6c2b292"
+            "00000000000000000000000000000000000000000000000000000000000003e8"
+        )
+    );
+    assert_eq!(
+        Token::TokenConstructor::decode(&encoded, true).unwrap(),
+        args
+    );
+
+    // deployment data is the creation code followed by the encoded arguments
+    let bytecode = hex!("6080604052");
+    let deploy = args.encode_deploy(&bytecode);
+    assert_eq!(deploy[..5], bytecode);
+    assert_eq!(deploy[5..], encoded);
+
+    // the constructor is not part of the calls enum
+    assert_eq!(Token::TokenCalls::SELECTORS, [Token::capCall::SELECTOR]);
+}
+
+#[test]
+fn contract_derives() {
+    // a contract's derives are applied to all of its generated types,
+    // including return structs
+    let data = U256::from(1000).to_be_bytes::<32>();
+    assert_eq!(
+        Token::capCall::decode_returns(&data, true),
+        Ok(Token::capReturn {
+            _0: U256::from(1000)
+        })
+    );
+}
+
+#[test]
+fn library() {
+    assert_eq!(
+        Math::mulDivCall::SIGNATURE,
+        "mulDiv(uint256,uint256,uint256,Math.Rounding)"
+    );
+    assert_eq!(Math::normCall::SIGNATURE, "norm(Math.Point)");
+    assert_eq!(
+        Math::scaleCall::SIGNATURE,
+        "scale(Math.Point storage,uint256)"
+    );
+    assert_eq!(Math::DivisionByZero::SIGNATURE, "DivisionByZero()");
+
+    assert_eq!(Math::MathCalls::SELECTORS.len(), 3);
+    assert!(!Math::MathCalls::SELECTORS.contains(&Math::halfCall::SELECTOR));
+
+    let call = Math::mulDivCall {
+        x: U256::from(1),
+        y: U256::from(2),
+        d: U256::from(3),
+        r: Math::Rounding::Up,
+    };
+    let data = call.encode();
+    assert_eq!(data[..4], Math::mulDivCall::SELECTOR);
+    assert!(matches!(
+        Math::MathCalls::decode(&data, true),
+        Ok(Math::MathCalls::mulDiv(_))
+    ));
+}
+
+#[test]
+fn getters() {
+    use Registry::*;
+
+    assert_eq!(totalSupplyCall::SIGNATURE, "totalSupply()");
+    assert_eq!(balanceOfCall::SIGNATURE, "balanceOf(address)");
+    assert_eq!(allowanceCall::SIGNATURE, "allowance(address,address)");
+    assert_eq!(membersCall::SIGNATURE, "members(uint256)");
+    assert_eq!(entriesCall::SIGNATURE, "entries(bytes32)");
+    assert_eq!(RegistryCalls::SELECTORS.len(), 5);
+
+    let _ = allowanceCall {
+        owner: Address::ZERO,
+        spender: Address::ZERO,
+    };
+    let _ = membersCall { _0: U256::ZERO };
+
+    // struct members are returned individually, without arrays and mappings
+    let returns = entriesReturn {
+        owner: Address::ZERO,
+        expiry: 42,
+        data: vec![1, 2, 3],
+    };
+    let data = entriesCall::encode_returns(&returns);
+    let decoded = entriesCall::decode_returns(&data, true).unwrap();
+    assert_eq!(decoded.expiry, 42);
+    assert_eq!(decoded.data, [1, 2, 3]);
 }
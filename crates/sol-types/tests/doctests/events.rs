@@ -21,6 +21,30 @@ sol! {
         bytes data;
     }
     event MyEvent2(Data indexed data);
+
+    struct Point {
+        uint256 x;
+        uint256 y;
+    }
+    enum Kind {
+        A,
+        B
+    }
+    event MyEvent3(uint256[2] indexed arr, Point indexed point, Kind indexed kind);
+
+    event AnonEvent(
+        uint256,
+        address indexed,
+        string indexed,
+        bytes32 indexed,
+        bool indexed,
+        bytes
+    ) anonymous;
+
+    contract MyContract {
+        event Named(uint256 a);
+        event Anon(uint256 a) anonymous;
+    }
 }
 
 #[test]
@@ -30,7 +54,7 @@ fn event() {
     let event = MyEvent {
         a: [0x11; 32],
         b: U256::from(1u64),
-        c: keccak256("Hello World").into(),
+        c: keccak256("Hello World"),
         d: Vec::new(),
     };
     // topics are `(SELECTOR, a, keccak256(c))`
@@ -62,6 +86,234 @@ fn event() {
     assert!(!MyEvent2::ANONYMOUS);
 }
 
+#[test]
+fn event_indexed_hashes() {
+    use alloy_sol_types::{sol_data, SolType};
+
+    // static arrays and structs are hashed even though they are not dynamic,
+    // while value types, including enums, are stored as-is
+    let arr = keccak256(<sol_data::FixedArray<sol_data::Uint<256>, 2>>::encode(&[
+        U256::from(1u64),
+        U256::from(2u64),
+    ]));
+    let point = keccak256(Point::encode(&Point {
+        x: U256::from(3u64),
+        y: U256::from(4u64),
+    }));
+    let event = MyEvent3 {
+        arr,
+        point,
+        kind: Kind::B,
+    };
+    let topics = event.encode_topics_array::<4>();
+    assert_eq!(topics[1], WordToken(arr));
+    assert_eq!(topics[2], WordToken(point));
+    assert_eq!(topics[3], WordToken(B256::with_last_byte(1)));
+
+    let decoded = MyEvent3::decode_raw_log(topics, &event.encode_data(), true).unwrap();
+    assert_eq!(decoded.arr, arr);
+    assert_eq!(decoded.point, point);
+    assert!(matches!(decoded.kind, Kind::B));
+}
+
+#[test]
+fn anonymous_event() {
+    assert!(AnonEvent::ANONYMOUS);
+    let event = AnonEvent {
+        _0: U256::from(1u64),
+        _1: alloy_primitives::Address::repeat_byte(0x11),
+        _2: keccak256("hello"),
+        _3: [0x33; 32],
+        _4: true,
+        _5: vec![0x55; 2],
+    };
+
+    // no signature hash: all four topics are indexed params
+    let topics = event.encode_topics_array::<4>();
+    assert_eq!(
+        topics,
+        [
+            WordToken(alloy_primitives::Address::repeat_byte(0x11).into_word()),
+            WordToken(keccak256("hello")),
+            WordToken(B256::repeat_byte(0x33)),
+            WordToken(B256::with_last_byte(1)),
+        ]
+    );
+
+    let data = event.encode_data();
+    let decoded = AnonEvent::decode_raw_log(topics, &data, true).unwrap();
+    assert_eq!(decoded._0, event._0);
+    assert_eq!(decoded._1, event._1);
+    assert_eq!(decoded._2, event._2);
+    assert_eq!(decoded._3, event._3);
+    assert_eq!(decoded._4, event._4);
+    assert_eq!(decoded._5, event._5);
+
+    // anonymous events are never matched by their selector
+    assert_eq!(MyContract::MyContractEvents::SELECTORS.len(), 1);
+    assert_eq!(
+        MyContract::MyContractEvents::SELECTORS[0],
+        MyContract::Named::SIGNATURE_HASH
+    );
+}
+
+#[test]
+fn events_enum() {
+    use MyContract::{MyContractEvents, Named};
+
+    let event = Named {
+        a: U256::from(42u64),
+    };
+    let decoded =
+        MyContractEvents::decode_log(event.encode_topics(), &event.encode_data(), true).unwrap();
+    match decoded {
+        MyContractEvents::Named(decoded) => assert_eq!(decoded.a, event.a),
+        MyContractEvents::Anon(_) => panic!("anonymous events are never decoded"),
+    }
+
+    let unknown = [WordToken(B256::repeat_byte(0xff))];
+    assert_eq!(
+        MyContractEvents::decode_log(unknown, &event.encode_data(), true).err(),
+        Some(alloy_sol_types::Error::UnknownEventSignature {
+            name: "MyContractEvents",
+            hash: B256::repeat_byte(0xff),
+        })
+    );
+    assert_eq!(
+        MyContractEvents::decode_log([] as [WordToken; 0], &[], true).err(),
+        Some(alloy_sol_types::Error::TopicLengthMismatch {
+            expected: 1,
+            actual: 0,
+        })
+    );
+}
+
+#[test]
+fn decode_raw_log() {
+    let event = MyEvent {
+        a: [0x11; 32],
+        b: U256::from(1u64),
+        c: keccak256("Hello World"),
+        d: vec![0x22; 3],
+    };
+    let topics = event.encode_topics();
+    let data = event.encode_data();
+
+    let decoded = MyEvent::decode_raw_log(topics.iter().copied(), &data, true).unwrap();
+    assert_eq!(decoded.a, event.a);
+    assert_eq!(decoded.b, event.b);
+    assert_eq!(decoded.c, event.c);
+    assert_eq!(decoded.d, event.d);
+
+    // wrong topic0
+    let mut bad_topics = topics.clone();
+    bad_topics[0] = WordToken(B256::repeat_byte(0xff));
+    assert_eq!(
+        MyEvent::decode_raw_log(bad_topics.iter().copied(), &data, true).err(),
+        Some(alloy_sol_types::Error::EventSignatureMismatch {
+            expected: MyEvent::SIGNATURE_HASH,
+            got: B256::repeat_byte(0xff),
+        })
+    );
+    // `decode_log` does not check topic0
+    assert!(MyEvent::decode_log(bad_topics, &data, true).is_ok());
+
+    // too few and too many topics
+    assert_eq!(
+        MyEvent::decode_raw_log(topics[..2].iter().copied(), &data, true).err(),
+        Some(alloy_sol_types::Error::TopicLengthMismatch {
+            expected: topics.len(),
+            actual: 2,
+        })
+    );
+    let extra = topics.iter().copied().chain([WordToken(B256::ZERO)]);
+    assert_eq!(
+        MyEvent::decode_raw_log(extra, &data, true).err(),
+        Some(alloy_sol_types::Error::TopicLengthMismatch {
+            expected: topics.len(),
+            actual: topics.len() + 1,
+        })
+    );
+}
+
+#[test]
+fn log_data() {
+    let event = MyEvent {
+        a: [0x11; 32],
+        b: U256::from(1u64),
+        c: keccak256("Hello World"),
+        d: vec![0x22; 3],
+    };
+
+    let log = event.encode_log_data();
+    assert_eq!(log.topics().len(), 3);
+    assert_eq!(log.topics()[0], MyEvent::SIGNATURE_HASH);
+    assert_eq!(log.data[..], event.encode_data()[..]);
+
+    let decoded = MyEvent::decode_log_data(&log, true).unwrap();
+    assert_eq!(
+        (decoded.a, decoded.b, decoded.d),
+        (event.a, event.b, event.d)
+    );
+}
+
+#[test]
+fn event_filter() {
+    use alloy_sol_types::SolType;
+
+    // Each event has a filter builder which yields the topics used by
+    // `eth_getLogs`; unset indexed parameters match any value
+    assert_eq!(
+        MyEvent::filter().topics(),
+        [Some(MyEvent::SIGNATURE_HASH), None, None, None]
+    );
+
+    // dynamic values are hashed like in the event's topics
+    let filter = MyEvent::filter().c("Hello World".into());
+    assert_eq!(filter.c, Some(keccak256("Hello World")));
+    assert_eq!(
+        filter.topics(),
+        [
+            Some(MyEvent::SIGNATURE_HASH),
+            None,
+            Some(keccak256("Hello World")),
+            None
+        ]
+    );
+
+    let filter = MyEvent3::filter()
+        .point(Point {
+            x: U256::from(3u64),
+            y: U256::from(4u64),
+        })
+        .kind(Kind::B);
+    let point = keccak256(Point::encode(&Point {
+        x: U256::from(3u64),
+        y: U256::from(4u64),
+    }));
+    assert_eq!(
+        filter.topics(),
+        [
+            Some(MyEvent3::SIGNATURE_HASH),
+            None,
+            Some(point),
+            Some(B256::with_last_byte(1))
+        ]
+    );
+
+    // anonymous events have no signature topic
+    let guy = alloy_primitives::Address::repeat_byte(0x11);
+    assert_eq!(
+        LogNote::filter().guy(guy).bar([0x22; 32]).topics(),
+        [
+            None,
+            Some(guy.into_word()),
+            None,
+            Some(B256::repeat_byte(0x22))
+        ]
+    );
+}
+
 fn assert_event_signature<T: SolEvent>(expected: &str) {
     assert_eq!(T::SIGNATURE, expected);
     assert_eq!(T::SIGNATURE_HASH, keccak256(expected));
@@ -1,10 +1,21 @@
-use alloy_primitives::{keccak256, U256};
-use alloy_sol_types::{sol, SolCall, SolError};
+use alloy_primitives::{keccak256, Address, U256};
+use alloy_sol_types::{sol, SolCall, SolError, SolInterface};
 use hex_literal::hex;
 
 sol! {
     function foo(uint256 a, uint256 b) external view returns (uint256);
 
+    // Return values generate a struct with named fields.
+    /// @notice Returns the reserves of the pair.
+    #[derive(Debug, PartialEq)]
+    function getReserves() external view returns (
+        /// @return The reserve of the first token.
+        uint112 reserve0,
+        /// @return The reserve of the second token.
+        uint112 reserve1,
+        uint32
+    );
+
     // These will generate structs prefixed with `overloaded_0`, `overloaded_1`,
     // and `overloaded_2` by default, but each signature is calculated with
     // `overloaded` as the function name.
@@ -12,6 +23,16 @@ sol! {
     function overloaded(uint256) returns (uint256);
     function overloaded(string);
 
+    // Overloaded functions are all included in a contract's calls enum.
+    // The generated names can be customized with `#[sol(rename = "...")]`.
+    #[derive(Debug, PartialEq)]
+    contract Transfers {
+        function transfer(address to, uint256 amount) returns (bool);
+        function transfer(address to, uint256 amount, bytes data) returns (bool);
+        #[sol(rename = "transferBatch")]
+        function transfer(address[] to, uint256 amount) returns (bool);
+    }
+
     /// Implements [`SolError`].
     #[derive(Debug, PartialEq)]
     error MyError(uint256 a, uint256 b);
@@ -38,6 +59,113 @@ fn function() {
     assert_call_signature::<overloaded_2Call>("overloaded(string)");
 }
 
+#[test]
+fn function_returns() {
+    // `eth_call` output for `getReserves()`
+    let data = hex!(
+        "000000000000000000000000000000000000000000000000000000000000002a"
+        "0000000000000000000000000000000000000000000000000000000000000045"
+        "0000000000000000000000000000000000000000000000000000000064c3a7f0"
+    );
+    let returns = getReservesCall::decode_returns(&data, true).unwrap();
+    assert_eq!(
+        returns,
+        getReservesReturn {
+            reserve0: 42,
+            reserve1: 69,
+            _2: 0x64c3a7f0,
+        }
+    );
+    assert_eq!(getReservesCall::encode_returns(&returns), data);
+
+    // not enough data
+    assert!(getReservesCall::decode_returns(&data[..64], true).is_err());
+}
+
+#[test]
+fn overloads() {
+    use Transfers::*;
+
+    assert_call_signature::<transfer_0Call>("transfer(address,uint256)");
+    assert_call_signature::<transfer_1Call>("transfer(address,uint256,bytes)");
+    assert_call_signature::<transferBatchCall>("transfer(address[],uint256)");
+
+    let call = transfer_1Call {
+        to: Address::ZERO,
+        amount: U256::from(1),
+        data: vec![1, 2, 3],
+    };
+    let data = call.encode();
+    assert_eq!(
+        TransfersCalls::decode(&data, true),
+        Ok(TransfersCalls::transfer_1(call))
+    );
+
+    let call = transferBatchCall {
+        to: vec![Address::ZERO],
+        amount: U256::from(1),
+    };
+    let data = call.encode();
+    assert_eq!(
+        TransfersCalls::decode(&data, true),
+        Ok(TransfersCalls::transferBatch(call))
+    );
+    assert_eq!(TransfersCalls::SELECTORS.len(), 3);
+}
+
+#[test]
+fn display() {
+    use Transfers::*;
+
+    // Calls are displayed with their arguments formatted as Solidity values
+    let call = transfer_1Call {
+        to: Address::repeat_byte(0x11),
+        amount: U256::from(10).pow(U256::from(18)),
+        data: vec![0xde, 0xad],
+    };
+    assert_eq!(
+        call.to_string(),
+        "transfer(0x1111111111111111111111111111111111111111, 1000000000000000000, 0xdead)"
+    );
+
+    // The alternate flag abbreviates addresses
+    let call = TransfersCalls::transferBatch(transferBatchCall {
+        to: vec![Address::repeat_byte(0x22), Address::ZERO],
+        amount: U256::from(1),
+    });
+    assert_eq!(
+        format!("{call:#}"),
+        "transfer([0x2222…2222, 0x0000…0000], 1)"
+    );
+}
+
+#[test]
+fn mutability() {
+    use alloy_sol_types::{StateMutability, Visibility};
+
+    // State mutability and visibility are exposed as associated constants,
+    // defaulting to `nonpayable` and `external` respectively
+    assert_eq!(fooCall::STATE_MUTABILITY, StateMutability::View);
+    assert_eq!(fooCall::VISIBILITY, Visibility::External);
+    assert!(fooCall::STATE_MUTABILITY.is_read_only());
+
+    assert_eq!(
+        overloaded_0Call::STATE_MUTABILITY,
+        StateMutability::NonPayable
+    );
+    assert_eq!(overloaded_0Call::VISIBILITY, Visibility::External);
+
+    sol! {
+        function deposit() public payable;
+        function sum(uint256 a, uint256 b) internal pure returns (uint256);
+    }
+    assert_eq!(depositCall::STATE_MUTABILITY, StateMutability::Payable);
+    assert_eq!(depositCall::VISIBILITY, Visibility::Public);
+    assert!(!depositCall::STATE_MUTABILITY.is_read_only());
+    assert_eq!(sumCall::STATE_MUTABILITY, StateMutability::Pure);
+    assert_eq!(sumCall::VISIBILITY.as_str(), "internal");
+}
+
 #[test]
 fn error() {
     assert_error_signature::<MyError>("MyError(uint256,uint256)");
@@ -52,6 +180,26 @@ fn error() {
             b: U256::from(2)
         })
     );
+
+    // revert data is the selector followed by the encoded arguments
+    let error = MyError {
+        a: U256::from(1),
+        b: U256::from(2),
+    };
+    let revert_data = error.encode();
+    assert_eq!(revert_data[..4], MyError::SELECTOR);
+    assert_eq!(revert_data[4..], call_data);
+    assert_eq!(MyError::decode(&revert_data, true), Ok(error));
+
+    let mut bad_data = revert_data;
+    bad_data[0] ^= 0xff;
+    assert_eq!(
+        MyError::decode(&bad_data, true),
+        Err(alloy_sol_types::Error::selector_mismatch(
+            MyError::SELECTOR,
+            &bad_data
+        ))
+    );
 }
 
 fn assert_call_signature<T: SolCall>(expected: &str) {
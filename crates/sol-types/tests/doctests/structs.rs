@@ -1,9 +1,14 @@
-use alloy_primitives::{hex, Address, U256};
-use alloy_sol_types::{sol, SolEnum, SolType};
+use alloy_primitives::{b256, hex, Address, U256};
+use alloy_sol_types::{eip712_domain, sol, SolEnum, SolStruct, SolType};
 
 sol! {
+    /// Doc comments, including NatSpec tags, are kept on the generated struct.
+    ///
+    /// @notice A simple struct.
     struct Foo {
+        /// @dev Documentation is also kept on each field.
         uint256 bar;
+        /** Block doc comments work too. */
         address[] baz;
     }
 
@@ -44,6 +49,25 @@ fn structs() {
         }
     );
 
+    // `abi.encodePacked(foo.bar, foo.baz)`: array elements are padded
+    assert_eq!(
+        my_foo.abi_encode_packed(),
+        hex! {
+            "000000000000000000000000000000000000000000000000000000000000002a" // bar
+            "0000000000000000000000001111111111111111111111111111111111111111" // baz[0]
+            "0000000000000000000000002222222222222222222222222222222222222222" // baz[1]
+        }
+    );
+    assert_eq!(
+        _nested.abi_encode_packed(),
+        [
+            my_foo.abi_encode_packed(),
+            my_foo.abi_encode_packed(),
+            Address::ZERO.to_vec()
+        ]
+        .concat()
+    );
+
     let abi_encoded_enum = Enum::B.encode();
     assert_eq!(
         abi_encoded_enum,
@@ -52,3 +76,99 @@ fn structs() {
         }
     );
 }
+
+#[test]
+fn enums() {
+    assert_eq!(Enum::COUNT, 3);
+    assert_eq!(u8::from(Enum::C), 2);
+    assert!(matches!(Enum::try_from(1), Ok(Enum::B)));
+    assert!(Enum::try_from(3).is_err());
+
+    let encoded = Enum::C.encode();
+    assert!(matches!(Enum::decode_single(&encoded, true), Ok(Enum::C)));
+
+    // out-of-range discriminants are rejected when validating
+    let encoded = <alloy_sol_types::sol_data::Uint<8>>::encode_single(&3);
+    assert!(Enum::decode_single(&encoded, true).is_err());
+}
+
+// All structs implement `SolStruct`, which provides EIP-712 hashing.
+// The encoded type contains all the structs referenced by a struct, directly or
+// through arrays, deduplicated and sorted by name.
+sol! {
+    struct Person {
+        string name;
+        address wallet;
+    }
+
+    struct Mail {
+        Person from;
+        Person to;
+        string contents;
+    }
+
+    struct Asset {
+        address token;
+        uint256 amount;
+    }
+
+    struct Batch {
+        Mail mail;
+        Asset[] assets;
+    }
+}
+
+#[test]
+fn eip712() {
+    assert_eq!(
+        Person::eip712_root_type(),
+        "Person(string name,address wallet)"
+    );
+    assert_eq!(
+        <Mail as SolStruct>::eip712_encode_type(),
+        "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+    );
+    assert_eq!(
+        <Batch as SolStruct>::eip712_encode_type(),
+        "Batch(Mail mail,Asset[] assets)\
+         Asset(address token,uint256 amount)\
+         Mail(Person from,Person to,string contents)\
+         Person(string name,address wallet)"
+    );
+
+    // https://eips.ethereum.org/EIPS/eip-712#specification-of-the-eth_signtypeddata-json-rpc
+    let mail = Mail {
+        from: Person {
+            name: "Cow".into(),
+            wallet: "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"
+                .parse()
+                .unwrap(),
+        },
+        to: Person {
+            name: "Bob".into(),
+            wallet: "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"
+                .parse()
+                .unwrap(),
+        },
+        contents: "Hello, Bob!".into(),
+    };
+    let domain = eip712_domain! {
+        name: "Ether Mail",
+        version: "1",
+        chain_id: 1,
+        verifying_contract: "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".parse().unwrap(),
+    };
+
+    assert_eq!(
+        mail.eip712_type_hash(),
+        b256!("a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2")
+    );
+    assert_eq!(
+        mail.eip712_hash_struct(),
+        b256!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+    );
+    assert_eq!(
+        mail.eip712_signing_hash(&domain),
+        b256!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+    );
+}
@@ -1,11 +1,25 @@
-use alloy_primitives::Address;
-use alloy_sol_types::{sol, SolType};
+use alloy_primitives::{Address, Function, B256, U256};
+use alloy_sol_types::{sol, token::WordToken, SolCall, SolEvent, SolType};
 
 // Type definition: generates a new struct that implements `SolType`
 sol! {
     type MyType is uint256;
 }
 
+// User-defined value types can be used anywhere their underlying type can
+sol! {
+    type ShortString is bytes32;
+
+    #[derive(Debug, PartialEq)]
+    struct Named {
+        ShortString name;
+        address owner;
+    }
+
+    function rename(ShortString from, ShortString to) returns (Named);
+    event Renamed(ShortString indexed from, ShortString to);
+}
+
 // Type aliases
 type B32 = sol! { bytes32 };
 // This is equivalent to the following:
@@ -14,10 +28,63 @@ type B32 = sol! { bytes32 };
 type SolArrayOf<T> = sol! { T[] };
 type SolTuple = sol! { tuple(address, bytes, string) };
 
+// Type expressions can be arbitrarily nested
+type Matrix = sol!(uint256[3][]);
+type Transfers = sol!((address, uint256)[]);
+type ShortStrings = sol!(ShortString[2]);
+
+// Function types are encoded as an address followed by a selector
+type Callback = sol!(function(uint256) external returns (bool));
+
 #[test]
 fn types() {
     let _ = <sol!(bool)>::encode_single(&true);
     let _ = B32::encode_single(&[0; 32]);
     let _ = SolArrayOf::<sol!(bool)>::encode_single(&vec![true, false]);
     let _ = SolTuple::encode_single(&(Address::ZERO, vec![0; 32], "hello".to_string()));
+
+    assert_eq!(Matrix::sol_type_name(), "uint256[3][]");
+    assert_eq!(Transfers::sol_type_name(), "(address,uint256)[]");
+    assert_eq!(ShortStrings::sol_type_name(), "ShortString[2]");
+
+    let transfers = vec![
+        (Address::ZERO, U256::from(1)),
+        (Address::ZERO, U256::from(2)),
+    ];
+    let encoded = Transfers::encode_single(&transfers);
+    assert_eq!(Transfers::decode_single(&encoded, true).unwrap(), transfers);
+
+    assert_eq!(Callback::sol_type_name(), "function");
+    let callback = Function::from([0x11; 24]);
+    let encoded = Callback::encode_single(&callback);
+    assert_eq!(Callback::decode_single(&encoded, true).unwrap(), callback);
+}
+
+#[test]
+fn user_defined_value_types() {
+    assert_eq!(ShortString::sol_type_name(), "ShortString");
+    assert_eq!(
+        <ShortString as SolType>::encode_single(&[0x11; 32]),
+        <sol!(bytes32)>::encode_single(&[0x11; 32])
+    );
+    assert_eq!(ShortString::from([0x11; 32]).into(), [0x11; 32]);
+
+    // signatures use the underlying type
+    assert_eq!(renameCall::SIGNATURE, "rename(bytes32,bytes32)");
+    assert_eq!(Renamed::SIGNATURE, "Renamed(bytes32,bytes32)");
+
+    let named = Named {
+        name: [0x22; 32],
+        owner: Address::ZERO,
+    };
+    assert_eq!(Named::decode(&Named::encode(&named), true).unwrap(), named);
+
+    let event = Renamed {
+        from: [0x11; 32],
+        to: [0x22; 32],
+    };
+    assert_eq!(
+        event.encode_topics_array::<2>()[1],
+        WordToken(B256::repeat_byte(0x11))
+    );
 }
@@ -1,5 +1,5 @@
-use alloy_primitives::{keccak256, Address, U256};
-use alloy_sol_types::{sol, SolCall, SolError, SolType};
+use alloy_primitives::{keccak256, Address, Function, U256};
+use alloy_sol_types::{sol, FixedPoint, SolCall, SolError, SolStruct, SolType};
 
 #[test]
 fn e2e() {
@@ -128,6 +128,63 @@ fn function() {
     );
 }
 
+#[test]
+fn function_type() {
+    sol! {
+        function callback(function(uint256) external returns (bool) f, address a);
+    }
+
+    let sig = "callback(function,address)";
+    assert_eq!(callbackCall::SIGNATURE, sig);
+    assert_eq!(callbackCall::SELECTOR, keccak256(sig)[..4]);
+
+    let call = callbackCall {
+        f: Function::from_address_and_selector(Address::repeat_byte(0x11), [1, 2, 3, 4]),
+        a: Address::ZERO,
+    };
+    assert_eq!(call.encoded_size(), 64);
+    let encoded = call.encode();
+    assert_eq!(encoded[4..4 + 24], call.f[..]);
+    assert_eq!(callbackCall::decode(&encoded, true).unwrap().f, call.f);
+}
+
+#[test]
+fn fixed_point_types() {
+    sol! {
+        struct Rate {
+            ufixed128x18 value;
+            fixed64x2 delta;
+        }
+
+        function quote(fixed price, ufixed8x1[] weights) returns (Rate);
+    }
+
+    let sig = "quote(fixed128x18,ufixed8x1[])";
+    assert_eq!(quoteCall::SIGNATURE, sig);
+    assert_eq!(quoteCall::SELECTOR, keccak256(sig)[..4]);
+    assert_eq!(
+        <Rate as SolStruct>::eip712_encode_type(),
+        "Rate(ufixed128x18 value,fixed64x2 delta)"
+    );
+
+    let call = quoteCall {
+        price: FixedPoint(-1_500_000_000_000_000_000),
+        weights: vec![FixedPoint(5), FixedPoint(255)],
+    };
+    let encoded = call.encode();
+    assert_eq!(
+        encoded[4..36],
+        U256::from(1_500_000_000_000_000_000u64)
+            .wrapping_neg()
+            .to_be_bytes::<32>()
+    );
+    assert_eq!(
+        quoteCall::decode(&encoded, true).unwrap().weights,
+        call.weights
+    );
+    assert_eq!(call.price.to_string(), "-1.500000000000000000");
+}
+
 #[test]
 fn error() {
     sol! {
@@ -273,6 +330,944 @@ fn abigen_sol() {
     );
 }
 
+#[test]
+fn sol_file() {
+    // single-quoted strings are supported, and functions are only considered
+    // overloaded within the same contract
+    sol!("tests/contracts/IPool.sol");
+
+    assert_eq!(IPool::getReservesCall::SIGNATURE, "getReserves()");
+    assert_eq!(IPool::swap_0Call::SIGNATURE, "swap(uint256,address)");
+    assert_eq!(IPool::swap_1Call::SIGNATURE, "swap(uint256,address,bytes)");
+    assert_eq!(Pool::swap_0Call::SELECTOR, IPool::swap_0Call::SELECTOR);
+    assert_eq!(Pool::swap_1Call::SELECTOR, IPool::swap_1Call::SELECTOR);
+    assert_eq!(
+        IPool::InsufficientLiquidity::SIGNATURE,
+        "InsufficientLiquidity(uint256)"
+    );
+}
+
+mod reexports {
+    pub use alloy_sol_types as sol_types;
+}
+
+#[test]
+fn crate_path() {
+    // the generated code refers to the runtime crate through the given path
+    sol! {
+        #![sol(crate = "crate::reexports::sol_types")]
+
+        contract Exchange {
+            type Amount is uint256;
+
+            enum Side {
+                Buy,
+                Sell,
+            }
+
+            struct Order {
+                Side side;
+                Amount amount;
+            }
+
+            event Filled(address indexed maker, Order order);
+            error Expired(uint256 deadline);
+            function fill(Order order) external returns (bool);
+        }
+    }
+
+    assert_eq!(Exchange::fillCall::SIGNATURE, "fill((uint8,uint256))");
+    assert_eq!(Exchange::Expired::SIGNATURE, "Expired(uint256)");
+}
+
+#[test]
+fn struct_field_from_other_module() {
+    mod types {
+        alloy_sol_types::sol! {
+            struct Order {
+                uint256 id;
+                address maker;
+            }
+        }
+    }
+    use types::Order;
+
+    // struct fields are plain Rust paths, so they can refer to types declared
+    // in another `sol!` invocation
+    sol! {
+        struct Fill {
+            Order order;
+            uint256 amount;
+        }
+    }
+
+    let fill = Fill {
+        order: Order {
+            id: U256::from(1),
+            maker: Address::ZERO,
+        },
+        amount: U256::from(2),
+    };
+    assert_eq!(
+        Fill::decode(&Fill::encode(&fill), true).unwrap().amount,
+        fill.amount
+    );
+    assert_eq!(Fill::sol_type_name(), "Fill");
+}
+
+mod other_invocation {
+    pub mod types {
+        alloy_sol_types::sol! {
+            type Price is uint128;
+
+            enum Side {
+                Buy,
+                Sell,
+            }
+
+            struct Asset {
+                address token;
+            }
+
+            struct Order {
+                uint256 id;
+                Asset asset;
+                Side side;
+                Price price;
+            }
+        }
+    }
+    use types::{Order, Price, Side};
+
+    alloy_sol_types::sol! {
+        #![sol(use types::Order, use types::Price)]
+
+        #[sol(use types::Side)]
+        struct Fill {
+            Order order;
+            Price[] prices;
+            Side side;
+        }
+
+        struct Batch {
+            Order[] orders;
+        }
+
+        function fill(Order order, Price price) returns (Fill);
+        function batch(Batch batch);
+        error Rejected(Order order);
+        event Filled(address indexed maker, Order order);
+
+        interface IExchange {
+            function fill(Order order) external;
+            function cancel(uint256 id) external;
+        }
+    }
+}
+
+#[test]
+fn types_from_other_invocation() {
+    use alloy_sol_types::{SolEvent, SolInterface, SolStruct};
+    use other_invocation::*;
+
+    // structs, enums and UDVTs given with `#[sol(use ...)]` are part of the
+    // EIP-712 encoded type and of signatures
+    assert_eq!(
+        <Fill as SolStruct>::eip712_encode_type(),
+        "Fill(Order order,uint128[] prices,uint8 side)\
+         Asset(address token)\
+         Order(uint256 id,Asset asset,uint8 side,uint128 price)"
+    );
+    assert_eq!(
+        <Batch as SolStruct>::eip712_encode_type(),
+        "Batch(Order[] orders)Asset(address token)Order(uint256 id,Asset asset,uint8 side,uint128 price)"
+    );
+
+    let order = "(uint256,(address),uint8,uint128)";
+    let signature = format!("fill({order},uint128)");
+    assert_eq!(fillCall::SIGNATURE, signature);
+    assert_eq!(fillCall::SELECTOR, keccak256(&signature)[..4]);
+
+    let signature = format!("batch(({order}[]))");
+    assert_eq!(batchCall::SIGNATURE, signature);
+    assert_eq!(batchCall::SELECTOR, keccak256(&signature)[..4]);
+
+    let signature = format!("Rejected({order})");
+    assert_eq!(Rejected::SIGNATURE, signature);
+    assert_eq!(Rejected::SELECTOR, keccak256(&signature)[..4]);
+
+    let signature = format!("Filled(address,{order})");
+    assert_eq!(Filled::SIGNATURE, signature);
+    assert_eq!(Filled::SIGNATURE_HASH, keccak256(&signature));
+
+    let mut selectors = [
+        IExchange::fillCall::SELECTOR,
+        IExchange::cancelCall::SELECTOR,
+    ];
+    selectors.sort_unstable();
+    assert_eq!(IExchange::IExchangeCalls::SELECTORS, selectors);
+
+    let call = IExchange::IExchangeCalls::fill(IExchange::fillCall {
+        order: other_invocation::types::Order {
+            id: U256::from(1),
+            asset: other_invocation::types::Asset {
+                token: Address::ZERO,
+            },
+            side: other_invocation::types::Side::Sell,
+            price: 2,
+        },
+    });
+    let encoded = call.encode();
+    assert_eq!(encoded[..4], IExchange::fillCall::SELECTOR);
+    let IExchange::IExchangeCalls::fill(decoded) =
+        IExchange::IExchangeCalls::decode(&encoded, true).unwrap()
+    else {
+        panic!("wrong variant")
+    };
+    assert_eq!(decoded.order.price, 2);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde() {
+    sol! {
+        #![sol(serde)]
+
+        enum Side {
+            Buy,
+            Sell,
+        }
+
+        struct Order {
+            uint256 amount;
+            uint64 nonce;
+            address maker;
+            Side side;
+            bytes32 salt;
+            bytes data;
+            string note;
+        }
+
+        event Filled(bytes32 indexed id, Order order);
+
+        type Price is uint128;
+    }
+
+    let order = Order {
+        amount: U256::from(1000),
+        nonce: 7,
+        maker: Address::repeat_byte(0x11),
+        side: Side::Sell,
+        salt: [0x22; 32],
+        data: vec![0xde, 0xad],
+        note: "gm".into(),
+    };
+    let json = serde_json::to_value(&order).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "amount": "0x3e8",
+            "nonce": 7,
+            "maker": "0x1111111111111111111111111111111111111111",
+            "side": "Sell",
+            "salt": "0x2222222222222222222222222222222222222222222222222222222222222222",
+            "data": "0xdead",
+            "note": "gm",
+        })
+    );
+    let decoded: Order = serde_json::from_value(json).unwrap();
+    assert_eq!(Order::encode(&decoded), Order::encode(&order));
+
+    let event = Filled {
+        id: [0x33; 32],
+        order,
+    };
+    let json = serde_json::to_string(&event).unwrap();
+    assert!(json.starts_with(r#"{"id":"0x3333"#), "{json}");
+
+    let price = Price::from(42);
+    let json = serde_json::to_string(&price).unwrap();
+    assert_eq!(json, "42");
+    assert_eq!(serde_json::from_str::<Price>(&json).unwrap(), price);
+
+    // the hex helpers are referenced through the given crate path
+    sol! {
+        #![sol(serde, crate = "crate::reexports::sol_types")]
+
+        struct Blob {
+            bytes4 tag;
+            bytes data;
+        }
+    }
+
+    let blob = Blob {
+        tag: [0xca, 0xfe, 0xba, 0xbe],
+        data: vec![0x01],
+    };
+    let json = serde_json::to_value(&blob).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "tag": "0xcafebabe", "data": "0x01" })
+    );
+    let decoded: Blob = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.data, blob.data);
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn arbitrary() {
+    use alloy_sol_types::SolInterface;
+    use arbitrary::{Arbitrary, Unstructured};
+    use proptest::{prelude::*, test_runner::TestRunner};
+
+    sol! {
+        #![sol(all_derives, arbitrary)]
+
+        contract Exchange {
+            type Price is uint128;
+
+            enum Side {
+                Buy,
+                Sell,
+            }
+
+            struct Order {
+                Price price;
+                bytes data;
+                address[] makers;
+            }
+
+            event Placed(bytes32 indexed id, Order order, string indexed tag);
+
+            error Rejected(uint8 code);
+
+            function place(Order order) external returns (bytes32 id);
+            function cancel() external;
+        }
+    }
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&bytes);
+    let order = Exchange::Order::arbitrary(&mut u).unwrap();
+    assert_eq!(
+        Exchange::Order::decode(&Exchange::Order::encode(&order), true).unwrap(),
+        order
+    );
+    let call = Exchange::ExchangeCalls::arbitrary(&mut u).unwrap();
+    assert_eq!(
+        Exchange::ExchangeCalls::decode(&call.encode(), true).unwrap(),
+        call
+    );
+    Exchange::Placed::arbitrary(&mut u).unwrap();
+    Exchange::Rejected::arbitrary(&mut u).unwrap();
+    Exchange::cancelReturn::arbitrary(&mut u).unwrap();
+
+    TestRunner::default()
+        .run(&any::<Exchange::placeCall>(), |call| {
+            let encoded = call.encode();
+            prop_assert_eq!(Exchange::placeCall::decode(&encoded, true).unwrap(), call);
+            Ok(())
+        })
+        .unwrap();
+    TestRunner::default()
+        .run(
+            &any::<(Exchange::Side, Exchange::Price, Exchange::ExchangeErrors)>(),
+            |_| Ok(()),
+        )
+        .unwrap();
+}
+
+#[test]
+fn fallback_and_receive() {
+    sol! {
+        contract Wallet {
+            receive() external payable;
+            fallback() external payable;
+
+            function owner() external view returns (address);
+        }
+
+        interface Proxy {
+            fallback(bytes calldata input) external returns (bytes memory output);
+        }
+
+        interface Token {
+            function totalSupply() external view returns (uint256);
+        }
+    }
+
+    assert_eq!((Wallet::HAS_FALLBACK, Wallet::HAS_RECEIVE), (true, true));
+    assert_eq!(
+        Wallet::WalletCalls::SELECTORS,
+        [Wallet::ownerCall::SELECTOR]
+    );
+
+    assert_eq!((Proxy::HAS_FALLBACK, Proxy::HAS_RECEIVE), (true, false));
+
+    assert_eq!((Token::HAS_FALLBACK, Token::HAS_RECEIVE), (false, false));
+}
+
+#[test]
+fn function_bodies() {
+    // full source files can be used as-is: function bodies are ignored, as
+    // are functions which can only be called internally
+    sol! {
+        pragma solidity ^0.8.0;
+
+        import "./IERC20.sol";
+
+        type Amount is uint256;
+        using {addAmount as +} for Amount global;
+
+        function addAmount(Amount a, Amount b) pure returns (Amount) {
+            return Amount.wrap(Amount.unwrap(a) + Amount.unwrap(b));
+        }
+
+        interface IERC20 {
+            function transfer(address to, uint256 amount) external returns (bool);
+        }
+
+        library SafeMath {
+            function add(uint256 a, uint256 b) internal pure returns (uint256 c) {
+                c = a + b;
+                require(c >= a, "overflow");
+            }
+        }
+
+        abstract contract Ownable {
+            address public owner;
+
+            error Unauthorized();
+
+            modifier onlyOwner() {
+                if (msg.sender != owner) revert Unauthorized();
+                _;
+            }
+
+            constructor() {
+                owner = msg.sender;
+            }
+        }
+
+        contract Vault is Ownable {
+            using SafeMath for uint256;
+
+            struct Account {
+                uint256 balance;
+                mapping(address => bool) operators;
+            }
+
+            uint256 public constant FEE = 100;
+            mapping(address => Account) internal accounts;
+            uint256 private total = 0;
+
+            event Deposit(address indexed from, uint256 amount);
+
+            constructor(uint256 fee) Ownable() {
+                total = fee;
+            }
+
+            receive() external payable {
+                accounts[msg.sender].balance += msg.value;
+                emit Deposit(msg.sender, msg.value);
+            }
+
+            function deposit() public payable virtual {
+                unchecked {
+                    total += msg.value;
+                }
+                assembly {
+                    let x := add(1, 2)
+                    sstore(0, x)
+                }
+            }
+
+            function withdraw(uint256 amount) external onlyOwner returns (bool ok) {
+                try IERC20(address(0)).transfer(msg.sender, amount) returns (bool r) {
+                    ok = r;
+                } catch {
+                    ok = false;
+                }
+                for (uint256 i = 0; i < 10; i++) {
+                    total = total.add(i ** 2);
+                }
+            }
+
+            function _credit(Account storage account, uint256 amount) internal {
+                account.balance += amount;
+            }
+        }
+    }
+
+    assert_eq!(Vault::withdrawCall::SIGNATURE, "withdraw(uint256)");
+    assert_eq!(Vault::FEE, U256::from(100));
+    // `deposit`, `withdraw` and the `FEE` getter
+    assert_eq!(Vault::VaultCalls::SELECTORS.len(), 3);
+    assert_eq!((Vault::HAS_FALLBACK, Vault::HAS_RECEIVE), (false, true));
+}
+
+#[test]
+fn mappings() {
+    use alloy_primitives::B256;
+    use alloy_sol_types::SolMapping;
+
+    sol! {
+        contract Token {
+            struct Account {
+                uint256 balance;
+                mapping(address => bool) operators;
+            }
+
+            mapping(address => uint256) public balanceOf;
+            mapping(address owner => mapping(address spender => uint256)) public allowance;
+            mapping(string => Account) internal accounts;
+        }
+    }
+    use Token::*;
+
+    // public mappings have a getter with one argument per key
+    assert_eq!(balanceOfCall::SIGNATURE, "balanceOf(address)");
+    assert_eq!(allowanceCall::SIGNATURE, "allowance(address,address)");
+    let _ = allowanceCall {
+        owner: Address::ZERO,
+        spender: Address::ZERO,
+    };
+
+    // all mappings expose their layout
+    assert_eq!(balanceOfMapping::NAME, "balanceOf");
+    assert_eq!(balanceOfMapping::KEY_TYPES, ["address"]);
+    assert_eq!(balanceOfMapping::VALUE_TYPE, "uint256");
+    assert_eq!(allowanceMapping::KEY_TYPES, ["address", "address"]);
+    assert_eq!(accountsMapping::KEY_TYPES, ["string"]);
+    assert_eq!(accountsMapping::VALUE_TYPE, "Account");
+
+    let owner = Address::repeat_byte(0x11);
+    let spender = Address::repeat_byte(0x22);
+    let base = B256::with_last_byte(1);
+    assert_eq!(
+        balanceOfMapping::slot(base, &(owner,)),
+        keccak256([owner.into_word(), base].concat())
+    );
+    let inner = keccak256([owner.into_word(), base].concat());
+    assert_eq!(
+        allowanceMapping::slot(base, &(owner, spender)),
+        keccak256([spender.into_word(), inner].concat())
+    );
+    // `string` and `bytes` keys are not padded
+    assert_eq!(
+        accountsMapping::slot(base, &("alice".to_string(),)),
+        keccak256([&b"alice"[..], &base[..]].concat())
+    );
+}
+
+#[test]
+fn rename() {
+    use alloy_sol_types::{SolEvent, SolStruct};
+
+    sol! {
+        struct Order {
+            #[sol(rename = "kind")]
+            uint8 type;
+            address maker;
+        }
+
+        function matchOrder(Order order, #[sol(rename = "r#ref")] bytes32 ref)
+            returns (#[sol(rename = "matched")] bool);
+
+        #[sol(rename = "InvalidOrderError")]
+        error InvalidOrder(#[sol(rename = "order_type")] uint8 type);
+
+        #[sol(rename = "OrderMatchedEvent")]
+        event OrderMatched(#[sol(rename = "r#ref")] bytes32 indexed ref, #[sol(rename = "kind")] uint8 type);
+    }
+
+    // Rust names are changed, but the ABI and EIP-712 names are not
+    let order = Order {
+        kind: 1,
+        maker: Address::ZERO,
+    };
+    assert_eq!(
+        <Order as SolStruct>::eip712_encode_type(),
+        "Order(uint8 type,address maker)"
+    );
+
+    let call = matchOrderCall {
+        order,
+        r#ref: [0x11; 32],
+    };
+    assert_eq!(
+        matchOrderCall::SIGNATURE,
+        "matchOrder((uint8,address),bytes32)"
+    );
+    let decoded = matchOrderCall::decode(&call.encode(), true).unwrap();
+    assert_eq!(decoded.r#ref, [0x11; 32]);
+    let ret = matchOrderReturn { matched: true };
+    assert!(
+        matchOrderCall::decode_returns(&matchOrderCall::encode_returns(&ret), true)
+            .unwrap()
+            .matched
+    );
+
+    assert_eq!(InvalidOrderError::SIGNATURE, "InvalidOrder(uint8)");
+    let error = InvalidOrderError { order_type: 2 };
+    assert_eq!(
+        InvalidOrderError::decode(&error.encode(), true)
+            .unwrap()
+            .order_type,
+        2
+    );
+
+    assert_eq!(OrderMatchedEvent::SIGNATURE, "OrderMatched(bytes32,uint8)");
+    let event = OrderMatchedEvent {
+        r#ref: [0x22; 32],
+        kind: 3,
+    };
+    assert_eq!(
+        OrderMatchedEvent::filter().r#ref([0x22; 32]).topics()[1],
+        Some(alloy_primitives::B256::repeat_byte(0x22))
+    );
+    let decoded =
+        OrderMatchedEvent::decode_log(event.encode_topics(), &event.encode_data(), true).unwrap();
+    assert_eq!((decoded.r#ref, decoded.kind), ([0x22; 32], 3));
+}
+
+#[test]
+fn handler() {
+    sol! {
+        #[sol(handler)]
+        interface IToken {
+            function balanceOf(address owner) external view returns (uint256);
+            function transfer(address to, uint256 amount) external returns (bool);
+            function burn(uint256) external;
+        }
+    }
+    use IToken::*;
+
+    #[derive(Default)]
+    struct MockToken {
+        balances: std::collections::HashMap<Address, U256>,
+        burned: U256,
+    }
+
+    impl ITokenHandler for MockToken {
+        fn balanceOf(&mut self, owner: Address) -> balanceOfReturn {
+            balanceOfReturn {
+                _0: self.balances.get(&owner).copied().unwrap_or_default(),
+            }
+        }
+
+        fn transfer(&mut self, to: Address, amount: U256) -> transferReturn {
+            *self.balances.entry(to).or_default() += amount;
+            transferReturn { _0: true }
+        }
+
+        fn burn(&mut self, amount: U256) -> burnReturn {
+            self.burned += amount;
+            burnReturn {}
+        }
+    }
+
+    let mut token = MockToken::default();
+    let to = Address::repeat_byte(0x11);
+    let amount = U256::from(100);
+
+    let call = transferCall { to, amount };
+    let ret = ITokenCalls::dispatch_calldata(&mut token, &call.encode(), true).unwrap();
+    assert!(transferCall::decode_returns(&ret, true).unwrap()._0);
+
+    let call = ITokenCalls::balanceOf(balanceOfCall { owner: to });
+    let ret = call.dispatch(&mut token);
+    assert_eq!(
+        balanceOfCall::decode_returns(&ret, true).unwrap()._0,
+        amount
+    );
+
+    let ret = ITokenCalls::dispatch_calldata(&mut token, &burnCall { _0: amount }.encode(), true);
+    assert_eq!(ret.unwrap(), Vec::<u8>::new());
+    assert_eq!(token.burned, amount);
+
+    // unknown selectors are rejected
+    let handler: &mut dyn ITokenHandler = &mut token;
+    assert!(ITokenCalls::dispatch_calldata(handler, &[0xff; 4], true).is_err());
+}
+
+#[test]
+fn state_variables() {
+    use alloy_sol_types::StateVariable;
+
+    sol! {
+        contract Vault {
+            uint16 constant FEE_BPS = 30;
+            bool constant PAUSABLE = true;
+            bytes32 constant ROLE = keccak256("ROLE");
+            string constant NAME = "Vault";
+
+            address immutable owner;
+            uint256 public immutable cap = 1000;
+            uint256 total;
+
+            constructor(address owner_);
+        }
+    }
+
+    assert_eq!(
+        Vault::CONSTANTS,
+        [
+            StateVariable {
+                name: "FEE_BPS",
+                ty: "uint16",
+                value: Some("30"),
+            },
+            StateVariable {
+                name: "PAUSABLE",
+                ty: "bool",
+                value: Some("true"),
+            },
+            StateVariable {
+                name: "ROLE",
+                ty: "bytes32",
+                value: Some("0xed9ea7bc2a13bc59432ab07436e7f7f5450f82d4b48c401bed177bfaf36b1873"),
+            },
+            StateVariable {
+                name: "NAME",
+                ty: "string",
+                value: Some("\"Vault\""),
+            },
+        ]
+    );
+    assert_eq!(
+        Vault::IMMUTABLES,
+        [
+            StateVariable {
+                name: "owner",
+                ty: "address",
+                value: None,
+            },
+            StateVariable {
+                name: "cap",
+                ty: "uint256",
+                value: Some("1000"),
+            },
+        ]
+    );
+    assert_eq!(Vault::FEE_BPS, 30);
+    assert_eq!(Vault::ROLE, keccak256("ROLE"));
+}
+
+#[test]
+fn default() {
+    sol! {
+        #![sol(default)]
+
+        struct Order {
+            address maker;
+            address taker;
+            uint256 amount;
+            uint64 deadline;
+            bytes data;
+        }
+
+        function fill(Order order, uint8 v, bytes32 r, bytes32 s, bool partial);
+    }
+
+    let order = Order {
+        amount: U256::from(100),
+        ..Default::default()
+    };
+    assert_eq!(order.maker, Address::ZERO);
+    assert!(order.data.is_empty());
+
+    let mut call = fillCall {
+        order,
+        v: 27,
+        ..Default::default()
+    };
+    call.order.deadline = 1;
+    assert!(!call.partial);
+    assert_eq!(call.order.amount, U256::from(100));
+}
+
+#[test]
+fn udt_all_derives() {
+    sol! {
+        #![sol(all_derives)]
+
+        type Price is uint128;
+        type Salt is bytes32;
+    }
+
+    let price = Price::from(42);
+    let prices = [price, price, Price::default()]
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(prices.len(), 2);
+    assert_eq!(format!("{price:?}"), "Price(42)");
+    assert_ne!(Salt::from([1; 32]), Salt::default());
+}
+
+#[test]
+fn flatten() {
+    sol! {
+        #![sol(all_derives)]
+
+        interface IERC20 {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            error InsufficientBalance(uint256 available, uint256 required);
+
+            function totalSupply() external view returns (uint256);
+            function balanceOf(address owner) external view returns (uint256);
+            function transfer(address to, uint256 amount) external returns (bool);
+        }
+
+        #[sol(flatten)]
+        interface IERC20Metadata is IERC20 {
+            function name() external view returns (string);
+            function symbol() external view returns (string);
+            function decimals() external view returns (uint8);
+            function totalSupply() external view returns (uint256);
+        }
+
+        interface IERC20Burnable is IERC20 {
+            function burn(uint256 amount) external;
+        }
+
+        interface IERC20Mintable is IERC20 {
+            function mint(address to, uint256 amount) external;
+        }
+
+        // `IERC20` is inherited twice, but its items are only re-exported once
+        #[sol(flatten)]
+        interface IERC20Full is IERC20Burnable, IERC20Mintable {}
+    }
+    use alloy_sol_types::SolInterface;
+
+    // `totalSupply` is overridden and only appears once
+    assert_eq!(IERC20Metadata::IERC20MetadataCalls::SELECTORS.len(), 6);
+    assert_eq!(IERC20Metadata::IERC20MetadataErrors::SELECTORS.len(), 1);
+    assert_eq!(IERC20Metadata::IERC20MetadataEvents::SELECTORS.len(), 1);
+
+    // without the attribute, inherited items are not included
+    assert_eq!(IERC20Burnable::IERC20BurnableCalls::SELECTORS.len(), 1);
+
+    assert_eq!(IERC20Full::IERC20FullCalls::SELECTORS.len(), 5);
+    let _: IERC20Full::burnCall = IERC20Burnable::burnCall {
+        amount: U256::from(1),
+    };
+    let _: IERC20Full::InsufficientBalance = IERC20::InsufficientBalance {
+        available: U256::from(1),
+        required: U256::from(2),
+    };
+
+    // inherited types are re-exported from the derived contract's module
+    use IERC20Metadata::*;
+    let call = transferCall {
+        to: Address::repeat_byte(0x11),
+        amount: U256::from(1),
+    };
+    let decoded = IERC20MetadataCalls::decode(&call.encode(), true).unwrap();
+    assert_eq!(decoded, IERC20MetadataCalls::transfer(call));
+
+    let error = InsufficientBalance {
+        available: U256::from(1),
+        required: U256::from(2),
+    };
+    let decoded = IERC20MetadataErrors::decode(&error.encode(), true).unwrap();
+    assert_eq!(decoded, IERC20MetadataErrors::InsufficientBalance(error));
+
+    let _filter: TransferFilter = Transfer::filter();
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn abi() {
+    sol! {
+        #[sol(abi)]
+        contract Vault {
+            enum Status { Open, Closed }
+            type Shares is uint128;
+
+            struct Position {
+                address owner;
+                uint128[2] amounts;
+            }
+
+            constructor(address admin) payable;
+            receive() external payable;
+
+            Shares public totalShares;
+
+            function open(Position[] calldata positions) external returns (uint256 id);
+            function status(uint256 id) external view returns (Status);
+
+            event Opened(uint256 indexed id, Position position);
+            error Unauthorized(address caller);
+        }
+    }
+
+    let abi: serde_json::Value = serde_json::from_str(Vault::ABI).unwrap();
+    let position = serde_json::json!({
+        "name": "position",
+        "type": "tuple",
+        "internalType": "struct Vault.Position",
+        "components": [
+            { "name": "owner", "type": "address", "internalType": "address" },
+            { "name": "amounts", "type": "uint128[2]", "internalType": "uint128[2]" },
+        ],
+    });
+    let mut positions = position.clone();
+    positions["name"] = "positions".into();
+    positions["type"] = "tuple[]".into();
+    positions["internalType"] = "struct Vault.Position[]".into();
+    let mut event_position = position;
+    event_position["indexed"] = false.into();
+    assert_eq!(
+        abi,
+        serde_json::json!([
+            {
+                "type": "constructor",
+                "inputs": [{ "name": "admin", "type": "address", "internalType": "address" }],
+                "stateMutability": "payable",
+            },
+            { "type": "receive", "stateMutability": "payable" },
+            {
+                "type": "function",
+                "name": "open",
+                "inputs": [positions],
+                "outputs": [{ "name": "id", "type": "uint256", "internalType": "uint256" }],
+                "stateMutability": "nonpayable",
+            },
+            {
+                "type": "function",
+                "name": "status",
+                "inputs": [{ "name": "id", "type": "uint256", "internalType": "uint256" }],
+                "outputs": [{ "name": "", "type": "uint8", "internalType": "enum Vault.Status" }],
+                "stateMutability": "view",
+            },
+            {
+                "type": "function",
+                "name": "totalShares",
+                "inputs": [],
+                "outputs": [{ "name": "", "type": "uint128", "internalType": "Vault.Shares" }],
+                "stateMutability": "view",
+            },
+            {
+                "type": "event",
+                "name": "Opened",
+                "inputs": [
+                    { "name": "id", "type": "uint256", "indexed": true, "internalType": "uint256" },
+                    event_position,
+                ],
+                "anonymous": false,
+            },
+            {
+                "type": "error",
+                "name": "Unauthorized",
+                "inputs": [{ "name": "caller", "type": "address", "internalType": "address" }],
+            },
+        ])
+    );
+}
+
 #[test]
 #[cfg(feature = "json")]
 fn abigen_json() {
@@ -282,3 +1277,37 @@ fn abigen_json() {
         "callWithLongArray(uint64[128])"
     );
 }
+
+#[test]
+#[cfg(feature = "json")]
+fn abigen_json_artifact() {
+    use alloy_sol_types::{SolConstructor, SolEvent, SolInterface};
+
+    // constructors, errors, events, and the `receive` function are all supported
+    sol!(Seaport, "../json-abi/tests/abi/Seaport.json");
+    assert_eq!(Seaport::getCounterCall::SIGNATURE, "getCounter(address)");
+    assert_eq!(
+        Seaport::InvalidTime::SIGNATURE,
+        "InvalidTime(uint256,uint256)"
+    );
+    assert_eq!(
+        Seaport::CounterIncremented::SIGNATURE,
+        "CounterIncremented(uint256,address)"
+    );
+
+    let call = Seaport::getCounterCall {
+        offerer: Address::ZERO,
+    };
+    let data = call.encode();
+    assert!(matches!(
+        Seaport::SeaportCalls::decode(&data, true),
+        Ok(Seaport::SeaportCalls::getCounter(_))
+    ));
+
+    let constructor = Seaport::SeaportConstructor {
+        conduitController: Address::ZERO,
+    };
+    assert_eq!(constructor.encode(), [0; 32]);
+
+    assert_eq!((Seaport::HAS_FALLBACK, Seaport::HAS_RECEIVE), (false, true));
+}
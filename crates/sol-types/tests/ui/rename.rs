@@ -0,0 +1,53 @@
+use alloy_sol_types::sol;
+
+// OK
+sol! {
+    #[sol(rename = "overloadedA")]
+    function overloaded();
+    #[sol(rename = "overloadedB")]
+    function overloaded(uint256);
+
+    #[sol(rename = "MyError")]
+    error Err();
+    #[sol(rename = "MyEvent")]
+    event Ev();
+}
+
+sol! {
+    #[sol(rename = "sameRename")]
+    function renameTwice();
+    #[sol(rename = "sameRename")]
+    function renameTwice(uint256);
+}
+
+sol! {
+    function existingFunction();
+    #[sol(rename = "existingFunction")]
+    function renameToExisting();
+}
+
+sol! {
+    error ExistingError();
+    #[sol(rename = "ExistingError")]
+    error RenameToExistingError();
+}
+
+sol! {
+    struct ExistingStruct {
+        uint256 a;
+    }
+
+    #[sol(rename = "ExistingStruct")]
+    event RenameToExistingStruct();
+}
+
+sol! {
+    contract C {
+        #[sol(rename = "SameInContract")]
+        error E1();
+        #[sol(rename = "SameInContract")]
+        event E2();
+    }
+}
+
+fn main() {}
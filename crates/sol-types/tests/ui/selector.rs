@@ -0,0 +1,17 @@
+use alloy_sol_types::sol;
+
+sol! {
+    contract FunctionCollision {
+        function transferFrom(address, address, uint256) external;
+        function gasprice_bit_ether(int128) external;
+    }
+}
+
+sol! {
+    interface ErrorCollision {
+        error transferFrom(address, address, uint256);
+        error gasprice_bit_ether(int128);
+    }
+}
+
+fn main() {}
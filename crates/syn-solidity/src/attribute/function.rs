@@ -53,6 +53,24 @@ impl Parse for FunctionAttributes {
     }
 }
 
+impl FunctionAttributes {
+    /// Returns the visibility attribute, if any.
+    pub fn visibility(&self) -> Option<Visibility> {
+        self.0.iter().find_map(|attr| match attr {
+            FunctionAttribute::Visibility(visibility) => Some(*visibility),
+            _ => None,
+        })
+    }
+
+    /// Returns the mutability attribute, if any.
+    pub fn mutability(&self) -> Option<Mutability> {
+        self.0.iter().find_map(|attr| match attr {
+            FunctionAttribute::Mutability(mutability) => Some(*mutability),
+            _ => None,
+        })
+    }
+}
+
 /// A function attribute.
 #[derive(Clone)]
 pub enum FunctionAttribute {
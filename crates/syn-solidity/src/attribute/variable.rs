@@ -53,6 +53,28 @@ impl Parse for VariableAttributes {
     }
 }
 
+impl VariableAttributes {
+    /// Returns the visibility attribute, if any.
+    pub fn visibility(&self) -> Option<Visibility> {
+        self.0.iter().find_map(|attr| match attr {
+            VariableAttribute::Visibility(visibility) => Some(*visibility),
+            _ => None,
+        })
+    }
+
+    /// Returns true if the variable is `constant`.
+    pub fn is_constant(&self) -> bool {
+        self.0
+            .contains(&VariableAttribute::Constant(Default::default()))
+    }
+
+    /// Returns true if the variable is `immutable`.
+    pub fn is_immutable(&self) -> bool {
+        self.0
+            .contains(&VariableAttribute::Immutable(Default::default()))
+    }
+}
+
 /// A variable attribute.
 #[derive(Clone)]
 pub enum VariableAttribute {
@@ -207,12 +207,15 @@ impl EventParameter {
         self.ty.is_abi_dynamic()
     }
 
-    /// Returns `true` if the event parameter is indexed and dynamically sized.
-    /// These types are hashed, and then stored in the topics as specified in
-    /// [the Solidity spec][ref].
+    /// Returns `true` if the event parameter is indexed and not a value type.
+    /// These types (`string`, `bytes`, arrays and structs) are hashed, and then
+    /// stored in the topics as specified in [the Solidity spec][ref].
+    ///
+    /// Custom types are assumed to be structs, as they cannot be resolved
+    /// here.
     ///
     /// [ref]: https://docs.soliditylang.org/en/latest/abi-spec.html#events
     pub fn indexed_as_hash(&self) -> bool {
-        self.is_indexed() && self.is_abi_dynamic()
+        self.is_indexed() && !self.ty.is_one_word()
     }
 }
@@ -1,6 +1,10 @@
-use crate::{kw, Block, FunctionAttributes, Parameters, SolIdent, Type};
+use crate::{
+    kw, Block, FunctionAttribute, FunctionAttributes, Mutability, Parameters, SolIdent, Type,
+    VariableDeclaration, Visibility,
+};
 use proc_macro2::Span;
 use std::{
+    collections::HashSet,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -83,6 +87,63 @@ impl ItemFunction {
         }
     }
 
+    /// Creates the getter function of a public state variable, as generated by
+    /// the Solidity compiler:
+    /// - each mapping key and array index becomes an argument,
+    /// - the innermost value type is returned.
+    ///
+    /// Note that struct return values are not flattened into their members, as
+    /// this requires resolving the struct definition.
+    ///
+    /// Solidity reference:
+    /// <https://docs.soliditylang.org/en/latest/contracts.html#getter-functions>
+    pub fn new_getter(name: SolIdent, mut ty: Type) -> Self {
+        let span = name.span();
+
+        let mut arguments = Parameters::new();
+        let ty = loop {
+            ty = match ty {
+                Type::Mapping(mapping) => {
+                    let mut key = VariableDeclaration::new(*mapping.key);
+                    key.name = mapping.key_name;
+                    arguments.push(key);
+                    *mapping.value
+                }
+                Type::Array(array) => {
+                    arguments.push(VariableDeclaration::new(Type::Uint(span, None)));
+                    *array.ty
+                }
+                ty => break ty,
+            };
+        };
+
+        let mut attributes = FunctionAttributes(HashSet::new());
+        attributes.insert(FunctionAttribute::Visibility(Visibility::External(
+            kw::external(span),
+        )));
+        attributes.insert(FunctionAttribute::Mutability(Mutability::View(kw::view(
+            span,
+        ))));
+
+        let mut returns = Parameters::new();
+        returns.push(VariableDeclaration::new(ty));
+
+        Self {
+            attrs: Vec::new(),
+            kind: FunctionKind::Function(kw::function(span)),
+            name: Some(name),
+            paren_token: Paren(span),
+            arguments,
+            attributes,
+            returns: Some(Returns {
+                returns_token: kw::returns(span),
+                paren_token: Paren(span),
+                returns,
+            }),
+            body: FunctionBody::Empty(Token![;](span)),
+        }
+    }
+
     /// Returns true if the function returns nothing.
     pub fn is_void(&self) -> bool {
         match &self.returns {
@@ -131,6 +192,28 @@ kw_enum! {
     }
 }
 
+impl FunctionKind {
+    /// Returns true if this is a `constructor`.
+    pub const fn is_constructor(self) -> bool {
+        matches!(self, Self::Constructor(_))
+    }
+
+    /// Returns true if this is a `function`.
+    pub const fn is_function(self) -> bool {
+        matches!(self, Self::Function(_))
+    }
+
+    /// Returns true if this is a `fallback`.
+    pub const fn is_fallback(self) -> bool {
+        matches!(self, Self::Fallback(_))
+    }
+
+    /// Returns true if this is a `receive`.
+    pub const fn is_receive(self) -> bool {
+        matches!(self, Self::Receive(_))
+    }
+}
+
 /// The `returns` attribute of a function.
 #[derive(Clone)]
 pub struct Returns {
@@ -18,7 +18,7 @@ mod event;
 pub use event::{EventParameter, ItemEvent};
 
 mod function;
-pub use function::{ItemFunction, Returns};
+pub use function::{FunctionBody, FunctionKind, ItemFunction, Returns};
 
 mod import;
 pub use import::{
@@ -165,6 +165,35 @@ impl Item {
         }
     }
 
+    /// Returns the item's attributes, if it can have any.
+    pub fn attrs(&self) -> Option<&Vec<Attribute>> {
+        match self {
+            Self::Contract(ItemContract { attrs, .. })
+            | Self::Function(ItemFunction { attrs, .. })
+            | Self::Enum(ItemEnum { attrs, .. })
+            | Self::Error(ItemError { attrs, .. })
+            | Self::Event(ItemEvent { attrs, .. })
+            | Self::Struct(ItemStruct { attrs, .. })
+            | Self::Udt(ItemUdt { attrs, .. }) => Some(attrs),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the item's attributes, if it can have
+    /// any.
+    pub fn attrs_mut(&mut self) -> Option<&mut Vec<Attribute>> {
+        match self {
+            Self::Contract(ItemContract { attrs, .. })
+            | Self::Function(ItemFunction { attrs, .. })
+            | Self::Enum(ItemEnum { attrs, .. })
+            | Self::Error(ItemError { attrs, .. })
+            | Self::Event(ItemEvent { attrs, .. })
+            | Self::Struct(ItemStruct { attrs, .. })
+            | Self::Udt(ItemUdt { attrs, .. }) => Some(attrs),
+            _ => None,
+        }
+    }
+
     fn replace_attrs(&mut self, src: Vec<Attribute>) -> Vec<Attribute> {
         match self {
             Self::Contract(ItemContract { attrs, .. })
@@ -45,6 +45,12 @@ impl UsingDirective {
         self.using_token.span = span;
         self.semi_token.span = span;
     }
+
+    /// Returns `true` if the directive is `global`, meaning that it applies
+    /// everywhere the type is available.
+    pub const fn is_global(&self) -> bool {
+        self.global_token.is_some()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -53,6 +59,18 @@ pub enum UsingList {
     Multiple(Brace, Punctuated<UsingListItem, Token![,]>),
 }
 
+impl UsingList {
+    /// Returns an iterator over the paths of the attached library or
+    /// functions.
+    pub fn paths(&self) -> impl Iterator<Item = &SolPath> {
+        let (single, multiple) = match self {
+            Self::Single(path) => (Some(path), None),
+            Self::Multiple(_, items) => (None, Some(items.iter().map(|item| &item.path))),
+        };
+        single.into_iter().chain(multiple.into_iter().flatten())
+    }
+}
+
 impl Parse for UsingList {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         if input.peek(Brace) {
@@ -37,7 +37,7 @@ impl Parse for LitStr {
             unicode_token: input.parse()?,
             values: {
                 let mut values = Vec::new();
-                while !input.peek(syn::LitStr) {
+                while input.peek(syn::LitStr) {
                     values.push(input.parse()?);
                 }
                 if values.is_empty() {
@@ -26,6 +26,8 @@ macro_rules! make_visitor {
                         | Type::Bool(_)
                         | Type::Uint(..)
                         | Type::Int(..)
+                        | Type::Fixed(..)
+                        | Type::Ufixed(..)
                         | Type::String(_)
                         | Type::Bytes(_)
                         | Type::FixedBytes(..) => {},
@@ -46,6 +46,10 @@ pub enum Type {
     Int(Span, Option<NonZeroU16>),
     /// `uint[size]`
     Uint(Span, Option<NonZeroU16>),
+    /// `fixed[<size>x<decimals>]`
+    Fixed(Span, Option<(NonZeroU16, u8)>),
+    /// `ufixed[<size>x<decimals>]`
+    Ufixed(Span, Option<(NonZeroU16, u8)>),
 
     /// `$ty[$($size)?]`
     Array(TypeArray),
@@ -71,6 +75,8 @@ impl PartialEq for Type {
             (Self::FixedBytes(_, a), Self::FixedBytes(_, b)) => a == b,
             (Self::Int(_, a), Self::Int(_, b)) => a == b,
             (Self::Uint(_, a), Self::Uint(_, b)) => a == b,
+            (Self::Fixed(_, a), Self::Fixed(_, b)) => a == b,
+            (Self::Ufixed(_, a), Self::Ufixed(_, b)) => a == b,
 
             (Self::Tuple(a), Self::Tuple(b)) => a == b,
             (Self::Array(a), Self::Array(b)) => a == b,
@@ -94,6 +100,8 @@ impl Hash for Type {
             Self::FixedBytes(_, size) => size.hash(state),
             Self::Int(_, size) => size.hash(state),
             Self::Uint(_, size) => size.hash(state),
+            Self::Fixed(_, size) => size.hash(state),
+            Self::Ufixed(_, size) => size.hash(state),
 
             Self::Tuple(tuple) => tuple.hash(state),
             Self::Array(array) => array.hash(state),
@@ -116,6 +124,8 @@ impl fmt::Debug for Type {
             Self::FixedBytes(_, size) => f.debug_tuple("FixedBytes").field(size).finish(),
             Self::Int(_, size) => f.debug_tuple("Int").field(size).finish(),
             Self::Uint(_, size) => f.debug_tuple("Uint").field(size).finish(),
+            Self::Fixed(_, size) => f.debug_tuple("Fixed").field(size).finish(),
+            Self::Ufixed(_, size) => f.debug_tuple("Ufixed").field(size).finish(),
 
             Self::Tuple(tuple) => tuple.fmt(f),
             Self::Array(array) => array.fmt(f),
@@ -138,6 +148,8 @@ impl fmt::Display for Type {
             Self::FixedBytes(_, size) => write!(f, "bytes{size}"),
             Self::Int(_, size) => write_opt(f, "int", *size),
             Self::Uint(_, size) => write_opt(f, "uint", *size),
+            Self::Fixed(_, size) => write_fixed(f, "fixed", *size),
+            Self::Ufixed(_, size) => write_fixed(f, "ufixed", *size),
 
             Self::Tuple(tuple) => tuple.fmt(f),
             Self::Array(array) => array.fmt(f),
@@ -185,7 +197,9 @@ impl Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span,
             Self::Tuple(tuple) => tuple.span(),
             Self::Array(array) => array.span(),
             Self::Function(function) => function.span(),
@@ -207,7 +221,9 @@ impl Type {
             | Self::Bytes(span)
             | Self::FixedBytes(span, _)
             | Self::Int(span, _)
-            | Self::Uint(span, _) => *span = new_span,
+            | Self::Uint(span, _)
+            | Self::Fixed(span, _)
+            | Self::Ufixed(span, _) => *span = new_span,
 
             Self::Tuple(tuple) => tuple.set_span(new_span),
             Self::Array(array) => array.set_span(new_span),
@@ -226,6 +242,8 @@ impl Type {
                 | Self::Bool(_)
                 | Self::Int(..)
                 | Self::Uint(..)
+                | Self::Fixed(..)
+                | Self::Ufixed(..)
                 | Self::FixedBytes(..)
                 | Self::Function(_)
         )
@@ -238,6 +256,8 @@ impl Type {
             | Self::Bool(_)
             | Self::Int(..)
             | Self::Uint(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::FixedBytes(..)
             | Self::Function(_) => false,
 
@@ -279,6 +299,8 @@ impl Type {
             | Self::Bool(_)
             | Self::Uint(..)
             | Self::Int(..)
+            | Self::Fixed(..)
+            | Self::Ufixed(..)
             | Self::String(_)
             | Self::Bytes(_)
             | Self::FixedBytes(..) => false,
@@ -364,6 +386,16 @@ impl Type {
                             }
                             Some(size) => Self::Uint(span, size),
                         }
+                    } else if let Some(s) = s.strip_prefix("fixed") {
+                        match parse_fixed_size(s, span)? {
+                            None => Self::custom(ident),
+                            Some(size) => Self::Fixed(span, size),
+                        }
+                    } else if let Some(s) = s.strip_prefix("ufixed") {
+                        match parse_fixed_size(s, span)? {
+                            None => Self::custom(ident),
+                            Some(size) => Self::Ufixed(span, size),
+                        }
                     } else {
                         Self::custom(ident)
                     }
@@ -374,6 +406,7 @@ impl Type {
             Err(input.error(
                 "expected a Solidity type: \
                  `address`, `bool`, `string`, `bytesN`, `intN`, `uintN`, \
+                 `fixedMxN`, `ufixedMxN`, \
                  `tuple`, `function`, `mapping`, or a custom type name",
             ))
         }
@@ -388,6 +421,46 @@ fn write_opt(f: &mut fmt::Formatter<'_>, name: &str, size: Option<NonZeroU16>) -
     Ok(())
 }
 
+fn write_fixed(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    size: Option<(NonZeroU16, u8)>,
+) -> fmt::Result {
+    f.write_str(name)?;
+    if let Some((size, decimals)) = size {
+        write!(f, "{size}x{decimals}")?;
+    }
+    Ok(())
+}
+
+// None => Custom
+// Some(size) => size
+fn parse_fixed_size(s: &str, span: Span) -> Result<Option<Option<(NonZeroU16, u8)>>> {
+    if s.is_empty() {
+        return Ok(Some(None))
+    }
+    let Some((size, decimals)) = s.split_once('x') else {
+        return Ok(None)
+    };
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(size) || !is_digits(decimals) {
+        return Ok(None)
+    }
+
+    let error = || {
+        Error::new(
+            span,
+            "fixedMxN must have M a multiple of 8 up to 256, and N up to 80",
+        )
+    };
+    let size = size.parse::<NonZeroU16>().map_err(|_| error())?;
+    let decimals = decimals.parse::<u8>().map_err(|_| error())?;
+    if size.get() > 256 || size.get() % 8 != 0 || decimals > 80 {
+        return Err(error())
+    }
+    Ok(Some(Some((size, decimals))))
+}
+
 // None => Custom
 // Some(size) => size
 fn parse_size(s: &str, span: Span) -> Result<Option<Option<NonZeroU16>>> {
@@ -0,0 +1,70 @@
+use syn_solidity::{File, Item, Type, UserDefinableOperator, UsingDirective, UsingList, UsingType};
+
+#[macro_use]
+mod macros;
+
+#[test]
+fn using_library() {
+    let using: UsingDirective = syn::parse_str("using SafeERC20 for IERC20;").unwrap();
+    assert!(matches!(&using.list, UsingList::Single(path) if *path == path![SafeERC20]));
+    assert!(matches!(&using.ty, UsingType::Type(Type::Custom(path)) if *path == path![IERC20]));
+    assert!(!using.is_global());
+}
+
+#[test]
+fn using_functions() {
+    let using: UsingDirective =
+        syn::parse_str("using {add, Math.mul as *, sub as -} for UD60x18 global;").unwrap();
+    assert_eq!(
+        using.list.paths().cloned().collect::<Vec<_>>(),
+        [path![add], path![Math, mul], path![sub]]
+    );
+    let UsingList::Multiple(_, items) = &using.list else {
+        panic!("expected a list of functions")
+    };
+    assert!(items[0].op.is_none());
+    assert!(matches!(
+        items[1].op,
+        Some((_, UserDefinableOperator::Mul(_)))
+    ));
+    assert!(matches!(
+        items[2].op,
+        Some((_, UserDefinableOperator::Sub(_)))
+    ));
+    assert!(using.is_global());
+}
+
+#[test]
+fn using_star() {
+    let using: UsingDirective = syn::parse_str("using Math for *;").unwrap();
+    assert!(matches!(using.ty, UsingType::Star(_)));
+}
+
+#[test]
+fn using_in_contract() {
+    let file: File = syn::parse_str(
+        "
+        contract C {
+            using SafeERC20 for IERC20;
+            using {add} for uint256[];
+
+            function f() external {}
+        }
+        ",
+    )
+    .unwrap();
+    let Item::Contract(contract) = &file.items[0] else {
+        panic!("expected a contract")
+    };
+    let usings: Vec<_> = contract
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            Item::Using(using) => Some(using),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(usings.len(), 2);
+    assert!(matches!(&usings[1].ty, UsingType::Type(Type::Array(_))));
+    assert!(matches!(contract.body[2], Item::Function(_)));
+}
@@ -0,0 +1,45 @@
+//! Round-trip tests for the `sqlx` feature of `alloy-primitives`.
+//!
+//! These live in their own crate so that `alloy-primitives` does not need a
+//! database driver or an async runtime as dev-dependencies.
+
+#![cfg(test)]
+
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use sqlx_core::{connection::Connection, query_scalar::query_scalar, sqlite::SqliteConnection};
+
+#[tokio::test]
+async fn sqlite_round_trip() {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+    let address = Address::repeat_byte(0x42);
+    let decoded: Address = query_scalar("SELECT ?")
+        .bind(address)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(decoded, address);
+
+    let bytes = FixedBytes([0x11, 0x22, 0x33]);
+    let decoded: FixedBytes<3> = query_scalar("SELECT ?")
+        .bind(bytes)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(decoded, bytes);
+
+    let wrong_length = query_scalar::<_, B256>("SELECT ?")
+        .bind(address)
+        .fetch_one(&mut conn)
+        .await;
+    assert!(wrong_length.is_err());
+
+    // Mapped by `ruint/sqlx`.
+    let value = U256::from(1234);
+    let decoded: U256 = query_scalar("SELECT ?")
+        .bind(value)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(decoded, value);
+}